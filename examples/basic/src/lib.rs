@@ -2,7 +2,6 @@ use console_error_panic_hook::set_once as set_panic_hook;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 use xterm_js_sys::xterm::{LogLevel, Terminal, TerminalOptions, KeyEventData, ResizeEventData};
-use xterm_js_sys::ext::TerminalOptionsExt;
 
 #[path = "../../common.rs"]
 mod common;
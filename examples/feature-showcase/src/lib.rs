@@ -17,9 +17,8 @@ use web_sys::{console, HtmlElement, HtmlButtonElement};
 
 use xterm_js_sys::xterm::{
     LogLevel, Terminal, TerminalOptions, KeyEventData,
-    CursorStyle, BellStyle, Theme, WindowsPty,
+    CursorStyle, Theme, WindowsPty,
 };
-use xterm_js_sys::ext::{TerminalOptionsExt, ThemeExt};
 
 macro_rules! log {
     ( $( $t:tt )* ) => {
@@ -80,7 +79,6 @@ fn create_enhanced_terminal(document: &web_sys::Document) -> Result<Terminal, Js
         .with_rows(30)
         .with_cursor_style(CursorStyle::Block)
         .with_cursor_blink(true)
-        .with_bell_style(BellStyle::Sound)
         .with_scrollback(10000)
         .with_tab_stop_width(4);
     
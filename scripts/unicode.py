@@ -0,0 +1,279 @@
+#!/usr/bin/env python3
+"""Generates `src/ext/unicode/tables.rs` from the Unicode Character Database.
+
+Run from the root of the repo:
+
+    python3 scripts/unicode.py
+
+The UCD files are downloaded into `scripts/ucd/` (and reused from there on
+later runs).
+
+The grapheme tables are only the `Grapheme_Cluster_Break` and
+`Extended_Pictographic` properties: `graphemes.rs` implements Unicode 15.0's
+segmentation rules, so 15.1's `Indic_Conjunct_Break` (for GB9c) isn't
+generated.
+
+Widths follow Markus Kuhn's `wcwidth` (http://www.cl.cam.ac.uk/~mgk25/ucs/wcwidth.c),
+in decreasing order of importance:
+  - the soft hyphen (U+00AD) is narrow
+  - Hangul Jamo medial vowels and final consonants (U+1160..=U+11FF and,
+    in Hangul Jamo Extended-B, U+D7B0..=U+D7FF) are zero width
+  - everything in the `Cc`, `Cf`, `Mn` and `Me` general categories is zero
+    width
+  - East Asian Width `A` is ambiguous (narrow, unless overridden)
+  - East Asian Width `W` and `F` is wide
+  - everything else is narrow
+"""
+
+import os
+import re
+import sys
+import urllib.request
+
+UNICODE_VERSION = (15, 1, 0)
+UCD_URL = "https://www.unicode.org/Public/{}.{}.{}/ucd/".format(*UNICODE_VERSION)
+
+SCRIPTS_DIR = os.path.dirname(os.path.abspath(__file__))
+UCD_DIR = os.path.join(SCRIPTS_DIR, "ucd")
+OUT_FILE = os.path.join(SCRIPTS_DIR, "..", "src", "ext", "unicode", "tables.rs")
+
+NUM_CODEPOINTS = 0x110000
+
+RANGE = re.compile(r"^([0-9A-F]+)(?:\.\.([0-9A-F]+))?\s*;\s*(\w+)")
+
+
+def fetch(path):
+    """Returns the lines of the UCD file at `path`, downloading it first if
+    needed."""
+    local = os.path.join(UCD_DIR, os.path.basename(path))
+    if not os.path.exists(local):
+        os.makedirs(UCD_DIR, exist_ok=True)
+        print(f"fetching {path}", file=sys.stderr)
+        urllib.request.urlretrieve(UCD_URL + path, local)
+
+    with open(local, encoding="utf-8") as f:
+        return f.readlines()
+
+
+def ranges(lines):
+    """Yields `(low, high, value)` for each `XXXX(..YYYY) ; Value` line."""
+    for line in lines:
+        if match := RANGE.match(line):
+            low = int(match.group(1), 16)
+            high = int(match.group(2) or match.group(1), 16)
+            yield (low, high, match.group(3))
+
+
+def load_general_categories():
+    """Returns a list of general categories, indexed by codepoint."""
+    categories = ["Cn"] * NUM_CODEPOINTS
+    first = None
+    for line in fetch("UnicodeData.txt"):
+        fields = line.split(";")
+        if len(fields) != 15:
+            continue
+        codepoint, name, category = int(fields[0], 16), fields[1], fields[2]
+
+        if name.endswith(", First>"):
+            first = codepoint
+            continue
+        low = first if name.endswith(", Last>") else codepoint
+        for c in range(low, codepoint + 1):
+            categories[c] = category
+        first = None
+
+    return categories
+
+
+def load_east_asian_widths():
+    """Returns a list of East Asian Width classes, indexed by codepoint."""
+    widths = ["N"] * NUM_CODEPOINTS
+    for low, high, width in ranges(fetch("EastAsianWidth.txt")):
+        for c in range(low, high + 1):
+            widths[c] = width
+
+    return widths
+
+
+def load_property(path, prop):
+    """Returns the set of codepoints that have the (binary) property `prop`."""
+    return {
+        c
+        for low, high, value in ranges(fetch(path))
+        if value == prop
+        for c in range(low, high + 1)
+    }
+
+
+def load_values(path):
+    """Returns a dict mapping codepoints to their value for the (enumerated)
+    property in `path`. Codepoints with the default value are left out."""
+    return {
+        c: value
+        for low, high, value in ranges(fetch(path))
+        for c in range(low, high + 1)
+    }
+
+
+def load_widths():
+    """Returns `(zero, wide, ambiguous)` sets of codepoints."""
+    categories = load_general_categories()
+    east_asian_widths = load_east_asian_widths()
+
+    zero = {
+        c
+        for c in range(NUM_CODEPOINTS)
+        if categories[c] in ("Cc", "Cf", "Mn", "Me")
+    }
+    zero.discard(0x00AD)
+    zero.update(range(0x1160, 0x11FF + 1))
+    zero.update(range(0xD7B0, 0xD7FF + 1))
+
+    wide = {
+        c
+        for c in range(NUM_CODEPOINTS)
+        if east_asian_widths[c] in ("W", "F") and c not in zero
+    }
+    ambiguous = {
+        c
+        for c in range(NUM_CODEPOINTS)
+        if east_asian_widths[c] == "A" and c not in zero
+    }
+
+    return (zero, wide, ambiguous)
+
+
+def to_ranges(codepoints):
+    """Collapses a set of codepoints into sorted, inclusive `(low, high)`
+    ranges."""
+    out = []
+    for c in sorted(codepoints):
+        if out and out[-1][1] == c - 1:
+            out[-1] = (out[-1][0], c)
+        else:
+            out.append((c, c))
+
+    return out
+
+
+def rust_hex(codepoint):
+    """Formats a codepoint as a Rust hex literal, with separators if it's long
+    enough to need them."""
+    digits = f"{codepoint:04X}"
+    if len(digits) > 5:
+        digits = f"{codepoint:08X}"
+        digits = f"{digits[:4]}_{digits[4:]}"
+    return f"0x{digits}"
+
+
+def emit_table(out, name, doc, codepoints):
+    out.write(f"/// {doc}\n")
+    out.write(f"pub(super) const {name}: &[(u32, u32)] = &[\n")
+    for low, high in to_ranges(codepoints):
+        out.write(f"    ({rust_hex(low)}, {rust_hex(high)}),\n")
+    out.write("];\n")
+
+
+# Grapheme_Cluster_Break values, and the `GraphemeBreak` variants they map to.
+GRAPHEME_BREAKS = {
+    "CR": "Cr",
+    "LF": "Lf",
+    "Control": "Control",
+    "Extend": "Extend",
+    "ZWJ": "Zwj",
+    "Regional_Indicator": "RegionalIndicator",
+    "Prepend": "Prepend",
+    "SpacingMark": "SpacingMark",
+    "L": "L",
+    "V": "V",
+    "T": "T",
+    "LV": "Lv",
+    "LVT": "Lvt",
+}
+
+
+def emit_value_table(out, name, doc, ty, values):
+    out.write(f"/// {doc}\n")
+    out.write(f"pub(super) const {name}: &[(u32, u32, {ty})] = &[\n")
+
+    run = None
+    for c, value in sorted(values.items()):
+        if run and run[1] == c - 1 and run[2] == value:
+            run = (run[0], c, value)
+            continue
+        if run:
+            out.write(f"    ({rust_hex(run[0])}, {rust_hex(run[1])}, {ty}::{run[2]}),\n")
+        run = (c, c, value)
+    if run:
+        out.write(f"    ({rust_hex(run[0])}, {rust_hex(run[1])}, {ty}::{run[2]}),\n")
+
+    out.write("];\n")
+
+
+def emit(tables, value_tables):
+    """Writes out `tables`, a list of `(name, doc, codepoints)`, and
+    `value_tables`, a list of `(name, doc, type, {codepoint: variant})`."""
+    with open(OUT_FILE, "w", encoding="utf-8", newline="\n") as out:
+        out.write("// Generated by `scripts/unicode.py`; do not edit by hand!\n")
+        out.write("\n")
+        out.write("//! Unicode property tables.\n")
+        out.write("//!\n")
+        out.write(
+            "//! Each table is a sorted list of inclusive codepoint ranges.\n"
+        )
+        out.write("\n")
+        out.write("use super::graphemes::GraphemeBreak;\n")
+        out.write("\n")
+        out.write("/// The version of Unicode these tables were generated from.\n")
+        out.write(
+            "pub(super) const UNICODE_VERSION: (u8, u8, u8) = ({}, {}, {});\n".format(
+                *UNICODE_VERSION
+            )
+        )
+
+        for name, doc, codepoints in tables:
+            out.write("\n")
+            emit_table(out, name, doc, codepoints)
+
+        for name, doc, ty, values in value_tables:
+            out.write("\n")
+            emit_value_table(out, name, doc, ty, values)
+
+
+def main():
+    zero, wide, ambiguous = load_widths()
+    extended_pictographic = load_property(
+        "emoji/emoji-data.txt", "Extended_Pictographic"
+    )
+    grapheme_breaks = {
+        c: GRAPHEME_BREAKS[value]
+        for c, value in load_values(
+            "auxiliary/GraphemeBreakProperty.txt"
+        ).items()
+    }
+
+    emit(
+        [
+            ("ZERO_WIDTH", "Zero width codepoints.", zero),
+            ("WIDE", "East Asian Width `W` and `F` codepoints.", wide),
+            ("AMBIGUOUS", "East Asian Width `A` codepoints.", ambiguous),
+            (
+                "EXTENDED_PICTOGRAPHIC",
+                "`Extended_Pictographic` codepoints (emoji and friends).",
+                extended_pictographic,
+            ),
+        ],
+        [
+            (
+                "GRAPHEME_BREAK",
+                "`Grapheme_Cluster_Break` values, other than `Other`.",
+                "GraphemeBreak",
+                grapheme_breaks,
+            ),
+        ],
+    )
+    print(f"wrote {os.path.normpath(OUT_FILE)}", file=sys.stderr)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,50 @@
+//! Bindings for [`@xterm/addon-attach`].
+//!
+//! [`@xterm/addon-attach`]: https://www.npmjs.com/package/@xterm/addon-attach
+
+use crate::xterm::{options::wasm_struct, Disposable, TerminalAddon};
+
+use wasm_bindgen::prelude::*;
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Options for the [`AttachAddon`].
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct AttachOptions {
+    /// Whether input should be written to the backend. Defaults to `true`.
+    #[wasm_bindgen(js_name = bidirectional)]
+    pub bidirectional: Option<bool>,
+}}
+
+#[cfg_attr(feature = "xterm-5", wasm_bindgen(module = "@xterm/addon-attach"))]
+#[cfg_attr(feature = "xterm-4", wasm_bindgen(module = "xterm-addon-attach"))]
+extern "C" {
+    /// An addon that attaches the terminal to a [`WebSocket`]: data from the
+    /// socket is written to the terminal and (if `bidirectional`) terminal
+    /// input is sent to the socket.
+    ///
+    /// The socket's messages are written to the terminal as is, so this only
+    /// works with servers that send raw PTY output; for servers that frame
+    /// their messages (like ttyd or gotty) see [`PtyConnection`] (with the
+    /// `ext` feature).
+    ///
+    /// [`WebSocket`]: web_sys::WebSocket
+    /// [`PtyConnection`]: crate::ext::pty::PtyConnection
+    #[wasm_bindgen(extends = TerminalAddon, extends = Disposable)]
+    #[derive(Debug, Clone)]
+    pub type AttachAddon;
+
+    /// Creates a new [`AttachAddon`] for the given socket.
+    ///
+    /// Takes:
+    ///   - `socket`: The socket to attach the terminal to.
+    ///   - `options`: Options for the addon.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        socket: &web_sys::WebSocket,
+        options: Option<AttachOptions>,
+    ) -> AttachAddon;
+}
@@ -0,0 +1,61 @@
+//! Bindings for [`@xterm/addon-fit`].
+//!
+//! [`@xterm/addon-fit`]: https://www.npmjs.com/package/@xterm/addon-fit
+
+use crate::xterm::{Disposable, TerminalAddon};
+
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(feature = "xterm-5", wasm_bindgen(module = "@xterm/addon-fit"))]
+#[cfg_attr(feature = "xterm-4", wasm_bindgen(module = "xterm-addon-fit"))]
+extern "C" {
+    /// Corresponds to `{ rows: number, cols: number }`.
+    ///
+    /// Produced by [`FitAddon::propose_dimensions`].
+    ///
+    /// (This is a [duck-typed interface]).
+    ///
+    /// [duck-typed interface]: https://rustwasm.github.io/docs/wasm-bindgen/reference/working-with-duck-typed-interfaces.html
+    #[derive(Debug, Clone)]
+    pub type TerminalDimensions;
+
+    /// Gets the number of rows that would fit.
+    #[wasm_bindgen(structural, method, getter = rows)]
+    pub fn rows(this: &TerminalDimensions) -> u16;
+
+    /// Gets the number of columns that would fit.
+    #[wasm_bindgen(structural, method, getter = cols)]
+    pub fn cols(this: &TerminalDimensions) -> u16;
+}
+
+#[cfg_attr(feature = "xterm-5", wasm_bindgen(module = "@xterm/addon-fit"))]
+#[cfg_attr(feature = "xterm-4", wasm_bindgen(module = "xterm-addon-fit"))]
+extern "C" {
+    /// An addon that resizes the terminal to fill its parent element.
+    ///
+    /// See [`FitAddon::fit_on_resize`] (if the `ext` feature is enabled) to
+    /// keep the terminal fitted as its element changes size.
+    ///
+    /// [`FitAddon::fit_on_resize`]: FitAddon::fit_on_resize
+    #[wasm_bindgen(extends = TerminalAddon, extends = Disposable)]
+    #[derive(Debug, Clone)]
+    pub type FitAddon;
+
+    /// Creates a new [`FitAddon`].
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> FitAddon;
+
+    /// Resizes the terminal to the dimensions of its parent element.
+    #[wasm_bindgen(method, js_name = fit)]
+    pub fn fit(this: &FitAddon);
+
+    /// Gets the dimensions the terminal would have after a [`fit`], without
+    /// resizing it.
+    ///
+    /// Returns `None` if the terminal isn't open (or its element isn't
+    /// visible).
+    ///
+    /// [`fit`]: FitAddon::fit
+    #[wasm_bindgen(method, js_name = proposeDimensions)]
+    pub fn propose_dimensions(this: &FitAddon) -> Option<TerminalDimensions>;
+}
@@ -0,0 +1,146 @@
+//! Bindings for [`@xterm/addon-image`].
+//!
+//! The addon draws images sent with the [sixel] and [iTerm2 inline image]
+//! protocols.
+//!
+//! Programs that output images usually ask the terminal for its cell size (in
+//! pixels) first so they can scale their images to fit; this needs the size
+//! reports in [`WindowOptions`] (`get_win_size_pixels`, `get_cell_size_pixels`
+//! and `get_win_size_chars`). The addon turns these on when it's loaded
+//! unless [`ImageAddonOptions::enable_size_reports`] is `false`;
+//! [`Terminal::load_image_addon`] (with the `ext` feature) always turns them
+//! on.
+//!
+//! [`@xterm/addon-image`]: https://www.npmjs.com/package/@xterm/addon-image
+//! [sixel]: https://en.wikipedia.org/wiki/Sixel
+//! [iTerm2 inline image]: https://iterm2.com/documentation-images.html
+//! [`WindowOptions`]: crate::xterm::WindowOptions
+//! [`Terminal::load_image_addon`]: crate::xterm::Terminal::load_image_addon
+
+use crate::xterm::{options::wasm_struct, Disposable, TerminalAddon};
+
+use wasm_bindgen::prelude::*;
+use web_sys::HtmlCanvasElement;
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// Options for the [`ImageAddon`].
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct ImageAddonOptions {
+    /// Whether to turn on the size reports in the terminal's
+    /// [`WindowOptions`](crate::xterm::WindowOptions) when the addon is
+    /// loaded. Defaults to `true`.
+    #[wasm_bindgen(js_name = enableSizeReports)]
+    pub enable_size_reports: Option<bool>,
+
+    /// The maximum number of pixels in a single image. Larger images are
+    /// dropped. Defaults to 16777216 (4096 × 4096).
+    #[wasm_bindgen(js_name = pixelLimit)]
+    pub pixel_limit: Option<u32>,
+
+    /// The maximum amount of memory (in MB) used to store images; the oldest
+    /// images are evicted once this is exceeded. Defaults to 128.
+    #[wasm_bindgen(js_name = storageLimit)]
+    pub storage_limit: Option<f64>,
+
+    /// Whether to draw a placeholder where an evicted image used to be.
+    /// Defaults to `true`.
+    #[wasm_bindgen(js_name = showPlaceholder)]
+    pub show_placeholder: Option<bool>,
+
+    /// Whether to handle sixel images. Defaults to `true`.
+    #[wasm_bindgen(js_name = sixelSupport)]
+    pub sixel_support: Option<bool>,
+
+    /// Whether sixel images scroll with the text (as opposed to being drawn
+    /// at the top left of the screen). Defaults to `true`.
+    #[wasm_bindgen(js_name = sixelScrolling)]
+    pub sixel_scrolling: Option<bool>,
+
+    /// The maximum number of palette registers a sixel image can use.
+    /// Defaults to 256, can be at most 4096.
+    #[wasm_bindgen(js_name = sixelPaletteLimit)]
+    pub sixel_palette_limit: Option<u16>,
+
+    /// The maximum size (in bytes) of a sixel sequence. Defaults to
+    /// 25000000.
+    #[wasm_bindgen(js_name = sixelSizeLimit)]
+    pub sixel_size_limit: Option<u32>,
+
+    /// Whether to handle iTerm2 inline images (IIP). Defaults to `true`.
+    #[wasm_bindgen(js_name = iipSupport)]
+    pub iip_support: Option<bool>,
+
+    /// The maximum size (in bytes) of an IIP sequence. Defaults to 20000000.
+    #[wasm_bindgen(js_name = iipSizeLimit)]
+    pub iip_size_limit: Option<u32>,
+}}
+
+#[wasm_bindgen(module = "@xterm/addon-image")]
+extern "C" {
+    /// An addon that draws sixel and iTerm2 inline images.
+    ///
+    /// See the [module docs](self) for the window options this needs.
+    #[wasm_bindgen(extends = TerminalAddon, extends = Disposable)]
+    #[derive(Debug, Clone)]
+    pub type ImageAddon;
+
+    /// Creates a new [`ImageAddon`].
+    ///
+    /// Takes:
+    ///   - `options`: Options for the addon.
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: Option<ImageAddonOptions>) -> ImageAddon;
+
+    /// Clears all images and resets the image decoders.
+    #[wasm_bindgen(method, js_name = reset)]
+    pub fn reset(this: &ImageAddon);
+
+    /// Gets the storage limit (in MB).
+    #[wasm_bindgen(method, getter = storageLimit)]
+    pub fn storage_limit(this: &ImageAddon) -> f64;
+
+    /// Sets the storage limit (in MB). Lowering the limit evicts images
+    /// right away if needed.
+    #[wasm_bindgen(method, setter = storageLimit)]
+    pub fn set_storage_limit(this: &ImageAddon, limit: f64);
+
+    /// Gets the amount of memory (in MB) currently used to store images.
+    #[wasm_bindgen(method, getter = storageUsage)]
+    pub fn storage_usage(this: &ImageAddon) -> f64;
+
+    /// Gets whether placeholders are drawn for evicted images.
+    #[wasm_bindgen(method, getter = showPlaceholder)]
+    pub fn show_placeholder(this: &ImageAddon) -> bool;
+
+    /// Sets whether placeholders are drawn for evicted images.
+    #[wasm_bindgen(method, setter = showPlaceholder)]
+    pub fn set_show_placeholder(this: &ImageAddon, show: bool);
+
+    /// Gets the (whole) image covering a buffer cell, if there is one.
+    ///
+    /// Takes:
+    ///   - `x`: The cell's column.
+    ///   - `y`: The cell's line in the buffer.
+    #[wasm_bindgen(method, js_name = getImageAtBufferCell)]
+    pub fn get_image_at_buffer_cell(
+        this: &ImageAddon,
+        x: u32,
+        y: u32,
+    ) -> Option<HtmlCanvasElement>;
+
+    /// Gets the part of an image that covers a buffer cell, if there is one.
+    ///
+    /// Takes:
+    ///   - `x`: The cell's column.
+    ///   - `y`: The cell's line in the buffer.
+    #[wasm_bindgen(method, js_name = extractTileAtBufferCell)]
+    pub fn extract_tile_at_buffer_cell(
+        this: &ImageAddon,
+        x: u32,
+        y: u32,
+    ) -> Option<HtmlCanvasElement>;
+}
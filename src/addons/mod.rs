@@ -0,0 +1,38 @@
+//! Bindings for the first party xterm.js addons.
+//!
+//! Each addon lives behind its own feature and binds the corresponding
+//! `@xterm/addon-*` npm package (or, with the `xterm-4` feature, the
+//! `xterm-addon-*` package); make sure your bundler can find the package for
+//! every addon you enable. The image addon needs xterm.js 5.
+//!
+//! All the addons here [extend](TerminalAddon) [`TerminalAddon`] and can be
+//! loaded with [`Terminal::load_addon`] or, with the `ext` feature,
+//! [`Terminal::load_xterm_addon`].
+//!
+//! [`TerminalAddon`]: crate::xterm::TerminalAddon
+//! [`Terminal::load_addon`]: crate::xterm::Terminal::load_addon
+//! [`Terminal::load_xterm_addon`]: crate::xterm::Terminal::load_xterm_addon
+
+#[cfg(feature = "addon-attach")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-attach")))]
+pub mod attach;
+
+#[cfg(feature = "addon-fit")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-fit")))]
+pub mod fit;
+
+#[cfg(feature = "addon-image")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-image")))]
+pub mod image;
+
+#[cfg(feature = "addon-search")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-search")))]
+pub mod search;
+
+#[cfg(feature = "addon-serialize")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-serialize")))]
+pub mod serialize;
+
+#[cfg(feature = "addon-unicode11")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-unicode11")))]
+pub mod unicode11;
@@ -0,0 +1,185 @@
+//! Bindings for [`@xterm/addon-search`].
+//!
+//! [`@xterm/addon-search`]: https://www.npmjs.com/package/@xterm/addon-search
+
+use crate::xterm::{options::wasm_struct, Disposable, Str, TerminalAddon};
+
+use wasm_bindgen::prelude::*;
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Options for the [`SearchAddon`] itself.
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct SearchAddonOptions {
+    /// The maximum number of matches to highlight. Defaults to 1000.
+    #[wasm_bindgen(js_name = highlightLimit)]
+    pub highlight_limit: Option<u32>,
+}}
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Colors used to highlight matches.
+///
+/// Setting these (via [`SearchOptions::decorations`]) is what makes the addon
+/// highlight every match rather than just selecting the active one.
+///
+/// Colors must be in the `#RRGGBB` format.
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct SearchDecorationOptions {
+    /// The background color of a match.
+    |clone(set = set_match_background, js_name = matchBackground)
+    match_background: Option<Str>,
+
+    /// The border color of a match.
+    |clone(set = set_match_border, js_name = matchBorder)
+    match_border: Option<Str>,
+
+    /// The overview ruler color of a match.
+    |clone(set = set_match_overview_ruler, js_name = matchOverviewRuler)
+    match_overview_ruler: Option<Str>,
+
+    /// The background color of the active match.
+    |clone(set = set_active_match_background, js_name = activeMatchBackground)
+    active_match_background: Option<Str>,
+
+    /// The border color of the active match.
+    |clone(set = set_active_match_border, js_name = activeMatchBorder)
+    active_match_border: Option<Str>,
+
+    /// The overview ruler color of the active match.
+    |clone(
+        set = set_active_match_color_overview_ruler,
+        js_name = activeMatchColorOverviewRuler
+    )
+    active_match_color_overview_ruler: Option<Str>,
+}}
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+/// Options for a single search.
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct SearchOptions {
+    /// Whether the search term is a regex.
+    #[wasm_bindgen(js_name = regex)]
+    pub regex: Option<bool>,
+
+    /// Whether to search for a whole word; the result is only valid if it's
+    /// surrounded by "non-word" characters such as `_`, `(`, `)` or space.
+    #[wasm_bindgen(js_name = wholeWord)]
+    pub whole_word: Option<bool>,
+
+    /// Whether the search is case sensitive.
+    #[wasm_bindgen(js_name = caseSensitive)]
+    pub case_sensitive: Option<bool>,
+
+    /// Whether to do an incremental search; this will expand the selection if
+    /// it still matches the term the user typed. Note that this only affects
+    /// [`SearchAddon::find_next`], not [`SearchAddon::find_previous`].
+    #[wasm_bindgen(js_name = incremental)]
+    pub incremental: Option<bool>,
+
+    /// When set, matches are highlighted with these colors.
+    |clone(set = set_decorations, js_name = decorations)
+    decorations: Option<SearchDecorationOptions>,
+}}
+
+#[cfg_attr(feature = "xterm-5", wasm_bindgen(module = "@xterm/addon-search"))]
+#[cfg_attr(feature = "xterm-4", wasm_bindgen(module = "xterm-addon-search"))]
+extern "C" {
+    /// Corresponds to `{ resultIndex: number, resultCount: number }`.
+    ///
+    /// Produced by [`SearchAddon::on_did_change_results`].
+    ///
+    /// (This is a [duck-typed interface]).
+    ///
+    /// [duck-typed interface]: https://rustwasm.github.io/docs/wasm-bindgen/reference/working-with-duck-typed-interfaces.html
+    #[derive(Debug, Clone)]
+    pub type SearchResults;
+
+    /// Gets the index of the active match. This is -1 when there's no active
+    /// match or when there are more matches than the highlight limit.
+    ///
+    /// See [`get_result_index`] (if the `ext` feature is enabled) for a
+    /// version of this that returns an `Option`.
+    ///
+    /// [`get_result_index`]: SearchResults::get_result_index
+    #[wasm_bindgen(structural, method, getter = resultIndex)]
+    pub fn result_index(this: &SearchResults) -> i32;
+
+    /// Gets the number of matches.
+    #[wasm_bindgen(structural, method, getter = resultCount)]
+    pub fn result_count(this: &SearchResults) -> u32;
+}
+
+#[cfg_attr(feature = "xterm-5", wasm_bindgen(module = "@xterm/addon-search"))]
+#[cfg_attr(feature = "xterm-4", wasm_bindgen(module = "xterm-addon-search"))]
+extern "C" {
+    /// An addon that searches the terminal's buffer.
+    #[wasm_bindgen(extends = TerminalAddon, extends = Disposable)]
+    #[derive(Debug, Clone)]
+    pub type SearchAddon;
+
+    /// Creates a new [`SearchAddon`].
+    #[wasm_bindgen(constructor)]
+    pub fn new(options: Option<SearchAddonOptions>) -> SearchAddon;
+
+    /// Searches forwards for the next match, selecting it.
+    ///
+    /// Takes:
+    ///   - `term`: The search term.
+    ///   - `options`: Options for the search.
+    ///
+    /// Returns whether a match was found.
+    #[wasm_bindgen(method, js_name = findNext)]
+    pub fn find_next(
+        this: &SearchAddon,
+        term: &str,
+        options: Option<SearchOptions>,
+    ) -> bool;
+
+    /// Searches backwards for the previous match, selecting it.
+    ///
+    /// Takes:
+    ///   - `term`: The search term.
+    ///   - `options`: Options for the search.
+    ///
+    /// Returns whether a match was found.
+    #[wasm_bindgen(method, js_name = findPrevious)]
+    pub fn find_previous(
+        this: &SearchAddon,
+        term: &str,
+        options: Option<SearchOptions>,
+    ) -> bool;
+
+    /// Clears the highlighted matches and the active match.
+    #[wasm_bindgen(method, js_name = clearDecorations)]
+    pub fn clear_decorations(this: &SearchAddon);
+
+    /// Clears the active match (but not the other highlighted matches).
+    #[wasm_bindgen(method, js_name = clearActiveDecoration)]
+    pub fn clear_active_decoration(this: &SearchAddon);
+
+    /// Adds an event listener for when the search results change. This only
+    /// fires when [`SearchOptions::decorations`] is set.
+    ///
+    /// Returns a [`Disposable`] to stop listening.
+    ///
+    /// See [`attach_results_change_event_listener`] (if the `ext` feature is
+    /// enabled) for a friendlier version of this function.
+    ///
+    /// [`attach_results_change_event_listener`]: SearchAddon::attach_results_change_event_listener
+    #[wasm_bindgen(method, js_name = onDidChangeResults)]
+    pub fn on_did_change_results(
+        this: &SearchAddon,
+        listener: &Closure<dyn FnMut(SearchResults)>,
+    ) -> Disposable;
+}
@@ -0,0 +1,151 @@
+//! Bindings for [`@xterm/addon-serialize`].
+//!
+//! [`@xterm/addon-serialize`]: https://www.npmjs.com/package/@xterm/addon-serialize
+
+use crate::xterm::{options::wasm_struct, Disposable, Str, TerminalAddon};
+
+use wasm_bindgen::prelude::*;
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// A range of buffer lines to serialize.
+///
+/// xterm.js also accepts [markers] here; use [`Marker::line`] to get the line
+/// a marker is on.
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+///
+/// [markers]: crate::xterm::Marker
+/// [`Marker::line`]: crate::xterm::Marker::line
+pub struct SerializeRange {
+    /// The first line to serialize.
+    #[wasm_bindgen(js_name = start)]
+    pub start: u32,
+
+    /// The last line to serialize (inclusive).
+    #[wasm_bindgen(js_name = end)]
+    pub end: u32,
+}}
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Options for [`SerializeAddon::serialize`].
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct SerializeOptions {
+    /// The range of lines to serialize. Takes precedence over `scrollback`.
+    #[wasm_bindgen(js_name = range)]
+    pub range: Option<SerializeRange>,
+
+    /// The number of scrollback rows to serialize, starting from the bottom
+    /// of the scrollback. When not set, all of the scrollback is serialized.
+    #[wasm_bindgen(js_name = scrollback)]
+    pub scrollback: Option<u32>,
+
+    /// Whether to leave out the sequences that restore the terminal's modes
+    /// (bracketed paste, application cursor keys, etc.).
+    #[wasm_bindgen(js_name = excludeModes)]
+    pub exclude_modes: Option<bool>,
+
+    /// Whether to leave out the alternate buffer.
+    #[wasm_bindgen(js_name = excludeAltBuffer)]
+    pub exclude_alt_buffer: Option<bool>,
+}}
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// A range of the buffer to serialize as HTML.
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct HtmlSerializeRange {
+    /// The first line to serialize.
+    #[wasm_bindgen(js_name = startLine)]
+    pub start_line: u32,
+
+    /// The last line to serialize (inclusive).
+    #[wasm_bindgen(js_name = endLine)]
+    pub end_line: u32,
+
+    /// The column of the first line to start at.
+    #[wasm_bindgen(js_name = startCol)]
+    pub start_col: u32,
+}}
+
+wasm_struct! {
+#[wasm_bindgen(inspectable)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Options for [`SerializeAddon::serialize_as_html`].
+///
+/// (This is really an interface, but we just go and define our own type that
+/// satisfies the interface.)
+pub struct HtmlSerializeOptions {
+    /// The number of scrollback rows to serialize, starting from the bottom
+    /// of the scrollback. When not set, all of the scrollback is serialized.
+    #[wasm_bindgen(js_name = scrollback)]
+    pub scrollback: Option<u32>,
+
+    /// Whether to only serialize the current selection. Defaults to `false`.
+    #[wasm_bindgen(js_name = onlySelection)]
+    pub only_selection: Option<bool>,
+
+    /// Whether to include the terminal's background color on the outermost
+    /// element. Defaults to `false`.
+    #[wasm_bindgen(js_name = includeGlobalBackground)]
+    pub include_global_background: Option<bool>,
+
+    /// The range to serialize. Takes precedence over `only_selection` and
+    /// `scrollback`.
+    #[wasm_bindgen(js_name = range)]
+    pub range: Option<HtmlSerializeRange>,
+}}
+
+#[cfg_attr(
+    feature = "xterm-5",
+    wasm_bindgen(module = "@xterm/addon-serialize")
+)]
+#[cfg_attr(feature = "xterm-4", wasm_bindgen(module = "xterm-addon-serialize"))]
+extern "C" {
+    /// An addon that serializes the terminal's buffer (contents, attributes
+    /// and, optionally, modes) into a string of escape sequences that will
+    /// reproduce it when written to a terminal.
+    ///
+    /// See [`SessionSnapshot`] (with the `ext` feature) for something that
+    /// also keeps track of the terminal's options and size.
+    ///
+    /// [`SessionSnapshot`]: crate::ext::session::SessionSnapshot
+    #[wasm_bindgen(extends = TerminalAddon, extends = Disposable)]
+    #[derive(Debug, Clone)]
+    pub type SerializeAddon;
+
+    /// Creates a new [`SerializeAddon`].
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> SerializeAddon;
+
+    /// Serializes the terminal's buffer into a string that can be written
+    /// back to a terminal to restore it.
+    ///
+    /// Takes:
+    ///   - `options`: What to serialize.
+    #[wasm_bindgen(method, js_name = serialize)]
+    pub fn serialize(
+        this: &SerializeAddon,
+        options: Option<SerializeOptions>,
+    ) -> Str;
+
+    /// Serializes the terminal's buffer as HTML (i.e. for copying to the
+    /// clipboard with formatting intact).
+    ///
+    /// Takes:
+    ///   - `options`: What to serialize.
+    #[wasm_bindgen(method, js_name = serializeAsHTML)]
+    pub fn serialize_as_html(
+        this: &SerializeAddon,
+        options: Option<HtmlSerializeOptions>,
+    ) -> Str;
+}
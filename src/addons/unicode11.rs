@@ -0,0 +1,35 @@
+//! Bindings for [`@xterm/addon-unicode11`].
+//!
+//! [`@xterm/addon-unicode11`]: https://www.npmjs.com/package/@xterm/addon-unicode11
+
+use crate::xterm::{Disposable, TerminalAddon};
+
+use wasm_bindgen::prelude::*;
+
+#[cfg_attr(
+    feature = "xterm-5",
+    wasm_bindgen(module = "@xterm/addon-unicode11")
+)]
+#[cfg_attr(feature = "xterm-4", wasm_bindgen(module = "xterm-addon-unicode11"))]
+extern "C" {
+    /// An addon that registers Unicode version 11 character widths under the
+    /// version name `"11"`.
+    ///
+    /// Loading the addon doesn't switch the terminal over; do that with
+    /// [`UnicodeHandling::set_active_version`] (i.e.
+    /// `terminal.unicode().set_active_version("11".into())` on a
+    /// `ProposedTerminal`, with the `proposed-api` feature).
+    ///
+    /// See [`UnicodeV15`] (with the `ext` feature) for a provider
+    /// implemented in Rust that supports a newer version of Unicode.
+    ///
+    /// [`UnicodeHandling::set_active_version`]: crate::xterm::UnicodeHandling::set_active_version
+    /// [`UnicodeV15`]: crate::ext::unicode::UnicodeV15
+    #[wasm_bindgen(extends = TerminalAddon, extends = Disposable)]
+    #[derive(Debug, Clone)]
+    pub type Unicode11Addon;
+
+    /// Creates a new [`Unicode11Addon`].
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Unicode11Addon;
+}
@@ -0,0 +1,320 @@
+//! Finding out what the loaded xterm.js actually has.
+//!
+//! The bindings are duck-typed: calling a method that the loaded xterm.js
+//! doesn't have (because it's older, or built differently) fails with an
+//! opaque `is not a function` error, and calling a proposed API without
+//! [`allow_proposed_api`] throws. [`Terminal::capabilities`] inspects the JS
+//! object instead, so helpers can fail early with a clear
+//! [`CapabilityError`].
+//!
+//! [`allow_proposed_api`]: crate::xterm::TerminalOptions::allow_proposed_api
+
+use super::options::diff::option_names;
+use crate::xterm::Terminal;
+
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
+
+use core::fmt::{self, Display};
+use std::error::Error;
+
+/// The methods (and properties, like `unicode`) the bindings use, by their
+/// xterm.js names.
+const METHODS: &[&str] = &[
+    "attachCustomKeyEventHandler",
+    #[cfg(feature = "xterm-5")]
+    "attachCustomWheelEventHandler",
+    "blur",
+    "buffer",
+    "clear",
+    "clearSelection",
+    "clearTextureAtlas",
+    "cols",
+    "deregisterCharacterJoiner",
+    "element",
+    "focus",
+    "getSelection",
+    "getSelectionPosition",
+    "hasSelection",
+    #[cfg(feature = "xterm-5")]
+    "input",
+    "loadAddon",
+    "markers",
+    "modes",
+    "open",
+    "options",
+    "parser",
+    "paste",
+    "refresh",
+    "registerCharacterJoiner",
+    "registerMarker",
+    "reset",
+    "resize",
+    "rows",
+    "scrollLines",
+    "scrollToBottom",
+    "scrollToLine",
+    "scrollToTop",
+    "select",
+    "selectAll",
+    "selectLines",
+    "strings",
+    "textarea",
+    "unicode",
+    "write",
+    "writeln",
+];
+
+/// The events the bindings use, by their xterm.js names.
+const EVENTS: &[&str] = &[
+    "onBell",
+    "onBinary",
+    "onCursorMove",
+    "onData",
+    "onKey",
+    "onLineFeed",
+    "onRender",
+    "onResize",
+    "onScroll",
+    "onSelectionChange",
+    "onTitleChange",
+    #[cfg(feature = "xterm-5")]
+    "onWriteParsed",
+];
+
+/// The methods that throw unless
+/// [`allow_proposed_api`](crate::xterm::TerminalOptions::allow_proposed_api)
+/// is on.
+const PROPOSED: &[&str] = &[
+    "deregisterCharacterJoiner",
+    "markers",
+    #[cfg(feature = "xterm-4")]
+    "parser",
+    "registerCharacterJoiner",
+    #[cfg(feature = "xterm-4")]
+    "registerMarker",
+    "unicode",
+];
+
+/// A major version of xterm.js.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum XtermVersion {
+    /// xterm.js 4 (the `xterm-4` feature).
+    V4,
+    /// xterm.js 5 (the `xterm-5` feature).
+    V5,
+}
+
+impl XtermVersion {
+    /// The version the bindings were compiled for.
+    #[cfg(feature = "xterm-4")]
+    pub const ENABLED: Self = Self::V4;
+    /// The version the bindings were compiled for.
+    #[cfg(feature = "xterm-5")]
+    pub const ENABLED: Self = Self::V5;
+}
+
+impl Display for XtermVersion {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::V4 => write!(fmt, "4.x"),
+            Self::V5 => write!(fmt, "5.x"),
+        }
+    }
+}
+
+/// The kinds of [`Terminal`] members [`Capabilities`] covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A method or property (i.e. `registerMarker` or `unicode`).
+    Method,
+    /// An event (i.e. `onWriteParsed`).
+    Event,
+    /// An option (i.e. `windowsPty`).
+    Option,
+}
+
+impl Display for Kind {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Method => write!(fmt, "method"),
+            Self::Event => write!(fmt, "event"),
+            Self::Option => write!(fmt, "option"),
+        }
+    }
+}
+
+/// What a [`Terminal`] has, out of everything the bindings use; see
+/// [`Terminal::capabilities`].
+///
+/// Names are xterm.js's (i.e. `registerMarker`, not `register_marker`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Capabilities {
+    /// The detected major version of xterm.js (`None` if this doesn't look
+    /// like an xterm.js terminal at all).
+    ///
+    /// xterm.js doesn't say what version it is so this is worked out from
+    /// what's there: 4.x has `setOption`, which 5.0 removed.
+    pub version: Option<XtermVersion>,
+    /// Whether
+    /// [`allow_proposed_api`](crate::xterm::TerminalOptions::allow_proposed_api)
+    /// is on.
+    pub proposed_api: bool,
+    /// The methods (and properties) that exist.
+    pub methods: Vec<&'static str>,
+    /// The events that exist.
+    pub events: Vec<&'static str>,
+    /// The options that exist.
+    pub options: Vec<&'static str>,
+}
+
+impl Capabilities {
+    /// Whether the terminal has the given member.
+    #[must_use]
+    pub fn has(&self, kind: Kind, name: &str) -> bool {
+        self.names(kind).contains(&name)
+    }
+
+    /// Whether the given method is a proposed API (i.e. one that throws
+    /// unless [`proposed_api`](Capabilities::proposed_api) is on).
+    #[must_use]
+    pub fn is_proposed(name: &str) -> bool {
+        PROPOSED.contains(&name)
+    }
+
+    /// The members the bindings use that the terminal doesn't have.
+    pub fn missing(&self) -> impl Iterator<Item = (Kind, &'static str)> + '_ {
+        [(Kind::Method, METHODS), (Kind::Event, EVENTS)]
+            .into_iter()
+            .flat_map(|(kind, names)| names.iter().map(move |n| (kind, *n)))
+            .chain(option_names().map(|name| (Kind::Option, name)))
+            .filter(|(kind, name)| !self.has(*kind, name))
+    }
+
+    /// Checks that the terminal has the given member and, for proposed
+    /// APIs, that [`proposed_api`](Capabilities::proposed_api) is on.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the member is missing or is a proposed API that isn't
+    /// allowed.
+    pub fn require(
+        &self,
+        kind: Kind,
+        name: &'static str,
+    ) -> Result<(), CapabilityError> {
+        if !self.has(kind, name) {
+            Err(CapabilityError::Missing {
+                kind,
+                name,
+                version: self.version,
+            })
+        } else if kind == Kind::Method
+            && Self::is_proposed(name)
+            && !self.proposed_api
+        {
+            Err(CapabilityError::NeedsProposedApi { name })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// The names of the members of the given kind that exist.
+    fn names(&self, kind: Kind) -> &[&'static str] {
+        match kind {
+            Kind::Method => &self.methods,
+            Kind::Event => &self.events,
+            Kind::Option => &self.options,
+        }
+    }
+}
+
+/// Error produced when a [`Terminal`] can't do something; see
+/// [`Capabilities::require`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub enum CapabilityError {
+    /// The loaded xterm.js doesn't have the member.
+    Missing {
+        /// What the member is.
+        kind: Kind,
+        /// The member's xterm.js name.
+        name: &'static str,
+        /// The detected version of xterm.js.
+        version: Option<XtermVersion>,
+    },
+    /// The method is a proposed API and
+    /// [`allow_proposed_api`](crate::xterm::TerminalOptions::allow_proposed_api)
+    /// is off.
+    NeedsProposedApi {
+        /// The method's xterm.js name.
+        name: &'static str,
+    },
+}
+
+impl Display for CapabilityError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing {
+                kind,
+                name,
+                version: Some(version),
+            } => write!(
+                fmt,
+                "the loaded xterm.js ({version}) has no `{name}` {kind}"
+            ),
+            Self::Missing {
+                kind,
+                name,
+                version: None,
+            } => write!(fmt, "the loaded xterm.js has no `{name}` {kind}"),
+            Self::NeedsProposedApi { name } => write!(
+                fmt,
+                "`{name}` is a proposed API; turn on `allowProposedApi` to \
+                 use it"
+            ),
+        }
+    }
+}
+
+impl Error for CapabilityError {}
+
+impl Terminal {
+    /// Inspects the JS terminal to find out which of the methods, events and
+    /// options the bindings use it actually has, whether proposed APIs are
+    /// allowed, and which version of xterm.js it is.
+    ///
+    /// This doesn't call anything (so it doesn't trip over proposed APIs).
+    #[must_use]
+    pub fn capabilities(&self) -> Capabilities {
+        let has = |target: &JsValue, name: &str| {
+            Reflect::has(target, &JsValue::from_str(name)).unwrap_or(false)
+        };
+        let this: &JsValue = self.as_ref();
+
+        let options = if has(this, "options") {
+            Reflect::get(this, &"options".into()).unwrap_or(JsValue::UNDEFINED)
+        } else {
+            JsValue::UNDEFINED
+        };
+
+        let version = if !has(this, "write") {
+            None
+        } else if has(this, "setOption") {
+            Some(XtermVersion::V4)
+        } else {
+            Some(XtermVersion::V5)
+        };
+
+        Capabilities {
+            version,
+            proposed_api: Reflect::get(&options, &"allowProposedApi".into())
+                .ok()
+                .and_then(|value| value.as_bool())
+                .unwrap_or(false),
+            methods: METHODS.iter().copied().filter(|n| has(this, n)).collect(),
+            events: EVENTS.iter().copied().filter(|n| has(this, n)).collect(),
+            options: option_names().filter(|n| has(&options, n)).collect(),
+        }
+    }
+}
@@ -0,0 +1,565 @@
+//! A typed [`Color`], for the colors in a [`Theme`].
+//!
+//! xterm.js takes colors as CSS strings and only finds out that one is
+//! malformed when it goes to render with it (at which point it quietly falls
+//! back to a default). [`Color`] parses the CSS color syntaxes that make
+//! sense for a terminal up front:
+//!   - hex colors: `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`
+//!   - `rgb()` and `rgba()`, with commas or spaces (`rgb(0 0 0 / 50%)`)
+//!   - the CSS named colors (and `transparent`)
+//!
+//! Colors always go back to xterm.js as hex colors.
+//!
+//! [`Theme`]: crate::xterm::Theme
+
+use crate::xterm::Str;
+
+use core::fmt::{self, Display};
+use core::str::FromStr;
+use std::error::Error;
+
+/// A color, as 8 bit RGBA components.
+///
+/// ```rust
+/// # use xterm_js_sys::ext::color::Color;
+/// let color: Color = "rgba(255, 0, 0, 0.5)".parse().unwrap();
+/// assert_eq!(color, Color::rgba(0xFF, 0, 0, 0x80));
+/// assert_eq!(color.to_string(), "#ff000080");
+///
+/// assert_eq!("RebeccaPurple".parse(), Ok(Color::rgb(0x66, 0x33, 0x99)));
+/// assert!("#12345".parse::<Color>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// Red.
+    pub r: u8,
+    /// Green.
+    pub g: u8,
+    /// Blue.
+    pub b: u8,
+    /// Alpha (`0xFF` is opaque).
+    pub a: u8,
+}
+
+impl Color {
+    /// An opaque color.
+    #[must_use]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 0xFF }
+    }
+
+    /// A color that may be transparent.
+    #[must_use]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Looks up a CSS named color (case-insensitively).
+    #[must_use]
+    pub fn named(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        NAMED
+            .binary_search_by_key(&&*name, |&(n, _)| n)
+            .ok()
+            .map(|idx| NAMED[idx].1)
+    }
+
+    /// The same color without any transparency.
+    #[must_use]
+    pub const fn opaque(self) -> Self {
+        Self { a: 0xFF, ..self }
+    }
+
+    /// Formats the color as `#rrggbb` (or `#rrggbbaa`, if it isn't opaque).
+    #[must_use]
+    pub fn to_hex(self) -> Str {
+        self.to_string()
+    }
+
+    /// The color's [relative luminance], from 0 (black) to 1 (white); alpha
+    /// is ignored.
+    ///
+    /// [relative luminance]: https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    #[must_use]
+    pub fn relative_luminance(self) -> f64 {
+        let linear = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.040_45 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        0.2126 * linear(self.r)
+            + 0.7152 * linear(self.g)
+            + 0.0722 * linear(self.b)
+    }
+
+    /// The [contrast ratio] between two colors, from 1 (none) to 21 (black on
+    /// white); alpha is ignored (see [`Color::over`]).
+    ///
+    /// [contrast ratio]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+    #[must_use]
+    pub fn contrast_ratio(self, other: Self) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// The (opaque) color you get by drawing this color over `background`
+    /// (which is taken to be opaque).
+    #[must_use]
+    pub fn over(self, background: Self) -> Self {
+        let alpha = u16::from(self.a);
+        let blend = |fg: u8, bg: u8| {
+            let mixed = u16::from(fg) * alpha + u16::from(bg) * (255 - alpha);
+            // At most 255 * 255, so this fits after dividing.
+            #[allow(clippy::cast_possible_truncation)]
+            let mixed = ((mixed + 127) / 255) as u8;
+            mixed
+        };
+
+        Self::rgb(
+            blend(self.r, background.r),
+            blend(self.g, background.g),
+            blend(self.b, background.b),
+        )
+    }
+
+    /// Lightens or darkens the color (keeping its hue and saturation) as
+    /// little as possible for it to have a contrast ratio of at least
+    /// `ratio` when drawn over `background`.
+    ///
+    /// If no lightness gets there, the color with the most contrast is
+    /// returned instead.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::ext::color::Color;
+    /// let black = Color::rgb(0, 0, 0);
+    /// let blue = Color::rgb(0, 0, 0xCC);
+    /// assert!(blue.contrast_ratio(black) < 4.5);
+    ///
+    /// let adjusted = blue.with_minimum_contrast(black, 4.5);
+    /// assert!(adjusted.contrast_ratio(black) >= 4.5);
+    ///
+    /// // Still the same blue, just lighter.
+    /// assert_eq!(adjusted.r, adjusted.g);
+    /// assert_eq!(adjusted.b, 0xFF);
+    /// ```
+    #[must_use]
+    pub fn with_minimum_contrast(self, background: Self, ratio: f64) -> Self {
+        let background = background.opaque();
+        let contrast = |c: Self| c.over(background).contrast_ratio(background);
+        if contrast(self) >= ratio {
+            return self;
+        }
+
+        let (h, s, l) = self.to_hsl();
+        let at = |l: f64| Self::from_hsl(h, s, l, self.a);
+
+        // Luminance only ever goes up with lightness so the lightness that's
+        // just enough can be searched for, in either direction.
+        let search = |mut from: f64, mut to: f64| {
+            if contrast(at(to)) < ratio {
+                return None;
+            }
+            for _ in 0..20 {
+                let mid = (from + to) / 2.0;
+                if contrast(at(mid)) >= ratio {
+                    to = mid;
+                } else {
+                    from = mid;
+                }
+            }
+
+            Some(to)
+        };
+
+        let lightness = match (search(l, 1.0), search(l, 0.0)) {
+            (Some(up), Some(down)) if up - l <= l - down => up,
+            (_, Some(down)) => down,
+            (Some(up), None) => up,
+            (None, None) if contrast(at(1.0)) >= contrast(at(0.0)) => 1.0,
+            (None, None) => 0.0,
+        };
+
+        at(lightness)
+    }
+
+    /// Mixes in `other`: `t` of 0 is this color, 1 is `other`.
+    #[must_use]
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| {
+            let mixed = f64::from(a) + (f64::from(b) - f64::from(a)) * t;
+
+            // Between `a` and `b`, so this can't truncate or lose the sign.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let mixed = mixed.round() as u8;
+            mixed
+        };
+
+        Self::rgba(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+            mix(self.a, other.a),
+        )
+    }
+
+    /// Converts to hue (in turns), saturation and lightness.
+    #[allow(clippy::many_single_char_names)]
+    pub(super) fn to_hsl(self) -> (f64, f64, f64) {
+        let [r, g, b] = [self.r, self.g, self.b].map(|c| f64::from(c) / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+        #[allow(clippy::float_cmp)] // `max` is one of these exactly.
+        let h = if max == r {
+            ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+
+        (h / 6.0, s, l)
+    }
+
+    /// Converts from hue (in turns), saturation and lightness.
+    #[allow(clippy::many_single_char_names)]
+    pub(super) fn from_hsl(h: f64, s: f64, l: f64, a: u8) -> Self {
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let channel = |n: f64| {
+            let k = (n + h * 12.0) % 12.0;
+            let c = l - chroma / 2.0 * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0);
+
+            // Clamped, so this can't truncate or lose the sign.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let c = (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+            c
+        };
+
+        Self::rgba(channel(0.0), channel(8.0), channel(4.0), a)
+    }
+
+    /// Parses the digits of a hex color (without the `#`).
+    fn parse_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digit = |i: usize| {
+            hex.as_bytes()
+                .get(i)
+                .and_then(|&d| char::from(d).to_digit(16))
+                .and_then(|d| u8::try_from(d).ok())
+                .ok_or(ParseColorError::InvalidHex)
+        };
+        let short = |i: usize| digit(i).map(|d| d * 0x11);
+        let long = |i: usize| Ok(digit(i)? << 4 | digit(i + 1)?);
+
+        match hex.len() {
+            3 => Ok(Self::rgb(short(0)?, short(1)?, short(2)?)),
+            4 => Ok(Self::rgba(short(0)?, short(1)?, short(2)?, short(3)?)),
+            6 => Ok(Self::rgb(long(0)?, long(2)?, long(4)?)),
+            8 => Ok(Self::rgba(long(0)?, long(2)?, long(4)?, long(6)?)),
+            _ => Err(ParseColorError::InvalidHex),
+        }
+    }
+
+    /// Parses the arguments of an `rgb()`/`rgba()` color.
+    fn parse_function(args: &str) -> Result<Self, ParseColorError> {
+        let err = ParseColorError::InvalidFunction;
+
+        let args: Vec<&str> = if args.contains(',') {
+            args.split(',').map(str::trim).collect()
+        } else {
+            // The space separated syntax has a `/` before the alpha.
+            let (rgb, alpha) = match args.split_once('/') {
+                Some((rgb, alpha)) => (rgb, Some(alpha.trim())),
+                None => (args, None),
+            };
+            rgb.split_whitespace().chain(alpha).collect()
+        };
+
+        // Either a number in [0, max] or a percentage.
+        let component = |arg: &str, max: f64| {
+            let value = match arg.strip_suffix('%') {
+                Some(pct) => pct.parse::<f64>().ok()? / 100.0,
+                None => arg.parse::<f64>().ok()? / max,
+            };
+
+            // In range, so this can't truncate or lose the sign.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            (0.0..=1.0)
+                .contains(&value)
+                .then(|| (value * 255.0).round() as u8)
+        };
+
+        match *args {
+            [r, g, b] | [r, g, b, _] => Ok(Self::rgba(
+                component(r, 255.0).ok_or(err)?,
+                component(g, 255.0).ok_or(err)?,
+                component(b, 255.0).ok_or(err)?,
+                args.get(3)
+                    .map_or(Some(0xFF), |a| component(a, 1.0))
+                    .ok_or(err)?,
+            )),
+            _ => Err(err),
+        }
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { r, g, b, a } = *self;
+        write!(fmt, "#{r:02x}{g:02x}{b:02x}")?;
+        if a != 0xFF {
+            write!(fmt, "{a:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parses a hex, `rgb()`/`rgba()` or named color.
+    ///
+    /// Surrounding whitespace isn't allowed.
+    fn from_str(color: &str) -> Result<Self, ParseColorError> {
+        if let Some(hex) = color.strip_prefix('#') {
+            return Self::parse_hex(hex);
+        }
+
+        let function = ["rgba(", "rgb("].iter().find_map(|prefix| {
+            color
+                .get(..prefix.len())
+                .filter(|p| p.eq_ignore_ascii_case(prefix))
+                .map(|_| &color[prefix.len()..])
+        });
+        if let Some(rest) = function {
+            return rest
+                .strip_suffix(')')
+                .ok_or(ParseColorError::InvalidFunction)
+                .and_then(Self::parse_function);
+        }
+
+        Self::named(color).ok_or(ParseColorError::Unknown)
+    }
+}
+
+impl From<Color> for Str {
+    fn from(color: Color) -> Str {
+        color.to_hex()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        d: D,
+    ) -> Result<Self, D::Error> {
+        use serde::de::{Error as _, Unexpected};
+
+        let color = Str::deserialize(d)?;
+        color.parse().map_err(|_| {
+            D::Error::invalid_value(Unexpected::Str(&color), &"a CSS color")
+        })
+    }
+}
+
+/// Error produced when a [`Color`] can't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::module_name_repetitions)]
+pub enum ParseColorError {
+    /// A `#` that isn't followed by 3, 4, 6 or 8 hex digits.
+    InvalidHex,
+    /// An `rgb()`/`rgba()` color without 3 or 4 components, or with a
+    /// component that isn't a number or is out of range.
+    InvalidFunction,
+    /// Something that isn't a hex color, an `rgb()`/`rgba()` color or a CSS
+    /// color name.
+    Unknown,
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHex => write!(fmt, "invalid hex color"),
+            Self::InvalidFunction => {
+                write!(fmt, "invalid `rgb()`/`rgba()` color")
+            }
+            Self::Unknown => write!(fmt, "not a CSS color"),
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+/// The CSS named colors, sorted by name.
+const NAMED: [(&str, Color); 149] = [
+    ("aliceblue", Color::rgb(0xF0, 0xF8, 0xFF)),
+    ("antiquewhite", Color::rgb(0xFA, 0xEB, 0xD7)),
+    ("aqua", Color::rgb(0x00, 0xFF, 0xFF)),
+    ("aquamarine", Color::rgb(0x7F, 0xFF, 0xD4)),
+    ("azure", Color::rgb(0xF0, 0xFF, 0xFF)),
+    ("beige", Color::rgb(0xF5, 0xF5, 0xDC)),
+    ("bisque", Color::rgb(0xFF, 0xE4, 0xC4)),
+    ("black", Color::rgb(0x00, 0x00, 0x00)),
+    ("blanchedalmond", Color::rgb(0xFF, 0xEB, 0xCD)),
+    ("blue", Color::rgb(0x00, 0x00, 0xFF)),
+    ("blueviolet", Color::rgb(0x8A, 0x2B, 0xE2)),
+    ("brown", Color::rgb(0xA5, 0x2A, 0x2A)),
+    ("burlywood", Color::rgb(0xDE, 0xB8, 0x87)),
+    ("cadetblue", Color::rgb(0x5F, 0x9E, 0xA0)),
+    ("chartreuse", Color::rgb(0x7F, 0xFF, 0x00)),
+    ("chocolate", Color::rgb(0xD2, 0x69, 0x1E)),
+    ("coral", Color::rgb(0xFF, 0x7F, 0x50)),
+    ("cornflowerblue", Color::rgb(0x64, 0x95, 0xED)),
+    ("cornsilk", Color::rgb(0xFF, 0xF8, 0xDC)),
+    ("crimson", Color::rgb(0xDC, 0x14, 0x3C)),
+    ("cyan", Color::rgb(0x00, 0xFF, 0xFF)),
+    ("darkblue", Color::rgb(0x00, 0x00, 0x8B)),
+    ("darkcyan", Color::rgb(0x00, 0x8B, 0x8B)),
+    ("darkgoldenrod", Color::rgb(0xB8, 0x86, 0x0B)),
+    ("darkgray", Color::rgb(0xA9, 0xA9, 0xA9)),
+    ("darkgreen", Color::rgb(0x00, 0x64, 0x00)),
+    ("darkgrey", Color::rgb(0xA9, 0xA9, 0xA9)),
+    ("darkkhaki", Color::rgb(0xBD, 0xB7, 0x6B)),
+    ("darkmagenta", Color::rgb(0x8B, 0x00, 0x8B)),
+    ("darkolivegreen", Color::rgb(0x55, 0x6B, 0x2F)),
+    ("darkorange", Color::rgb(0xFF, 0x8C, 0x00)),
+    ("darkorchid", Color::rgb(0x99, 0x32, 0xCC)),
+    ("darkred", Color::rgb(0x8B, 0x00, 0x00)),
+    ("darksalmon", Color::rgb(0xE9, 0x96, 0x7A)),
+    ("darkseagreen", Color::rgb(0x8F, 0xBC, 0x8F)),
+    ("darkslateblue", Color::rgb(0x48, 0x3D, 0x8B)),
+    ("darkslategray", Color::rgb(0x2F, 0x4F, 0x4F)),
+    ("darkslategrey", Color::rgb(0x2F, 0x4F, 0x4F)),
+    ("darkturquoise", Color::rgb(0x00, 0xCE, 0xD1)),
+    ("darkviolet", Color::rgb(0x94, 0x00, 0xD3)),
+    ("deeppink", Color::rgb(0xFF, 0x14, 0x93)),
+    ("deepskyblue", Color::rgb(0x00, 0xBF, 0xFF)),
+    ("dimgray", Color::rgb(0x69, 0x69, 0x69)),
+    ("dimgrey", Color::rgb(0x69, 0x69, 0x69)),
+    ("dodgerblue", Color::rgb(0x1E, 0x90, 0xFF)),
+    ("firebrick", Color::rgb(0xB2, 0x22, 0x22)),
+    ("floralwhite", Color::rgb(0xFF, 0xFA, 0xF0)),
+    ("forestgreen", Color::rgb(0x22, 0x8B, 0x22)),
+    ("fuchsia", Color::rgb(0xFF, 0x00, 0xFF)),
+    ("gainsboro", Color::rgb(0xDC, 0xDC, 0xDC)),
+    ("ghostwhite", Color::rgb(0xF8, 0xF8, 0xFF)),
+    ("gold", Color::rgb(0xFF, 0xD7, 0x00)),
+    ("goldenrod", Color::rgb(0xDA, 0xA5, 0x20)),
+    ("gray", Color::rgb(0x80, 0x80, 0x80)),
+    ("green", Color::rgb(0x00, 0x80, 0x00)),
+    ("greenyellow", Color::rgb(0xAD, 0xFF, 0x2F)),
+    ("grey", Color::rgb(0x80, 0x80, 0x80)),
+    ("honeydew", Color::rgb(0xF0, 0xFF, 0xF0)),
+    ("hotpink", Color::rgb(0xFF, 0x69, 0xB4)),
+    ("indianred", Color::rgb(0xCD, 0x5C, 0x5C)),
+    ("indigo", Color::rgb(0x4B, 0x00, 0x82)),
+    ("ivory", Color::rgb(0xFF, 0xFF, 0xF0)),
+    ("khaki", Color::rgb(0xF0, 0xE6, 0x8C)),
+    ("lavender", Color::rgb(0xE6, 0xE6, 0xFA)),
+    ("lavenderblush", Color::rgb(0xFF, 0xF0, 0xF5)),
+    ("lawngreen", Color::rgb(0x7C, 0xFC, 0x00)),
+    ("lemonchiffon", Color::rgb(0xFF, 0xFA, 0xCD)),
+    ("lightblue", Color::rgb(0xAD, 0xD8, 0xE6)),
+    ("lightcoral", Color::rgb(0xF0, 0x80, 0x80)),
+    ("lightcyan", Color::rgb(0xE0, 0xFF, 0xFF)),
+    ("lightgoldenrodyellow", Color::rgb(0xFA, 0xFA, 0xD2)),
+    ("lightgray", Color::rgb(0xD3, 0xD3, 0xD3)),
+    ("lightgreen", Color::rgb(0x90, 0xEE, 0x90)),
+    ("lightgrey", Color::rgb(0xD3, 0xD3, 0xD3)),
+    ("lightpink", Color::rgb(0xFF, 0xB6, 0xC1)),
+    ("lightsalmon", Color::rgb(0xFF, 0xA0, 0x7A)),
+    ("lightseagreen", Color::rgb(0x20, 0xB2, 0xAA)),
+    ("lightskyblue", Color::rgb(0x87, 0xCE, 0xFA)),
+    ("lightslategray", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightslategrey", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightsteelblue", Color::rgb(0xB0, 0xC4, 0xDE)),
+    ("lightyellow", Color::rgb(0xFF, 0xFF, 0xE0)),
+    ("lime", Color::rgb(0x00, 0xFF, 0x00)),
+    ("limegreen", Color::rgb(0x32, 0xCD, 0x32)),
+    ("linen", Color::rgb(0xFA, 0xF0, 0xE6)),
+    ("magenta", Color::rgb(0xFF, 0x00, 0xFF)),
+    ("maroon", Color::rgb(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", Color::rgb(0x66, 0xCD, 0xAA)),
+    ("mediumblue", Color::rgb(0x00, 0x00, 0xCD)),
+    ("mediumorchid", Color::rgb(0xBA, 0x55, 0xD3)),
+    ("mediumpurple", Color::rgb(0x93, 0x70, 0xDB)),
+    ("mediumseagreen", Color::rgb(0x3C, 0xB3, 0x71)),
+    ("mediumslateblue", Color::rgb(0x7B, 0x68, 0xEE)),
+    ("mediumspringgreen", Color::rgb(0x00, 0xFA, 0x9A)),
+    ("mediumturquoise", Color::rgb(0x48, 0xD1, 0xCC)),
+    ("mediumvioletred", Color::rgb(0xC7, 0x15, 0x85)),
+    ("midnightblue", Color::rgb(0x19, 0x19, 0x70)),
+    ("mintcream", Color::rgb(0xF5, 0xFF, 0xFA)),
+    ("mistyrose", Color::rgb(0xFF, 0xE4, 0xE1)),
+    ("moccasin", Color::rgb(0xFF, 0xE4, 0xB5)),
+    ("navajowhite", Color::rgb(0xFF, 0xDE, 0xAD)),
+    ("navy", Color::rgb(0x00, 0x00, 0x80)),
+    ("oldlace", Color::rgb(0xFD, 0xF5, 0xE6)),
+    ("olive", Color::rgb(0x80, 0x80, 0x00)),
+    ("olivedrab", Color::rgb(0x6B, 0x8E, 0x23)),
+    ("orange", Color::rgb(0xFF, 0xA5, 0x00)),
+    ("orangered", Color::rgb(0xFF, 0x45, 0x00)),
+    ("orchid", Color::rgb(0xDA, 0x70, 0xD6)),
+    ("palegoldenrod", Color::rgb(0xEE, 0xE8, 0xAA)),
+    ("palegreen", Color::rgb(0x98, 0xFB, 0x98)),
+    ("paleturquoise", Color::rgb(0xAF, 0xEE, 0xEE)),
+    ("palevioletred", Color::rgb(0xDB, 0x70, 0x93)),
+    ("papayawhip", Color::rgb(0xFF, 0xEF, 0xD5)),
+    ("peachpuff", Color::rgb(0xFF, 0xDA, 0xB9)),
+    ("peru", Color::rgb(0xCD, 0x85, 0x3F)),
+    ("pink", Color::rgb(0xFF, 0xC0, 0xCB)),
+    ("plum", Color::rgb(0xDD, 0xA0, 0xDD)),
+    ("powderblue", Color::rgb(0xB0, 0xE0, 0xE6)),
+    ("purple", Color::rgb(0x80, 0x00, 0x80)),
+    ("rebeccapurple", Color::rgb(0x66, 0x33, 0x99)),
+    ("red", Color::rgb(0xFF, 0x00, 0x00)),
+    ("rosybrown", Color::rgb(0xBC, 0x8F, 0x8F)),
+    ("royalblue", Color::rgb(0x41, 0x69, 0xE1)),
+    ("saddlebrown", Color::rgb(0x8B, 0x45, 0x13)),
+    ("salmon", Color::rgb(0xFA, 0x80, 0x72)),
+    ("sandybrown", Color::rgb(0xF4, 0xA4, 0x60)),
+    ("seagreen", Color::rgb(0x2E, 0x8B, 0x57)),
+    ("seashell", Color::rgb(0xFF, 0xF5, 0xEE)),
+    ("sienna", Color::rgb(0xA0, 0x52, 0x2D)),
+    ("silver", Color::rgb(0xC0, 0xC0, 0xC0)),
+    ("skyblue", Color::rgb(0x87, 0xCE, 0xEB)),
+    ("slateblue", Color::rgb(0x6A, 0x5A, 0xCD)),
+    ("slategray", Color::rgb(0x70, 0x80, 0x90)),
+    ("slategrey", Color::rgb(0x70, 0x80, 0x90)),
+    ("snow", Color::rgb(0xFF, 0xFA, 0xFA)),
+    ("springgreen", Color::rgb(0x00, 0xFF, 0x7F)),
+    ("steelblue", Color::rgb(0x46, 0x82, 0xB4)),
+    ("tan", Color::rgb(0xD2, 0xB4, 0x8C)),
+    ("teal", Color::rgb(0x00, 0x80, 0x80)),
+    ("thistle", Color::rgb(0xD8, 0xBF, 0xD8)),
+    ("tomato", Color::rgb(0xFF, 0x63, 0x47)),
+    ("transparent", Color::rgba(0x00, 0x00, 0x00, 0x00)),
+    ("turquoise", Color::rgb(0x40, 0xE0, 0xD0)),
+    ("violet", Color::rgb(0xEE, 0x82, 0xEE)),
+    ("wheat", Color::rgb(0xF5, 0xDE, 0xB3)),
+    ("white", Color::rgb(0xFF, 0xFF, 0xFF)),
+    ("whitesmoke", Color::rgb(0xF5, 0xF5, 0xF5)),
+    ("yellow", Color::rgb(0xFF, 0xFF, 0x00)),
+    ("yellowgreen", Color::rgb(0x9A, 0xCD, 0x32)),
+];
@@ -0,0 +1,268 @@
+//! [WCAG contrast] checks for [`Theme`]s.
+//!
+//! xterm.js can fix up contrast as it renders (see
+//! [`minimum_contrast_ratio`]) but that doesn't help with showing that a
+//! theme is accessible, or with picking colors that stay put. A
+//! [`ContrastReport`] lists the contrast ratio of every ANSI color (and the
+//! foreground) against a theme's background, along with the closest color of
+//! the same hue that meets a target ratio; [`Theme::with_minimum_contrast`]
+//! makes a theme out of those colors.
+//!
+//! [WCAG contrast]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+//! [`minimum_contrast_ratio`]: crate::xterm::TerminalOptions::minimum_contrast_ratio
+
+use super::color::Color;
+use super::theme::{
+    ThemeError, ANSI_NAMES, DEFAULT_ANSI, DEFAULT_BACKGROUND,
+    DEFAULT_FOREGROUND,
+};
+use crate::xterm::{Str, Theme};
+
+use core::fmt::{self, Display};
+
+/// The contrast ratio WCAG's level AA asks for (for regular sized text).
+pub const AA: f64 = 4.5;
+
+/// The contrast ratio WCAG's level AA asks for, for large text.
+pub const AA_LARGE: f64 = 3.0;
+
+/// The contrast ratio WCAG's level AAA asks for (for regular sized text).
+pub const AAA: f64 = 7.0;
+
+/// How one color in a [`Theme`] contrasts with its background.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastEntry {
+    /// The color's xterm.js name (i.e. `red`, `extendedAnsi[3]` or
+    /// `foreground`).
+    pub name: Str,
+    /// Which ANSI color this is (`None` for the foreground).
+    pub index: Option<u8>,
+    /// The color.
+    pub color: Color,
+    /// Whether the theme leaves this color to xterm.js's default.
+    pub is_default: bool,
+    /// The contrast ratio against the background (after blending, if the
+    /// color is translucent).
+    pub ratio: f64,
+    /// The closest color of the same hue that meets the target ratio, if
+    /// this color doesn't (this can still fall short if no color of that
+    /// hue is up to it; it's as close as it gets).
+    pub adjusted: Option<Color>,
+    /// The contrast ratio of [`adjusted`](ContrastEntry::adjusted).
+    pub adjusted_ratio: Option<f64>,
+}
+
+impl ContrastEntry {
+    /// Checks `color` against `background`.
+    fn new(
+        name: Str,
+        index: Option<u8>,
+        color: Option<Color>,
+        default: Color,
+        background: Color,
+        target: f64,
+    ) -> Self {
+        let contrast = |c: Color| c.over(background).contrast_ratio(background);
+
+        let is_default = color.is_none();
+        let color = color.unwrap_or(default);
+        let ratio = contrast(color);
+        let adjusted = (ratio < target)
+            .then(|| color.with_minimum_contrast(background, target));
+
+        Self {
+            name,
+            index,
+            color,
+            is_default,
+            ratio,
+            adjusted,
+            adjusted_ratio: adjusted.map(contrast),
+        }
+    }
+
+    /// Whether the color meets the report's target ratio.
+    #[must_use]
+    pub fn passes(&self) -> bool {
+        self.adjusted.is_none()
+    }
+}
+
+/// The contrast of each of a [`Theme`]'s colors against its background; see
+/// [`Theme::contrast_report`].
+///
+/// Its [`Display`] impl renders a table:
+///
+/// ```text
+/// contrast against #2e3440 (target 4.5:1): 6 of 17 colors fall short
+///   foreground       #d8dee9     9.25:1  ok
+///   black            #343434     1.00:1  FAIL  adjust to #9c9c9c (4.55:1)
+///   red              #bf616a     3.05:1  FAIL  adjust to #cf888f (4.50:1)
+///   ...
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastReport {
+    /// The background everything is checked against (opaque).
+    pub background: Color,
+    /// The contrast ratio colors need to meet.
+    pub target: f64,
+    /// The foreground, then ANSI colors 0-15, then any extended ANSI colors
+    /// the theme sets.
+    pub entries: Vec<ContrastEntry>,
+}
+
+impl ContrastReport {
+    /// Whether every color meets the target ratio.
+    #[must_use]
+    pub fn passes(&self) -> bool {
+        self.entries.iter().all(ContrastEntry::passes)
+    }
+
+    /// The colors that don't meet the target ratio.
+    pub fn failures(&self) -> impl Iterator<Item = &ContrastEntry> + '_ {
+        self.entries.iter().filter(|e| !e.passes())
+    }
+}
+
+impl Display for ContrastReport {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            fmt,
+            "contrast against {} (target {}:1): {} of {} colors fall short",
+            self.background,
+            self.target,
+            self.failures().count(),
+            self.entries.len(),
+        )?;
+
+        for entry in &self.entries {
+            write!(
+                fmt,
+                "  {:<16} {:<9} {:>6.2}:1  ",
+                entry.name,
+                entry.color.to_hex(),
+                entry.ratio,
+            )?;
+
+            match (entry.adjusted, entry.adjusted_ratio) {
+                (Some(adjusted), Some(ratio)) => {
+                    write!(fmt, "FAIL  adjust to {adjusted} ({ratio:.2}:1)")?;
+                }
+                _ => write!(fmt, "ok")?,
+            }
+
+            if entry.is_default {
+                write!(fmt, " (default)")?;
+            }
+            writeln!(fmt)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Theme {
+    /// Works out the contrast ratio of the foreground and each ANSI color
+    /// against the background, and how to adjust the colors that fall short
+    /// of `target` (i.e. [`AA`] or [`AAA`]).
+    ///
+    /// Colors the theme doesn't set are checked as xterm.js's defaults; only
+    /// the extended ANSI colors the theme sets are checked. The background
+    /// is taken to be opaque.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::{ext::contrast::AA, xterm::Theme};
+    /// let report = Theme::nord().contrast_report(AA).unwrap();
+    /// assert!(!report.passes());
+    ///
+    /// let black = report.failures().next().unwrap();
+    /// assert_eq!(black.name, "black");
+    /// assert!(black.adjusted_ratio.unwrap() >= AA);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are malformed.
+    pub fn contrast_report(
+        &self,
+        target: f64,
+    ) -> Result<ContrastReport, ThemeError> {
+        self.validate()?;
+
+        // Everything parses now that the theme's been validated.
+        let background = self
+            .background_color()
+            .ok()
+            .flatten()
+            .unwrap_or(DEFAULT_BACKGROUND)
+            .opaque();
+        let parse = |color: Option<Str>| color.and_then(|c| c.parse().ok());
+
+        let mut entries = vec![ContrastEntry::new(
+            "foreground".into(),
+            None,
+            parse(self.foreground()),
+            DEFAULT_FOREGROUND,
+            background,
+            target,
+        )];
+
+        for (index, (name, default)) in
+            (0..).zip(ANSI_NAMES.iter().zip(DEFAULT_ANSI))
+        {
+            entries.push(ContrastEntry::new(
+                (*name).into(),
+                Some(index),
+                parse(self.ansi_color(index)),
+                default,
+                background,
+                target,
+            ));
+        }
+
+        let extended = self.extended_ansi().unwrap_or_default();
+        for (index, color) in (16..=255).zip(extended) {
+            entries.push(ContrastEntry::new(
+                format!("extendedAnsi[{}]", index - 16),
+                Some(index),
+                parse(Some(color)),
+                Color::rgb(0, 0, 0),
+                background,
+                target,
+            ));
+        }
+
+        Ok(ContrastReport {
+            background,
+            target,
+            entries,
+        })
+    }
+
+    /// A copy of the theme with each color that falls short of `target`
+    /// replaced with its adjusted color from [`Theme::contrast_report`].
+    ///
+    /// Colors the theme leaves to xterm.js's defaults are set, if they need
+    /// adjusting.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are malformed.
+    pub fn with_minimum_contrast(
+        &self,
+        target: f64,
+    ) -> Result<Theme, ThemeError> {
+        let report = self.contrast_report(target)?;
+
+        let mut theme = self.clone();
+        for entry in report.failures() {
+            let adjusted = entry.adjusted.map(Color::to_hex);
+            match entry.index {
+                Some(index) => theme.set_ansi_color(index, adjusted),
+                None => theme.set_foreground(adjusted),
+            }
+        }
+
+        Ok(theme)
+    }
+}
@@ -6,6 +6,9 @@ use crate::xterm::{
     Str, Terminal,
 };
 
+#[cfg(feature = "addon-search")]
+use crate::addons::search::{SearchAddon, SearchResults};
+
 use wasm_bindgen::prelude::*;
 
 macro_rules! event_methods {
@@ -105,6 +108,22 @@ impl Terminal { event_methods!{
     pub attach_title_change_event_listener: (Str) => Terminal::on_title_change
 }}
 
+#[rustfmt::skip]
+#[cfg(feature = "xterm-5")]
+impl Terminal { event_methods! {
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
+    @doc: "write parsed"
+    pub attach_write_parsed_event_listener: () => Terminal::on_write_parsed
+}}
+
+#[rustfmt::skip]
+#[cfg(feature = "addon-search")]
+impl SearchAddon { event_methods! {
+    @doc: "search results change"
+    pub attach_results_change_event_listener: (SearchResults)
+        => SearchAddon::on_did_change_results
+}}
+
 #[rustfmt::skip]
 impl BufferNamespace { event_methods! {
     /// Attaches an event listener for when the active buffer changes and
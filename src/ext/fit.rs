@@ -0,0 +1,127 @@
+//! Keeps a [`FitAddon`]'s terminal fitted to its element.
+//!
+//! [`FitAddon`]: crate::addons::fit::FitAddon
+
+use crate::addons::fit::FitAddon;
+
+use js_sys::Array;
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{Element, ResizeObserver};
+
+use core::cell::Cell;
+use core::fmt;
+use std::rc::Rc;
+
+/// Calls [`FitAddon::fit`] whenever an element is resized.
+///
+/// Resizes are debounced: the terminal is only refitted once the element has
+/// stopped changing size for the configured delay. (Resizing a terminal is
+/// fairly expensive and each resize is usually forwarded to a PTY.)
+///
+/// Dropping this stops observing the element.
+pub struct FitOnResize {
+    /// The observer watching the element.
+    observer: ResizeObserver,
+    /// The observer's callback.
+    _on_resize: Closure<dyn FnMut(Array)>,
+    /// Shared with the observer's callback; see [`Debounce`].
+    debounce: Rc<Debounce>,
+}
+
+/// State for debouncing refits.
+struct Debounce {
+    /// Handle of the pending `setTimeout`, if any.
+    timer: Cell<Option<i32>>,
+    /// How long to wait, in milliseconds.
+    delay_ms: i32,
+    /// Calls [`FitAddon::fit`].
+    fit: Closure<dyn FnMut()>,
+}
+
+impl Debounce {
+    /// (Re)starts the timer.
+    fn poke(&self) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+
+        if let Some(handle) = self.timer.take() {
+            window.clear_timeout_with_handle(handle);
+        }
+
+        self.timer.set(
+            window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    self.fit.as_ref().unchecked_ref(),
+                    self.delay_ms,
+                )
+                .ok(),
+        );
+    }
+}
+
+impl fmt::Debug for FitOnResize {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("FitOnResize")
+            .field("observer", &self.observer)
+            .field("delay_ms", &self.debounce.delay_ms)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for FitOnResize {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+
+        if let (Some(handle), Some(window)) =
+            (self.debounce.timer.take(), web_sys::window())
+        {
+            window.clear_timeout_with_handle(handle);
+        }
+    }
+}
+
+impl FitAddon {
+    /// Refits the terminal whenever `element` (usually the element the
+    /// terminal was [opened] in) is resized, waiting until `debounce_ms`
+    /// milliseconds have passed without another resize.
+    ///
+    /// The returned [`FitOnResize`] must be kept around; dropping it stops
+    /// observing the element.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the [`ResizeObserver`] can't be constructed (i.e. the
+    /// browser doesn't support it).
+    ///
+    /// [opened]: crate::xterm::Terminal::open
+    /// [`ResizeObserver`]: web_sys::ResizeObserver
+    pub fn fit_on_resize(
+        &self,
+        element: &Element,
+        debounce_ms: u32,
+    ) -> Result<FitOnResize, JsValue> {
+        let addon = self.clone();
+        let fit: Box<dyn FnMut()> = Box::new(move || addon.fit());
+
+        let debounce = Rc::new(Debounce {
+            timer: Cell::new(None),
+            delay_ms: i32::try_from(debounce_ms).unwrap_or(i32::MAX),
+            fit: Closure::wrap(fit),
+        });
+
+        let shared = Rc::clone(&debounce);
+        let on_resize: Box<dyn FnMut(Array)> =
+            Box::new(move |_entries| shared.poke());
+        let on_resize = Closure::wrap(on_resize);
+
+        let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref())?;
+        observer.observe(element);
+
+        Ok(FitOnResize {
+            observer,
+            _on_resize: on_resize,
+            debounce,
+        })
+    }
+}
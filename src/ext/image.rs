@@ -0,0 +1,93 @@
+//! Loading the [image addon] with the window options it needs.
+//!
+//! [image addon]: crate::addons::image
+
+use crate::addons::image::{ImageAddon, ImageAddonOptions};
+use crate::xterm::{Terminal, WindowOptions};
+
+use js_sys::{Object, Reflect};
+use wasm_bindgen::JsValue;
+
+impl WindowOptions {
+    /// Turns on the size reports that programs drawing images rely on:
+    /// `get_win_size_pixels` (`CSI 14 t`), `get_cell_size_pixels` (`CSI 16 t`)
+    /// and `get_win_size_chars` (`CSI 18 t`).
+    ///
+    /// All three have default implementations in xterm.js.
+    #[must_use]
+    pub fn with_size_reports(mut self) -> Self {
+        self.get_win_size_pixels = Some(true);
+        self.get_cell_size_pixels = Some(true);
+        self.get_win_size_chars = Some(true);
+        self
+    }
+
+    /// Whether all the size reports turned on by [`with_size_reports`] are
+    /// enabled.
+    ///
+    /// [`with_size_reports`]: WindowOptions::with_size_reports
+    #[must_use]
+    pub fn has_size_reports(&self) -> bool {
+        [
+            self.get_win_size_pixels,
+            self.get_cell_size_pixels,
+            self.get_win_size_chars,
+        ]
+        .iter()
+        .all(|opt| *opt == Some(true))
+    }
+}
+
+impl Terminal {
+    /// Turns on the size reports image-drawing programs need (see
+    /// [`WindowOptions::with_size_reports`]) if they aren't on already,
+    /// leaving the other window options as they are.
+    ///
+    /// [`load_image_addon`](Terminal::load_image_addon) does this for you.
+    pub fn enable_size_reports(&self) {
+        // xterm.js hands back a plain object, which `Terminal::options`
+        // can't convert.
+        let current = Reflect::get(self, &"options".into())
+            .and_then(|options| Reflect::get(&options, &"windowOptions".into()))
+            .unwrap_or(JsValue::UNDEFINED);
+        let window_options =
+            WindowOptions::from_object(&current).unwrap_or_default();
+
+        if !window_options.has_size_reports() {
+            // Only `windowOptions` is set: setting the rest back (`cols` and
+            // `rows` in particular) throws. xterm.js compares object options
+            // by reference so this has to be a new object, too.
+            let changed = Object::new();
+            let _ = Reflect::set(
+                &changed,
+                &"windowOptions".into(),
+                &window_options.with_size_reports().to_object(),
+            );
+            self.set_options_object(&changed);
+        }
+    }
+
+    /// Creates an [`ImageAddon`] and [loads] it into this terminal.
+    ///
+    /// This first turns on the size reports image-drawing programs need (see
+    /// [`enable_size_reports`]), regardless of
+    /// [`ImageAddonOptions::enable_size_reports`].
+    ///
+    /// Keep the returned addon around to query its
+    /// [storage usage](ImageAddon::storage_usage).
+    ///
+    /// [loads]: Terminal::load_xterm_addon
+    /// [`enable_size_reports`]: Terminal::enable_size_reports
+    #[must_use]
+    pub fn load_image_addon(
+        &self,
+        options: Option<ImageAddonOptions>,
+    ) -> ImageAddon {
+        self.enable_size_reports();
+
+        let addon = ImageAddon::new(options);
+        self.load_xterm_addon(&addon);
+
+        addon
+    }
+}
@@ -0,0 +1,276 @@
+//! A safer interface to [`ProposedTerminal::register_character_joiner`],
+//! along with a [joiner](LigatureJoiner) for programming font ligatures.
+//!
+//! Note that character joiners are only used by the canvas renderer.
+//!
+//! [`ProposedTerminal::register_character_joiner`]: crate::xterm::ProposedTerminal::register_character_joiner
+
+use super::capabilities::{CapabilityError, Kind};
+use crate::xterm::{ProposedTerminal, Str};
+
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+use core::fmt;
+use core::ops::Range;
+use std::borrow::Cow;
+
+/// Decides which characters should be rendered together as a single unit
+/// (i.e. as a ligature).
+///
+/// This is implemented for closures with the right signature.
+pub trait CharacterJoiner {
+    /// Given a run of text that's eligible for joining, returns the ranges
+    /// (byte offsets into `text`, end exclusive) that should be rendered as
+    /// single units.
+    ///
+    /// Ranges can be in any order. Ranges that don't fall on `char`
+    /// boundaries are ignored, as are ranges that overlap an earlier (or, when
+    /// they start at the same place, longer) range.
+    fn join(&self, text: &str) -> Vec<Range<usize>>;
+}
+
+impl<F: Fn(&str) -> Vec<Range<usize>>> CharacterJoiner for F {
+    fn join(&self, text: &str) -> Vec<Range<usize>> {
+        (self)(text)
+    }
+}
+
+/// Converts a byte offset into `text` into an offset in UTF-16 code units
+/// (which is what JS strings are indexed by).
+///
+/// Returns `None` if `offset` isn't on a `char` boundary.
+#[must_use]
+pub fn utf8_to_utf16_offset(text: &str, offset: usize) -> Option<usize> {
+    text.get(..offset)
+        .map(|prefix| prefix.chars().map(char::len_utf16).sum())
+}
+
+/// Converts an offset in UTF-16 code units into a byte offset into `text`.
+///
+/// Returns `None` if `offset` is past the end of `text` or falls in the
+/// middle of a surrogate pair.
+#[must_use]
+pub fn utf16_to_utf8_offset(text: &str, offset: usize) -> Option<usize> {
+    let mut utf16 = 0;
+    for (idx, c) in text.char_indices() {
+        if utf16 == offset {
+            return Some(idx);
+        }
+        if utf16 > offset {
+            return None;
+        }
+        utf16 += c.len_utf16();
+    }
+
+    (utf16 == offset).then_some(text.len())
+}
+
+/// Runs `joiner` on `text`, converting the ranges it returns to UTF-16
+/// offsets (dropping any that aren't on `char` boundaries or are empty).
+///
+/// xterm.js needs the ranges sorted and non-overlapping, so they're sorted by
+/// where they start and ranges that overlap one that's already been kept
+/// (starting earlier, or at the same place but longer) are dropped.
+#[must_use]
+pub fn join_utf16(
+    joiner: &impl CharacterJoiner,
+    text: &str,
+) -> Vec<Range<usize>> {
+    let mut ranges: Vec<_> = joiner
+        .join(text)
+        .into_iter()
+        .filter_map(|Range { start, end }| {
+            let start = utf8_to_utf16_offset(text, start)?;
+            let end = utf8_to_utf16_offset(text, end)?;
+            (start < end).then_some(start..end)
+        })
+        .collect();
+
+    ranges.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+    let mut kept_end = 0;
+    ranges.retain(|range| {
+        let keep = range.start >= kept_end;
+        if keep {
+            kept_end = range.end;
+        }
+        keep
+    });
+
+    ranges
+}
+
+/// A [`CharacterJoiner`] registered with a [`ProposedTerminal`].
+///
+/// Dropping this deregisters the joiner.
+pub struct RegisteredJoiner {
+    /// The terminal the joiner is registered with.
+    terminal: ProposedTerminal,
+    /// The ID xterm.js gave the joiner.
+    id: u32,
+    /// The JS side of the joiner; must outlive the registration.
+    _handler: Closure<dyn FnMut(Str) -> Array>,
+}
+
+impl RegisteredJoiner {
+    /// The ID xterm.js gave the joiner.
+    #[must_use]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+impl fmt::Debug for RegisteredJoiner {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("RegisteredJoiner")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for RegisteredJoiner {
+    fn drop(&mut self) {
+        self.terminal.deregister_character_joiner(self.id);
+    }
+}
+
+impl ProposedTerminal {
+    /// Registers a [`CharacterJoiner`], taking care of converting between
+    /// UTF-16 and UTF-8 offsets.
+    ///
+    /// The joiner stays registered until the returned [`RegisteredJoiner`] is
+    /// dropped.
+    ///
+    /// See [`ProposedTerminal::register_character_joiner`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if the loaded xterm.js doesn't have character joiners or
+    /// [`allow_proposed_api`] has been turned back off; see
+    /// [`Terminal::capabilities`].
+    ///
+    /// [`allow_proposed_api`]: crate::xterm::TerminalOptions::allow_proposed_api
+    /// [`Terminal::capabilities`]: crate::xterm::Terminal::capabilities
+    pub fn register_joiner<J>(
+        &self,
+        joiner: J,
+    ) -> Result<RegisteredJoiner, CapabilityError>
+    where
+        J: CharacterJoiner + 'static,
+    {
+        self.capabilities()
+            .require(Kind::Method, "registerCharacterJoiner")?;
+
+        let handler: Box<dyn FnMut(Str) -> Array> = Box::new(move |text| {
+            join_utf16(&joiner, &text)
+                .into_iter()
+                .map(|Range { start, end }| {
+                    #[allow(clippy::cast_precision_loss)]
+                    let range = [start as f64, end as f64];
+                    Array::of2(&range[0].into(), &range[1].into())
+                })
+                .collect()
+        });
+        let handler = Closure::wrap(handler);
+        let id = self.register_character_joiner(&handler);
+
+        Ok(RegisteredJoiner {
+            terminal: self.clone(),
+            id,
+            _handler: handler,
+        })
+    }
+}
+
+/// Programming font ligatures (the common ones; fonts with ligatures
+/// generally support all of these).
+#[rustfmt::skip]
+pub const DEFAULT_LIGATURES: &[&str] = &[
+    // Arrows.
+    "<=>", "<->", "<==", "==>", "<--", "-->", "=>", "->", "<-", "<=<", ">=>",
+    "|>", "<|", "~>", "<~", ">>=", "=<<",
+    // Comparisons.
+    "===", "!==", "==", "!=", "/=", ">=", "<=", "=~", "!~",
+    // Other operators.
+    "&&", "||", "::", ":=", "::=", "..", "...", "..=", "..<", "?.", "??",
+    "++", "+=", "-=", "*=", "<<", ">>", "<<<", ">>>", "<>", "</", "/>", "</>",
+    // Comments.
+    "//", "///", "/*", "*/", "/**",
+];
+
+/// A [`CharacterJoiner`] that joins ligature sequences (like `=>`, `!=` and
+/// `->`).
+///
+/// Text is scanned left to right, joining the longest sequence that matches
+/// at each position.
+///
+/// ```rust
+/// # use xterm_js_sys::ext::joiner::{CharacterJoiner, LigatureJoiner};
+/// let joiner = LigatureJoiner::default();
+/// assert_eq!(joiner.join("a => b"), vec![2..4]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LigatureJoiner {
+    /// The sequences to join, longest first.
+    sequences: Vec<Cow<'static, str>>,
+}
+
+impl Default for LigatureJoiner {
+    /// A joiner for [`DEFAULT_LIGATURES`].
+    fn default() -> Self {
+        Self::new(DEFAULT_LIGATURES.iter().copied().map(Cow::Borrowed))
+    }
+}
+
+impl LigatureJoiner {
+    /// A joiner for the given sequences.
+    ///
+    /// Sequences shorter than two characters are ignored.
+    pub fn new(sequences: impl IntoIterator<Item = Cow<'static, str>>) -> Self {
+        let mut sequences: Vec<_> = sequences
+            .into_iter()
+            .filter(|seq| seq.chars().nth(1).is_some())
+            .collect();
+
+        sequences.sort_by(|a, b| b.len().cmp(&a.len()).then(a.cmp(b)));
+        sequences.dedup();
+
+        Self { sequences }
+    }
+
+    /// Adds sequences to the joiner.
+    #[must_use]
+    pub fn with_sequences(
+        self,
+        sequences: impl IntoIterator<Item = Cow<'static, str>>,
+    ) -> Self {
+        Self::new(self.sequences.into_iter().chain(sequences))
+    }
+
+    /// The sequences that are joined, longest first.
+    #[must_use]
+    pub fn sequences(&self) -> &[Cow<'static, str>] {
+        &self.sequences
+    }
+}
+
+impl CharacterJoiner for LigatureJoiner {
+    fn join(&self, text: &str) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut idx = 0;
+
+        while let Some(rest) = text.get(idx..).filter(|rest| !rest.is_empty()) {
+            match self.sequences.iter().find(|seq| rest.starts_with(&***seq)) {
+                Some(seq) => {
+                    ranges.push(idx..idx + seq.len());
+                    idx += seq.len();
+                }
+                None => {
+                    idx += rest.chars().next().map_or(1, char::len_utf8);
+                }
+            }
+        }
+
+        ranges
+    }
+}
@@ -8,7 +8,12 @@ use std::convert::TryFrom;
 impl From<Level> for LogLevel {
     fn from(level: Level) -> LogLevel {
         match level {
-            Level::Trace | Level::Debug => LogLevel::Debug,
+            #[cfg(feature = "xterm-5")]
+            Level::Trace => LogLevel::Trace,
+            // xterm.js 4 doesn't have a trace level.
+            #[cfg(feature = "xterm-4")]
+            Level::Trace => LogLevel::Debug,
+            Level::Debug => LogLevel::Debug,
             Level::Info => LogLevel::Info,
             Level::Warn => LogLevel::Warn,
             Level::Error => LogLevel::Error,
@@ -34,6 +39,8 @@ impl TryFrom<LogLevel> for Level {
     #[allow(clippy::match_wildcard_for_single_variants)]
     fn try_from(level: LogLevel) -> Result<Level, LogLevelIsOff> {
         match level {
+            #[cfg(feature = "xterm-5")]
+            LogLevel::Trace => Ok(Level::Trace),
             LogLevel::Debug => Ok(Level::Debug),
             LogLevel::Info => Ok(Level::Info),
             LogLevel::Warn => Ok(Level::Warn),
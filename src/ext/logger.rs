@@ -0,0 +1,465 @@
+//! A [`Logger`] that forwards xterm.js's diagnostics to the [`log`] crate
+//! (xterm.js 5 only), and a [`log::Log` implementation](TerminalLogger) that
+//! writes records to a [`Terminal`].
+
+#[cfg(feature = "xterm-5")]
+use crate::xterm::Logger;
+use crate::xterm::{LogLevel, Terminal};
+
+use js_sys::Date;
+#[cfg(feature = "xterm-5")]
+use js_sys::{Array, Error, Function, JSON};
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+#[cfg(feature = "xterm-5")]
+use wasm_bindgen::{prelude::*, JsCast};
+
+use core::fmt::Write;
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "xterm-5")]
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+#[cfg(feature = "xterm-5")]
+#[wasm_bindgen(inline_js = "
+export function collectArgs(f) {
+    return (message, ...args) => f(message, args);
+}
+")]
+extern "C" {
+    /// Wraps a `(message, args)` function in a variadic function that
+    /// collects its extra arguments into `args` (closures only take a fixed
+    /// number of arguments).
+    #[wasm_bindgen(js_name = collectArgs)]
+    fn collect_args(f: &JsValue) -> Function;
+}
+
+/// Forwards everything xterm.js logs (along with any extra arguments) to the
+/// [`log`] crate, under the [`xterm_js`](LogForwarder::DEFAULT_TARGET) target
+/// by default.
+///
+/// xterm.js only calls the logger for messages at or above its
+/// [`log_level`](crate::xterm::TerminalOptions::log_level) so it's worth
+/// keeping that in step with [`log::max_level`]:
+///
+/// ```rust,no_run
+/// # use xterm_js_sys::{ext::logger::LogForwarder, xterm::TerminalOptions};
+/// let mut options = TerminalOptions::default();
+/// options.log_level = Some(LogForwarder::log_level());
+/// options.set_logger(Some(LogForwarder::default().into()));
+/// ```
+#[cfg(feature = "xterm-5")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogForwarder {
+    /// The target to log under.
+    target: Cow<'static, str>,
+}
+
+#[cfg(feature = "xterm-5")]
+impl Default for LogForwarder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "xterm-5")]
+impl LogForwarder {
+    /// The target messages are logged under, unless you pick another one.
+    pub const DEFAULT_TARGET: &'static str = "xterm_js";
+
+    /// A forwarder that logs under [`DEFAULT_TARGET`](Self::DEFAULT_TARGET).
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            target: Cow::Borrowed(Self::DEFAULT_TARGET),
+        }
+    }
+
+    /// Sets the target messages are logged under.
+    #[must_use]
+    pub fn with_target(self, target: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            target: target.into(),
+        }
+    }
+
+    /// The target messages are logged under.
+    #[must_use]
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// The xterm.js [`LogLevel`] that matches [`log::max_level`].
+    #[must_use]
+    pub fn log_level() -> LogLevel {
+        log::max_level().to_level().into()
+    }
+
+    /// Logs a message from xterm.js, appending its extra arguments (space
+    /// separated, like `console.log`).
+    pub fn log(&self, level: Level, message: &JsValue, args: &[JsValue]) {
+        if level > log::max_level() {
+            return;
+        }
+
+        let mut text = describe(message);
+        for arg in args {
+            text.push(' ');
+            text.push_str(&describe(arg));
+        }
+
+        log::logger().log(
+            &Record::builder()
+                .level(level)
+                .target(&self.target)
+                .args(format_args!("{text}"))
+                .build(),
+        );
+    }
+
+    /// Makes a JS [`Logger`] out of this forwarder.
+    ///
+    /// The forwarder is leaked (as with
+    /// [`IntoJsInterface`](crate::ext::IntoJsInterface)).
+    #[must_use]
+    pub fn into_logger(self) -> Logger {
+        let forwarder: &'static Self = Box::leak(Box::new(self));
+
+        let method = |level: Level| {
+            let f: Box<dyn FnMut(JsValue, Array)> =
+                Box::new(move |message, args| {
+                    forwarder.log(level, &message, &args.to_vec());
+                });
+
+            collect_args(&Closure::wrap(f).into_js_value())
+        };
+
+        object! {
+            trace: method(Level::Trace),
+            debug: method(Level::Debug),
+            info: method(Level::Info),
+            warn: method(Level::Warn),
+            error: method(Level::Error),
+        }
+        .unchecked_into()
+    }
+}
+
+#[cfg(feature = "xterm-5")]
+impl From<LogForwarder> for Logger {
+    fn from(forwarder: LogForwarder) -> Logger {
+        forwarder.into_logger()
+    }
+}
+
+/// Turns a value xterm.js logged into text.
+///
+/// Strings are used as is, errors become `Name: message`, and anything else
+/// is rendered as JSON if possible.
+#[cfg(feature = "xterm-5")]
+fn describe(value: &JsValue) -> String {
+    if let Some(string) = value.as_string() {
+        return string;
+    }
+
+    if let Some(error) = value.dyn_ref::<Error>() {
+        return error.to_string().into();
+    }
+
+    JSON::stringify(value)
+        .ok()
+        .and_then(|json| json.as_string())
+        .unwrap_or_else(|| format!("{value:?}"))
+}
+
+thread_local! {
+    /// The terminals [`TerminalLogger`]s are attached to, by logger ID.
+    ///
+    /// Loggers have to be `Send + Sync` and terminals are neither so the
+    /// terminals live here instead; a logger only writes to its terminal from
+    /// the thread it was attached on.
+    static TERMINALS: RefCell<HashMap<usize, Terminal>> =
+        RefCell::new(HashMap::new());
+}
+
+/// The ID the next [`TerminalLogger`] gets.
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A [`log::Log`] implementation that writes records to a [`Terminal`].
+///
+/// Each record gets a line of its own with a (colored) level tag, a
+/// timestamp and the module the record came from:
+///
+/// ```text
+/// [12:34:56.789 WARN  my_app::net] connection lost; retrying
+/// ```
+///
+/// Records that arrive before a terminal has been
+/// [attached](TerminalLogger::attach) and [opened](Terminal::open) are kept
+/// in a ring buffer (the oldest are dropped once it's full) and written out
+/// once there's somewhere to write them. If writing fails (i.e. the terminal
+/// has been disposed) the terminal is detached and records go back to being
+/// buffered.
+///
+/// ```rust,no_run
+/// # use xterm_js_sys::{ext::logger::TerminalLogger, xterm::LogLevel};
+/// # use xterm_js_sys::Terminal;
+/// let logger = TerminalLogger::new(LogLevel::Info).install().unwrap();
+/// log::info!("this is buffered");
+///
+/// let terminal = Terminal::new(None);
+/// logger.attach(&terminal);
+/// // ...and written out here:
+/// # let element = web_sys::HtmlElement::from(wasm_bindgen::JsValue::NULL);
+/// terminal.open(element);
+/// log::logger().flush();
+/// ```
+#[derive(Debug)]
+pub struct TerminalLogger {
+    /// The key for this logger's terminal in [`TERMINALS`].
+    id: usize,
+    /// The most verbose [`LevelFilter`] (as a `usize`) that gets written.
+    level: AtomicUsize,
+    /// Whether records are prefixed with the time they were logged.
+    timestamps: bool,
+    /// Whether records are prefixed with their module path.
+    module_paths: bool,
+    /// Whether to color the level tags (and dim the prefix).
+    colors: bool,
+    /// How many records to buffer while there's no terminal.
+    capacity: usize,
+    /// Rendered records waiting for a terminal.
+    backlog: Mutex<VecDeque<String>>,
+}
+
+impl TerminalLogger {
+    /// How many records are buffered (by default) while there's no terminal.
+    pub const DEFAULT_BACKLOG_CAPACITY: usize = 1000;
+
+    /// A logger that writes records at `level` and above.
+    ///
+    /// Timestamps, module paths and colors are all on.
+    #[must_use]
+    pub fn new(level: LogLevel) -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            level: AtomicUsize::new(level_filter(level) as usize),
+            timestamps: true,
+            module_paths: true,
+            colors: true,
+            capacity: Self::DEFAULT_BACKLOG_CAPACITY,
+            backlog: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Sets whether records are prefixed with the (local) time they were
+    /// logged.
+    #[must_use]
+    pub fn with_timestamps(mut self, timestamps: bool) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+
+    /// Sets whether records are prefixed with the module they came from.
+    #[must_use]
+    pub fn with_module_paths(mut self, module_paths: bool) -> Self {
+        self.module_paths = module_paths;
+        self
+    }
+
+    /// Sets whether level tags are colored.
+    #[must_use]
+    pub fn with_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Sets how many records are buffered while there's no terminal to write
+    /// them to.
+    #[must_use]
+    pub fn with_backlog_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Registers the logger with the [`log`] crate (leaking it) and raises
+    /// [`log::max_level`] to the logger's level.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a logger has already been set.
+    pub fn install(self) -> Result<&'static Self, SetLoggerError> {
+        let logger: &'static Self = Box::leak(Box::new(self));
+        log::set_logger(logger)?;
+        log::set_max_level(level_filter(logger.level()));
+
+        Ok(logger)
+    }
+
+    /// The least severe level that gets written.
+    #[must_use]
+    pub fn level(&self) -> LogLevel {
+        match self.level.load(Ordering::Relaxed) {
+            0 => LogLevel::Off,
+            1 => LogLevel::Error,
+            2 => LogLevel::Warn,
+            3 => LogLevel::Info,
+            4 => LogLevel::Debug,
+            _ => Level::Trace.into(),
+        }
+    }
+
+    /// Changes the least severe level that gets written.
+    ///
+    /// Note that this doesn't touch [`log::max_level`].
+    pub fn set_level(&self, level: LogLevel) {
+        self.level
+            .store(level_filter(level) as usize, Ordering::Relaxed);
+    }
+
+    /// Starts writing records to `terminal` (replacing the terminal the
+    /// logger was attached to, if any).
+    ///
+    /// If the terminal has been opened, buffered records are written right
+    /// away.
+    pub fn attach(&self, terminal: &Terminal) {
+        let _ = TERMINALS
+            .with(|t| t.borrow_mut().insert(self.id, terminal.clone()));
+
+        self.write(String::new());
+    }
+
+    /// Stops writing records to the attached terminal, returning it.
+    ///
+    /// Records are buffered until another terminal is attached.
+    pub fn detach(&self) -> Option<Terminal> {
+        TERMINALS
+            .try_with(|t| t.borrow_mut().remove(&self.id))
+            .ok()
+            .flatten()
+    }
+
+    /// The rendered records that are waiting for a terminal, oldest first.
+    #[must_use]
+    pub fn backlog(&self) -> Vec<String> {
+        self.lock_backlog().iter().cloned().collect()
+    }
+
+    /// Locks the backlog (even if a panic poisoned it).
+    fn lock_backlog(&self) -> MutexGuard<'_, VecDeque<String>> {
+        self.backlog.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Renders a record as a line of terminal output.
+    fn render(&self, record: &Record<'_>) -> String {
+        let (color, tag) = match record.level() {
+            Level::Error => ("31", "ERROR"),
+            Level::Warn => ("33", "WARN "),
+            Level::Info => ("32", "INFO "),
+            Level::Debug => ("34", "DEBUG"),
+            Level::Trace => ("35", "TRACE"),
+        };
+        let (dim, bold, reset) = if self.colors {
+            ("\x1b[2m", format!("\x1b[1;{color}m"), "\x1b[0m")
+        } else {
+            ("", String::new(), "")
+        };
+
+        let mut line = format!("{dim}[{reset}");
+        if self.timestamps {
+            let now = Date::new_0();
+            let _ = write!(
+                line,
+                "{dim}{:02}:{:02}:{:02}.{:03}{reset} ",
+                now.get_hours(),
+                now.get_minutes(),
+                now.get_seconds(),
+                now.get_milliseconds(),
+            );
+        }
+        let _ = write!(line, "{bold}{tag}{reset}");
+        if self.module_paths {
+            let path = record.module_path().unwrap_or_else(|| record.target());
+            let _ = write!(line, " {dim}{path}{reset}");
+        }
+        let _ = write!(line, "{dim}]{reset} ");
+
+        // Terminals need a carriage return to get back to the first column.
+        let message = record.args().to_string();
+        line.push_str(&message.replace('\n', "\r\n"));
+        line.push_str("\r\n");
+
+        line
+    }
+
+    /// Writes `line` (after anything that's buffered) to the terminal if
+    /// there is one and it's been opened; buffers it otherwise.
+    fn write(&self, line: String) {
+        // Clone the terminal out so that records logged while we're writing
+        // (by xterm.js, say) don't find `TERMINALS` already borrowed.
+        let terminal = TERMINALS
+            .try_with(|t| t.borrow().get(&self.id).cloned())
+            .ok()
+            .flatten()
+            .filter(|terminal| terminal.element().is_some());
+
+        let mut backlog = self.lock_backlog();
+        if !line.is_empty() {
+            backlog.push_back(line);
+            while backlog.len() > self.capacity {
+                let _ = backlog.pop_front();
+            }
+        }
+
+        let Some(terminal) = terminal else { return };
+        if backlog.is_empty() {
+            return;
+        }
+
+        let pending: Vec<String> = backlog.drain(..).collect();
+        drop(backlog);
+
+        if terminal.try_write(pending.concat()).is_err() {
+            let _ = self.detach();
+
+            let mut backlog = self.lock_backlog();
+            for line in pending.into_iter().rev() {
+                backlog.push_front(line);
+            }
+            while backlog.len() > self.capacity {
+                let _ = backlog.pop_front();
+            }
+        }
+    }
+}
+
+impl Drop for TerminalLogger {
+    fn drop(&mut self) {
+        let _ = self.detach();
+    }
+}
+
+impl Log for TerminalLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() as usize <= self.level.load(Ordering::Relaxed)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            self.write(self.render(record));
+        }
+    }
+
+    /// Writes out buffered records, if the terminal is ready.
+    fn flush(&self) {
+        self.write(String::new());
+    }
+}
+
+/// The [`LevelFilter`] for a [`LogLevel`].
+fn level_filter(level: LogLevel) -> LevelFilter {
+    Level::try_from(level).map_or(LevelFilter::Off, |l| l.to_level_filter())
+}
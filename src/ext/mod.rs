@@ -643,21 +643,57 @@ pub use super::{calculated_doc, interface, object};
 pub mod addon;
 pub use addon::*;
 
+pub mod capabilities;
+
+pub mod color;
+
+pub mod contrast;
+
 pub mod disposable;
 pub use disposable::*;
 
 pub mod event;
 // pub use event::*; // Temporarily commented to fix unused import
 
+#[cfg(feature = "addon-fit")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-fit")))]
+pub mod fit;
+
+#[cfg(feature = "addon-image")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-image")))]
+pub mod image;
+
+#[cfg(feature = "proposed-api")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "proposed-api")))]
+pub mod joiner;
+
 pub mod log_level;
 // pub use log_level::*; // Temporarily commented to fix unused import
 
+pub mod logger;
+
 pub mod marker;
 // pub use marker::*; // Temporarily commented to fix unused import
 
+#[cfg(feature = "xterm-5")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
+pub mod modes;
+
 pub mod options;
 pub use options::*;
 
+pub mod panic;
+
+pub mod pty;
+
+#[cfg(feature = "addon-search")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-search")))]
+pub mod search;
+
+#[cfg(feature = "addon-serialize")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "addon-serialize")))]
+pub mod session;
+
 pub mod theme;
 // pub use theme::*; // Only used internally for now
 
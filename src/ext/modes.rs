@@ -0,0 +1,224 @@
+//! Events for when programs change a [`Terminal`]'s [`Modes`].
+//!
+//! xterm.js doesn't have an event for mode changes; a [`ModeTracker`]
+//! re-reads the modes each time written data has been parsed
+//! ([`Terminal::on_write_parsed`]) and reports what changed as
+//! [`ModeChanged`] events.
+
+use super::capabilities::{CapabilityError, Kind};
+use super::{Disposable, DisposableWrapper};
+use crate::xterm::{Modes, MouseTrackingMode, Terminal};
+
+use js_sys::Reflect;
+
+use core::cell::RefCell;
+use core::fmt;
+use std::rc::Rc;
+
+/// A mode that a program switched (i.e. vim turning on bracketed paste),
+/// along with its new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(variant_size_differences)] // They're all still tiny.
+pub enum ModeChanged {
+    /// Application Cursor Keys (DECCKM): `CSI ? 1 h`
+    ApplicationCursorKeys(bool),
+    /// Bracketed Paste Mode: `CSI ? 2004 h`
+    BracketedPaste(bool),
+    /// Insert Mode (IRM): `CSI 4 h`
+    Insert(bool),
+    /// Mouse Tracking mode: `CSI ? 9 h`, `CSI ? 1000 h`, `CSI ? 1002 h` or
+    /// `CSI ? 1003 h`
+    MouseTracking(MouseTrackingMode),
+    /// Origin Mode (DECOM): `CSI ? 6 h`
+    Origin(bool),
+    /// Send FocusIn/FocusOut events: `CSI ? 1004 h`
+    SendFocus(bool),
+}
+
+impl ModeChanged {
+    /// The changes between `old` and `new`, in the order the variants are
+    /// declared in.
+    ///
+    /// Modes that aren't set are taken to be off (and mouse tracking to be
+    /// [`MouseTrackingMode::None`]).
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::{ext::modes::ModeChanged, xterm::*};
+    /// let old = Modes::default();
+    /// let mut new = old.clone();
+    /// new.bracketed_paste_mode = Some(true);
+    /// new.mouse_tracking_mode = Some(MouseTrackingMode::Any);
+    /// new.wraparound_mode = Some(false);
+    ///
+    /// assert_eq!(
+    ///     ModeChanged::between(&old, &new),
+    ///     [
+    ///         ModeChanged::BracketedPaste(true),
+    ///         ModeChanged::MouseTracking(MouseTrackingMode::Any),
+    ///     ],
+    /// );
+    /// ```
+    #[must_use]
+    pub fn between(old: &Modes, new: &Modes) -> Vec<Self> {
+        let flag = |mode: fn(&Modes) -> Option<bool>,
+                    variant: fn(bool) -> Self| {
+            let new = mode(new).unwrap_or(false);
+            (mode(old).unwrap_or(false) != new).then(|| variant(new))
+        };
+        let mouse = |modes: &Modes| {
+            modes.mouse_tracking_mode.unwrap_or(MouseTrackingMode::None)
+        };
+
+        [
+            flag(
+                |m| m.application_cursor_keys_mode,
+                Self::ApplicationCursorKeys,
+            ),
+            flag(|m| m.bracketed_paste_mode, Self::BracketedPaste),
+            flag(|m| m.insert_mode, Self::Insert),
+            (mouse(old) != mouse(new)).then(|| Self::MouseTracking(mouse(new))),
+            flag(|m| m.origin_mode, Self::Origin),
+            flag(|m| m.send_focus_mode, Self::SendFocus),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Reads `terminal`'s modes.
+///
+/// [`Terminal::modes`] can't be used for this: xterm.js hands back a plain
+/// object, not a [`Modes`].
+fn read_modes(terminal: &Terminal) -> Modes {
+    Reflect::get(terminal, &"modes".into())
+        .ok()
+        .and_then(|modes| Modes::from_object(&modes))
+        .unwrap_or_default()
+}
+
+/// Listener for mode changes.
+type ModeListener = Box<dyn FnMut(&ModeChanged)>;
+
+/// Shared state behind a [`ModeTracker`].
+struct Inner {
+    /// The terminal being tracked.
+    terminal: Terminal,
+    /// The modes as of the last check.
+    modes: RefCell<Modes>,
+    /// Mode change listeners.
+    listeners: RefCell<Vec<ModeListener>>,
+}
+
+impl Inner {
+    /// Re-reads the terminal's modes and notifies listeners of any changes.
+    fn refresh(&self) {
+        let modes = read_modes(&self.terminal);
+        let old = self.modes.replace(modes.clone());
+        let changes = ModeChanged::between(&old, &modes);
+        if changes.is_empty() {
+            return;
+        }
+
+        // Listeners are allowed to register more listeners so we can't hold
+        // the borrow while calling them.
+        let mut listeners = self.listeners.take();
+        for change in &changes {
+            for listener in &mut listeners {
+                listener(change);
+            }
+        }
+        let mut current = self.listeners.borrow_mut();
+        listeners.append(&mut current);
+        *current = listeners;
+    }
+}
+
+/// Tracks a [`Terminal`]'s [`Modes`], emitting [`ModeChanged`] events when a
+/// program changes them.
+///
+/// ```rust,no_run
+/// # use xterm_js_sys::{ext::modes::{ModeChanged, ModeTracker}, Terminal};
+/// # use std::{cell::Cell, rc::Rc};
+/// # let terminal = Terminal::new(None);
+/// let tracker = ModeTracker::new(&terminal).unwrap();
+///
+/// // Whether pastes should be wrapped in `ESC [200~` and `ESC [201~`.
+/// let bracketed_paste = Rc::new(Cell::new(false));
+/// let flag = bracketed_paste.clone();
+/// tracker.on_mode_change(move |change| {
+///     if let ModeChanged::BracketedPaste(on) = *change {
+///         flag.set(on);
+///     }
+/// });
+/// ```
+///
+/// Dropping this detaches from the terminal.
+#[allow(clippy::module_name_repetitions)]
+pub struct ModeTracker {
+    /// Shared state.
+    inner: Rc<Inner>,
+    /// Our `on_write_parsed` listener on the terminal.
+    _write_parsed: DisposableWrapper<Disposable>,
+}
+
+impl fmt::Debug for ModeTracker {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("ModeTracker")
+            .field("modes", &self.inner.modes.borrow())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ModeTracker {
+    /// Starts tracking `terminal`'s modes.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the loaded xterm.js doesn't have `modes` or `onWriteParsed`;
+    /// see [`Terminal::capabilities`].
+    pub fn new(terminal: &Terminal) -> Result<Self, CapabilityError> {
+        let capabilities = terminal.capabilities();
+        capabilities.require(Kind::Method, "modes")?;
+        capabilities.require(Kind::Event, "onWriteParsed")?;
+
+        let inner = Rc::new(Inner {
+            terminal: terminal.clone(),
+            modes: RefCell::new(read_modes(terminal)),
+            listeners: RefCell::new(Vec::new()),
+        });
+
+        let weak = Rc::downgrade(&inner);
+        let write_parsed =
+            terminal.attach_write_parsed_event_listener(move || {
+                if let Some(inner) = weak.upgrade() {
+                    inner.refresh();
+                }
+            });
+
+        Ok(Self {
+            inner,
+            _write_parsed: write_parsed,
+        })
+    }
+
+    /// The modes as of the last check.
+    #[must_use]
+    pub fn modes(&self) -> Modes {
+        self.inner.modes.borrow().clone()
+    }
+
+    /// Registers a listener that's called for each mode that changes.
+    pub fn on_mode_change<F>(&self, listener: F)
+    where
+        F: FnMut(&ModeChanged) + 'static,
+    {
+        self.inner.listeners.borrow_mut().push(Box::new(listener));
+    }
+
+    /// Re-reads the modes now, rather than waiting for the next write (i.e.
+    /// after a [`reset`](Terminal::reset), which isn't a write).
+    pub fn refresh(&self) {
+        self.inner.refresh();
+    }
+}
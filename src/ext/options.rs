@@ -1,189 +1,245 @@
-//! Simplified helpers for [`TerminalOptions`] and [`Theme`].
+//! Simplified helpers for [`TerminalOptions`], [`Theme`] and
+//! [`WindowOptions`].
 //!
-//! This module provides builder-style methods for creating and configuring
-//! xterm.js options in a more Rust-friendly way.
+//! The option structs have builder-style `with_*` methods for every field of
+//! their own (i.e. [`TerminalOptions::with_font_size`]); this module adds
+//! [`WindowOptions`] presets and keeps around the extension traits that came
+//! before those. [`diff`] sets just the options that have changed and
+//! [`validate`] checks options against the ranges xterm.js accepts.
 //!
 //! [`TerminalOptions`]: crate::xterm::TerminalOptions
 //! [`Theme`]: crate::xterm::Theme
+//! [`WindowOptions`]: crate::xterm::WindowOptions
+//! [`TerminalOptions::with_font_size`]: crate::xterm::TerminalOptions::with_font_size
 
+#[cfg(feature = "xterm-4")]
+use crate::xterm::BellStyle;
 use crate::xterm::{
-    BellStyle, CursorStyle, LogLevel, TerminalOptions, Theme,
+    CursorStyle, LogLevel, TerminalOptions, Theme, WindowOptions,
 };
 
+pub mod diff;
+
+pub mod validate;
+
+impl WindowOptions {
+    /// Every window option explicitly turned off (which is also what
+    /// xterm.js does with options that aren't set).
+    #[must_use]
+    pub const fn none() -> Self {
+        Self::all_set(false)
+    }
+
+    /// Every window option turned on: all the reports and all the window
+    /// manipulations.
+    ///
+    /// Most of these have no default implementation in xterm.js; see
+    /// [`WindowOptions`] for how to add one (and for why they're off by
+    /// default).
+    #[must_use]
+    pub const fn all() -> Self {
+        Self::all_set(true)
+    }
+
+    /// Just the reports (the `get_*` options) turned on; everything that
+    /// changes the window stays off.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::xterm::WindowOptions;
+    /// let reports = WindowOptions::all_reports();
+    /// assert_eq!(reports.get_win_size_chars, Some(true));
+    /// assert_eq!(reports.maximize_win, Some(false));
+    /// ```
+    #[must_use]
+    pub const fn all_reports() -> Self {
+        let mut options = Self::none();
+        options.get_cell_size_pixels = Some(true);
+        options.get_icon_title = Some(true);
+        options.get_screen_size_chars = Some(true);
+        options.get_screen_size_pixels = Some(true);
+        options.get_win_position = Some(true);
+        options.get_win_size_chars = Some(true);
+        options.get_win_size_pixels = Some(true);
+        options.get_win_state = Some(true);
+        options.get_win_title = Some(true);
+        options
+    }
+
+    /// Every option set to `Some(value)`.
+    const fn all_set(value: bool) -> Self {
+        let v = Some(value);
+        Self::new(
+            v, v, v, v, v, v, v, v, v, v, v, v, v, v, v, v, v, v, v, v, v, v,
+        )
+    }
+}
+
 /// Extension trait for [`TerminalOptions`] providing builder-style methods.
+#[deprecated(
+    since = "5.5.0",
+    note = "`TerminalOptions` has `with_*` methods for every field of its own"
+)]
 pub trait TerminalOptionsExt {
     /// Set the log level and return self for chaining.
     fn with_log_level(self, log_level: LogLevel) -> Self;
-    
+
     /// Set the font size and return self for chaining.
     fn with_font_size(self, font_size: f32) -> Self;
-    
+
     /// Set the number of columns and return self for chaining.
     fn with_cols(self, cols: u16) -> Self;
-    
+
     /// Set the number of rows and return self for chaining.
     fn with_rows(self, rows: u16) -> Self;
-    
+
     /// Set the cursor style and return self for chaining.
     fn with_cursor_style(self, cursor_style: CursorStyle) -> Self;
-    
+
     /// Set whether the cursor blinks and return self for chaining.
     fn with_cursor_blink(self, cursor_blink: bool) -> Self;
-    
+
     /// Set the bell style and return self for chaining.
+    #[cfg(feature = "xterm-4")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-4")))]
     fn with_bell_style(self, bell_style: BellStyle) -> Self;
-    
+
     /// Set whether to convert EOL and return self for chaining.
     fn with_convert_eol(self, convert_eol: bool) -> Self;
-    
+
     /// Set the scrollback amount and return self for chaining.
     fn with_scrollback(self, scrollback: u32) -> Self;
-    
+
     /// Set the tab stop width and return self for chaining.
     fn with_tab_stop_width(self, tab_stop_width: u16) -> Self;
 }
 
+#[allow(deprecated)]
 impl TerminalOptionsExt for TerminalOptions {
-    fn with_log_level(mut self, log_level: LogLevel) -> Self {
-        self.log_level = Some(log_level);
-        self
-    }
-    
-    fn with_font_size(mut self, font_size: f32) -> Self {
-        self.font_size = Some(font_size);
-        self
-    }
-    
-    fn with_cols(mut self, cols: u16) -> Self {
-        self.cols = Some(cols);
-        self
-    }
-    
-    fn with_rows(mut self, rows: u16) -> Self {
-        self.rows = Some(rows);
-        self
-    }
-    
-    fn with_cursor_style(mut self, cursor_style: CursorStyle) -> Self {
-        self.cursor_style = Some(cursor_style);
-        self
-    }
-    
-    fn with_cursor_blink(mut self, cursor_blink: bool) -> Self {
-        self.cursor_blink = Some(cursor_blink);
-        self
-    }
-    
-    fn with_bell_style(mut self, bell_style: BellStyle) -> Self {
-        self.bell_style = Some(bell_style);
-        self
-    }
-    
-    fn with_convert_eol(mut self, convert_eol: bool) -> Self {
-        self.convert_eol = Some(convert_eol);
-        self
-    }
-    
-    fn with_scrollback(mut self, scrollback: u32) -> Self {
-        self.scrollback = Some(scrollback);
-        self
-    }
-    
-    fn with_tab_stop_width(mut self, tab_stop_width: u16) -> Self {
-        self.tab_stop_width = Some(tab_stop_width);
-        self
+    fn with_log_level(self, log_level: LogLevel) -> Self {
+        TerminalOptions::with_log_level(self, log_level)
+    }
+
+    fn with_font_size(self, font_size: f32) -> Self {
+        TerminalOptions::with_font_size(self, font_size)
+    }
+
+    fn with_cols(self, cols: u16) -> Self {
+        TerminalOptions::with_cols(self, cols)
+    }
+
+    fn with_rows(self, rows: u16) -> Self {
+        TerminalOptions::with_rows(self, rows)
+    }
+
+    fn with_cursor_style(self, cursor_style: CursorStyle) -> Self {
+        TerminalOptions::with_cursor_style(self, cursor_style)
+    }
+
+    fn with_cursor_blink(self, cursor_blink: bool) -> Self {
+        TerminalOptions::with_cursor_blink(self, cursor_blink)
+    }
+
+    #[cfg(feature = "xterm-4")]
+    fn with_bell_style(self, bell_style: BellStyle) -> Self {
+        TerminalOptions::with_bell_style(self, bell_style)
+    }
+
+    fn with_convert_eol(self, convert_eol: bool) -> Self {
+        TerminalOptions::with_convert_eol(self, convert_eol)
+    }
+
+    fn with_scrollback(self, scrollback: u32) -> Self {
+        TerminalOptions::with_scrollback(self, scrollback)
+    }
+
+    fn with_tab_stop_width(self, tab_stop_width: u16) -> Self {
+        TerminalOptions::with_tab_stop_width(self, tab_stop_width)
     }
 }
 
 /// Extension trait for [`Theme`] providing builder-style methods.
+#[deprecated(
+    since = "5.5.0",
+    note = "`Theme` has `with_*` methods for every color of its own"
+)]
 pub trait ThemeExt {
     /// Set the foreground color and return self for chaining.
     fn with_foreground<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the background color and return self for chaining.
     fn with_background<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the cursor color and return self for chaining.
     fn with_cursor<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the black color and return self for chaining.
     fn with_black<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the red color and return self for chaining.
     fn with_red<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the green color and return self for chaining.
     fn with_green<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the yellow color and return self for chaining.
     fn with_yellow<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the blue color and return self for chaining.
     fn with_blue<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the magenta color and return self for chaining.
     fn with_magenta<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the cyan color and return self for chaining.
     fn with_cyan<S: Into<String>>(self, color: S) -> Self;
-    
+
     /// Set the white color and return self for chaining.
     fn with_white<S: Into<String>>(self, color: S) -> Self;
 }
 
+#[allow(deprecated)]
 impl ThemeExt for Theme {
-    fn with_foreground<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_foreground(Some(color.into()));
-        self
-    }
-    
-    fn with_background<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_background(Some(color.into()));
-        self
-    }
-    
-    fn with_cursor<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_cursor(Some(color.into()));
-        self
-    }
-    
-    fn with_black<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_black(Some(color.into()));
-        self
-    }
-    
-    fn with_red<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_red(Some(color.into()));
-        self
-    }
-    
-    fn with_green<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_green(Some(color.into()));
-        self
-    }
-    
-    fn with_yellow<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_yellow(Some(color.into()));
-        self
-    }
-    
-    fn with_blue<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_blue(Some(color.into()));
-        self
-    }
-    
-    fn with_magenta<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_magenta(Some(color.into()));
-        self
-    }
-    
-    fn with_cyan<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_cyan(Some(color.into()));
-        self
-    }
-    
-    fn with_white<S: Into<String>>(mut self, color: S) -> Self {
-        self.set_white(Some(color.into()));
-        self
+    fn with_foreground<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_foreground(self, color.into())
+    }
+
+    fn with_background<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_background(self, color.into())
+    }
+
+    fn with_cursor<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_cursor(self, color.into())
+    }
+
+    fn with_black<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_black(self, color.into())
+    }
+
+    fn with_red<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_red(self, color.into())
+    }
+
+    fn with_green<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_green(self, color.into())
+    }
+
+    fn with_yellow<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_yellow(self, color.into())
+    }
+
+    fn with_blue<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_blue(self, color.into())
+    }
+
+    fn with_magenta<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_magenta(self, color.into())
+    }
+
+    fn with_cyan<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_cyan(self, color.into())
+    }
+
+    fn with_white<S: Into<String>>(self, color: S) -> Self {
+        Theme::with_white(self, color.into())
     }
 }
@@ -0,0 +1,340 @@
+//! Diffing [`TerminalOptions`], so that only the options that changed get set.
+//!
+//! xterm.js compares object options ([`theme`], [`window_options`]) by
+//! reference and re-renders (sometimes expensively) when some options are set,
+//! so pushing a whole [`TerminalOptions`] whenever one option changes does more
+//! work than it has to. [`TerminalOptions::diff`] lists the options that
+//! differ between two sets of options and what changing each costs;
+//! [`OptionsDiff::apply`] sets just those (after checking that the new options
+//! are [valid](TerminalOptions::validate)).
+//!
+//! [`cols`] and [`rows`] are the exception: xterm.js only takes them in the
+//! constructor and throws if they're set as options, so a diff that changes
+//! them [needs a resize](OptionsDiff::needs_resize) instead.
+//!
+//! ```rust,no_run
+//! # use xterm_js_sys::xterm::{Terminal, TerminalOptions};
+//! # let terminal = Terminal::new(None);
+//! let old = TerminalOptions::default().with_font_size(12.0);
+//! let new = old.clone().with_font_size(13.0).with_cursor_blink(true);
+//!
+//! let diff = old.diff(&new);
+//! assert!(diff.rebuilds_texture_atlas());
+//! diff.apply(&terminal).unwrap();
+//! ```
+//!
+//! [`theme`]: TerminalOptions::theme
+//! [`window_options`]: TerminalOptions::window_options
+//! [`cols`]: TerminalOptions::cols
+//! [`rows`]: TerminalOptions::rows
+
+use super::validate::OptionsError;
+use crate::xterm::{Terminal, TerminalOptions};
+
+use js_sys::{Object, Reflect};
+use wasm_bindgen::JsValue;
+
+/// What xterm.js does when an option changes.
+///
+/// Ordered from cheapest to most expensive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OptionEffect {
+    /// Nothing beyond updating the option (and, for the cursor options,
+    /// redrawing the cursor's row).
+    None,
+    /// Every row is redrawn.
+    Refresh,
+    /// The renderer's texture atlas (its cache of rasterized glyphs) is
+    /// rebuilt and every row is redrawn.
+    TextureAtlas,
+}
+
+/// One option that differs; see [`TerminalOptions::diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OptionChange {
+    /// The option's xterm.js name (i.e. `fontSize`).
+    pub name: &'static str,
+    /// What xterm.js does when the option is set.
+    pub effect: OptionEffect,
+}
+
+/// The options that differ between two [`TerminalOptions`]; see
+/// [`TerminalOptions::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionsDiff {
+    /// The options that changed, in alphabetical order.
+    pub changes: Vec<OptionChange>,
+    /// The new options.
+    options: TerminalOptions,
+}
+
+impl OptionsDiff {
+    /// Whether nothing changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The most expensive effect of any of the changes.
+    #[must_use]
+    pub fn effect(&self) -> OptionEffect {
+        self.changes
+            .iter()
+            .map(|c| c.effect)
+            .max()
+            .unwrap_or(OptionEffect::None)
+    }
+
+    /// Whether applying the changes makes xterm.js redraw every row.
+    #[must_use]
+    pub fn refreshes(&self) -> bool {
+        self.effect() >= OptionEffect::Refresh
+    }
+
+    /// Whether applying the changes makes xterm.js rebuild its texture atlas.
+    #[must_use]
+    pub fn rebuilds_texture_atlas(&self) -> bool {
+        self.effect() >= OptionEffect::TextureAtlas
+    }
+
+    /// Whether [`cols`] or [`rows`] changed.
+    ///
+    /// These are left out of [`to_object`](OptionsDiff::to_object) (setting
+    /// them as options throws); [`apply`](OptionsDiff::apply) resizes the
+    /// terminal instead.
+    ///
+    /// [`cols`]: TerminalOptions::cols
+    /// [`rows`]: TerminalOptions::rows
+    #[must_use]
+    pub fn needs_resize(&self) -> bool {
+        self.changes.iter().any(|c| RESIZE.contains(&c.name))
+    }
+
+    /// A plain object with just the options that changed, other than the size
+    /// (see [`needs_resize`](OptionsDiff::needs_resize)).
+    ///
+    /// Object options ([`theme`], [`window_options`]) are always new objects,
+    /// so xterm.js sees that they've changed. Options that were unset get
+    /// xterm.js's default for the option: xterm.js doesn't reset options that
+    /// are set to `undefined` (some end up as `NaN` or missing fonts).
+    ///
+    /// [`theme`]: TerminalOptions::theme
+    /// [`window_options`]: TerminalOptions::window_options
+    #[must_use]
+    pub fn to_object(&self) -> Object {
+        let object = Object::new();
+        for change in &self.changes {
+            if RESIZE.contains(&change.name) {
+                continue;
+            }
+            if let Some(field) = FIELDS.iter().find(|f| f.name == change.name) {
+                let value = (field.value)(&self.options);
+                let _ = Reflect::set(&object, &change.name.into(), &value);
+            }
+        }
+
+        object
+    }
+
+    /// Sets the options that changed on `terminal` (if any did), leaving the
+    /// rest alone.
+    ///
+    /// If the size changed the terminal is [resized](Terminal::resize); a
+    /// dimension that's unset in the new options keeps its current value.
+    ///
+    /// # Errors
+    ///
+    /// Fails, without setting anything, if the new options don't
+    /// [validate](TerminalOptions::validate).
+    pub fn apply(&self, terminal: &Terminal) -> Result<(), OptionsError> {
+        self.options.validate()?;
+
+        let object = self.to_object();
+        if Object::keys(&object).length() != 0 {
+            terminal.set_options_object(&object);
+        }
+        if self.needs_resize() {
+            terminal.resize(
+                self.options.cols.unwrap_or_else(|| terminal.cols()),
+                self.options.rows.unwrap_or_else(|| terminal.rows()),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl TerminalOptions {
+    /// The options that differ between `self` and `new`.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::{ext::options::diff::OptionEffect, xterm::*};
+    /// let old = TerminalOptions::default().with_cursor_blink(true);
+    /// let new = TerminalOptions::default()
+    ///     .with_scrollback(5000)
+    ///     .with_theme(Theme::nord());
+    ///
+    /// let diff = old.diff(&new);
+    /// let names: Vec<_> = diff.changes.iter().map(|c| c.name).collect();
+    /// assert_eq!(names, ["cursorBlink", "scrollback", "theme"]);
+    /// assert_eq!(diff.effect(), OptionEffect::TextureAtlas);
+    ///
+    /// assert!(new.diff(&new.clone()).is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, new: &TerminalOptions) -> OptionsDiff {
+        let changes = FIELDS
+            .iter()
+            .filter(|f| (f.changed)(self, new))
+            .map(|f| OptionChange {
+                name: f.name,
+                effect: f.effect,
+            })
+            .collect();
+
+        OptionsDiff {
+            changes,
+            options: new.clone(),
+        }
+    }
+}
+
+/// How to compare and set one of [`TerminalOptions`]' fields.
+struct Field {
+    /// The option's xterm.js name.
+    name: &'static str,
+    /// What setting the option does.
+    effect: OptionEffect,
+    /// Whether the option differs.
+    changed: fn(&TerminalOptions, &TerminalOptions) -> bool,
+    /// The option's value, for JS.
+    value: fn(&TerminalOptions) -> JsValue,
+}
+
+/// Makes the [`Field`] for each option: `"jsName" => field: Effect = default`,
+/// with `field()` for fields that are behind getters (and a `#[cfg]` for
+/// options only one xterm.js version has). `default` is xterm.js's default for
+/// the option, which is what's set when the option is unset.
+macro_rules! fields {
+    ($(
+        $(#[$attr:meta])*
+        $name:literal => $field:ident $(($($call:tt)*))?: $effect:ident
+            = $default:expr,
+    )*) => {
+        &[$(
+            $(#[$attr])*
+            Field {
+                name: $name,
+                effect: OptionEffect::$effect,
+                changed: |old, new| {
+                    old.$field $(($($call)*))? != new.$field $(($($call)*))?
+                },
+                value: |options| match options.$field $(($($call)*))? {
+                    Some(value) => JsValue::from(value),
+                    None => JsValue::from($default),
+                },
+            },
+        )*]
+    };
+}
+
+/// Every option (for the enabled xterm.js version), in alphabetical order.
+#[rustfmt::skip]
+const FIELDS: &[Field] = fields! {
+    #[cfg(feature = "xterm-4")]
+    "allowProposedApi" => allow_proposed_api: None = true,
+    #[cfg(feature = "xterm-5")]
+    "allowProposedApi" => allow_proposed_api: None = false,
+    "allowTransparency" => allow_transparency: TextureAtlas = false,
+    "altClickMovesCursor" => alt_click_moves_cursor: None = true,
+    #[cfg(feature = "xterm-4")]
+    "bellSound" => bell_sound(): None = default_bell_sound(),
+    #[cfg(feature = "xterm-4")]
+    "bellStyle" => bell_style: None = "none",
+    "cols" => cols: Refresh = 80,
+    "convertEol" => convert_eol: None = false,
+    "cursorBlink" => cursor_blink: None = false,
+    #[cfg(feature = "xterm-5")]
+    "cursorInactiveStyle" => cursor_inactive_style: None = "outline",
+    "cursorStyle" => cursor_style: None = "block",
+    "cursorWidth" => cursor_width: None = 1,
+    "customGlyphs" => custom_glyphs: TextureAtlas = true,
+    "disableStdin" => disable_stdin: None = false,
+    "drawBoldTextInBrightColors" =>
+        draw_bold_text_in_bright_colors: TextureAtlas = true,
+    "fastScrollModifier" => fast_scroll_modifier: None = "alt",
+    "fastScrollSensitivity" => fast_scroll_sensitivity: None = 5,
+    "fontFamily" => font_family(): TextureAtlas =
+        "courier-new, courier, monospace",
+    "fontSize" => font_size: TextureAtlas = 15,
+    "fontWeight" => font_weight: TextureAtlas = "normal",
+    "fontWeightBold" => font_weight_bold: TextureAtlas = "bold",
+    #[cfg(feature = "xterm-5")]
+    "ignoreBracketedPasteMode" => ignore_bracketed_paste_mode: None = false,
+    "letterSpacing" => letter_spacing: TextureAtlas = 0,
+    "lineHeight" => line_height: TextureAtlas = 1,
+    "linkTooltipHoverDuration" => link_tooltip_hover_duration: None = 500,
+    "logLevel" => log_level: None = "info",
+    #[cfg(feature = "xterm-5")]
+    "logger" => logger(): None = JsValue::NULL,
+    "macOptionClickForcesSelection" =>
+        mac_option_click_forces_selection: None = false,
+    "macOptionIsMeta" => mac_option_is_meta: None = false,
+    "minimumContrastRatio" => minimum_contrast_ratio: TextureAtlas = 1,
+    #[cfg(feature = "xterm-4")]
+    "rendererType" => renderer_type: Refresh = "canvas",
+    "rightClickSelectsWord" => right_click_selects_word: None = is_mac(),
+    "rows" => rows: Refresh = 24,
+    "screenReaderMode" => screen_reader_mode: None = false,
+    "scrollSensitivity" => scroll_sensitivity: None = 1,
+    "scrollback" => scrollback: None = 1000,
+    "tabStopWidth" => tab_stop_width: None = 8,
+    "theme" => theme(): TextureAtlas = Object::new(),
+    "windowOptions" => window_options(): None = Object::new(),
+    "windowsMode" => windows_mode: None = false,
+    #[cfg(feature = "xterm-5")]
+    "windowsPty" => windows_pty: None = Object::new(),
+    "wordSeparator" => word_separator(): None = " ()[]{}',\"`",
+};
+
+/// xterm.js's default for `rightClickSelectsWord`, which is on for macOS.
+fn is_mac() -> bool {
+    let platform = Reflect::get(&js_sys::global(), &"navigator".into())
+        .and_then(|navigator| Reflect::get(&navigator, &"platform".into()))
+        .ok()
+        .and_then(|platform| platform.as_string());
+
+    matches!(
+        platform.as_deref(),
+        Some("Macintosh" | "MacIntel" | "MacPPC" | "Mac68K"),
+    )
+}
+
+/// xterm.js 4's default bell sound.
+///
+/// This is a whole MP3 (as a data URI), so rather than copying it we read it
+/// off a throwaway terminal.
+#[cfg(feature = "xterm-4")]
+fn default_bell_sound() -> JsValue {
+    use wasm_bindgen::JsCast;
+
+    let terminal = Terminal::new(None);
+    let sound = Reflect::get(&terminal, &"getOption".into())
+        .and_then(|get| {
+            get.unchecked_into::<js_sys::Function>()
+                .call1(&terminal, &"bellSound".into())
+        })
+        .unwrap_or(JsValue::UNDEFINED);
+    terminal.dispose();
+
+    sound
+}
+
+/// The options that are applied with [`Terminal::resize`].
+const RESIZE: &[&str] = &["cols", "rows"];
+
+/// The xterm.js names of every option (for the enabled xterm.js version), in
+/// alphabetical order.
+pub(crate) fn option_names() -> impl Iterator<Item = &'static str> {
+    FIELDS.iter().map(|field| field.name)
+}
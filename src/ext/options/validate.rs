@@ -0,0 +1,266 @@
+//! Checking [`TerminalOptions`] against the ranges xterm.js accepts.
+//!
+//! xterm.js throws on some out of range options (i.e. a `lineHeight` under 1)
+//! and quietly clamps or misrenders others (i.e. `cols` under 2 or a
+//! `fontSize` of 0); [`TerminalOptions::validate`] catches both in Rust.
+//! Options whose types already rule out bad values (i.e. `scrollback`, which
+//! can't be negative) aren't checked.
+//!
+//! Every way into a terminal from here validates first: a chain of `with_*`
+//! calls ends in [`TerminalOptions::validated`], [`Terminal::try_new`] builds
+//! a terminal from options that validate and [`Terminal::try_set_options`]
+//! sets options that validate (so does [`OptionsDiff::apply`]).
+//!
+//! ```rust,no_run
+//! # use xterm_js_sys::xterm::{Terminal, TerminalOptions};
+//! let options = TerminalOptions::default()
+//!     .with_cols(100)
+//!     .with_font_size(13.0)
+//!     .validated()?;
+//! let terminal = Terminal::try_new(options)?;
+//!
+//! terminal.try_set_options(&TerminalOptions::default().with_rows(40))?;
+//! # Ok::<_, xterm_js_sys::ext::options::validate::OptionsError>(())
+//! ```
+//!
+//! [`OptionsDiff::apply`]: super::diff::OptionsDiff::apply
+
+use crate::xterm::{FontWeight, Terminal, TerminalOptions};
+
+use core::fmt::{self, Display};
+use std::error::Error;
+
+/// The values an option accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Allowed {
+    /// At least the given value.
+    AtLeast(f32),
+    /// More than the given value.
+    MoreThan(f32),
+    /// Between the given values (inclusive).
+    Between(f32, f32),
+}
+
+impl Allowed {
+    /// Whether `value` is allowed (`NaN` never is).
+    #[must_use]
+    pub fn contains(self, value: f32) -> bool {
+        match self {
+            Self::AtLeast(min) => value >= min,
+            Self::MoreThan(min) => value > min,
+            Self::Between(min, max) => (min..=max).contains(&value),
+        }
+    }
+}
+
+impl Display for Allowed {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AtLeast(min) => write!(fmt, "at least {min}"),
+            Self::MoreThan(min) => write!(fmt, "more than {min}"),
+            Self::Between(min, max) => write!(fmt, "between {min} and {max}"),
+        }
+    }
+}
+
+/// An option that's out of range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct OptionFieldError {
+    /// The option's xterm.js name (i.e. `fontSize`).
+    pub field: &'static str,
+    /// The option's value.
+    pub value: f32,
+    /// The values the option accepts.
+    pub allowed: Allowed,
+}
+
+impl Display for OptionFieldError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            fmt,
+            "`{}` is {} but must be {}",
+            self.field, self.value, self.allowed
+        )
+    }
+}
+
+/// Error produced when [`TerminalOptions`] are
+/// [validated](TerminalOptions::validate); lists every option that's out of
+/// range.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct OptionsError(pub Vec<OptionFieldError>);
+
+impl Display for OptionsError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "invalid terminal options")?;
+        for (idx, err) in self.0.iter().enumerate() {
+            write!(fmt, "{} {err}", if idx == 0 { ":" } else { ";" })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for OptionsError {}
+
+impl TerminalOptions {
+    /// Checks that every option that's set is in the range xterm.js accepts,
+    /// listing all the ones that aren't.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::{ext::options::validate::Allowed, xterm::*};
+    /// let options = TerminalOptions::default()
+    ///     .with_font_size(0.0)
+    ///     .with_line_height(0.8)
+    ///     .with_cols(80);
+    ///
+    /// let err = options.validate().unwrap_err();
+    /// assert_eq!(err.0[0].field, "fontSize");
+    /// assert_eq!(err.0[1].allowed, Allowed::AtLeast(1.0));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "invalid terminal options: `fontSize` is 0 but must be more than \
+    ///      0; `lineHeight` is 0.8 but must be at least 1",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if any option is out of range.
+    pub fn validate(&self) -> Result<(), OptionsError> {
+        let weight = |weight: Option<FontWeight>| match weight {
+            Some(FontWeight::Numeric(weight)) => Some(f32::from(weight)),
+            _ => None,
+        };
+
+        let checks = [
+            (
+                "cols",
+                self.cols.map(f32::from),
+                Allowed::Between(2.0, MAX_SIZE),
+            ),
+            ("cursorWidth", self.cursor_width, AT_LEAST_1),
+            (
+                "fastScrollSensitivity",
+                self.fast_scroll_sensitivity,
+                Allowed::MoreThan(0.0),
+            ),
+            ("fontSize", self.font_size, Allowed::MoreThan(0.0)),
+            ("fontWeight", weight(self.font_weight), FONT_WEIGHTS),
+            (
+                "fontWeightBold",
+                weight(self.font_weight_bold),
+                FONT_WEIGHTS,
+            ),
+            ("lineHeight", self.line_height, AT_LEAST_1),
+            (
+                "minimumContrastRatio",
+                self.minimum_contrast_ratio,
+                Allowed::Between(1.0, 21.0),
+            ),
+            (
+                "rows",
+                self.rows.map(f32::from),
+                Allowed::Between(1.0, MAX_SIZE),
+            ),
+            (
+                "scrollSensitivity",
+                self.scroll_sensitivity,
+                Allowed::MoreThan(0.0),
+            ),
+            (
+                "tabStopWidth",
+                self.tab_stop_width.map(f32::from),
+                AT_LEAST_1,
+            ),
+        ];
+
+        let errors: Vec<_> = checks
+            .into_iter()
+            .filter_map(|(field, value, allowed)| {
+                value.filter(|v| !allowed.contains(*v)).map(|value| {
+                    OptionFieldError {
+                        field,
+                        value,
+                        allowed,
+                    }
+                })
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(OptionsError(errors))
+        }
+    }
+
+    /// [Validates](TerminalOptions::validate) the options, for the end of a
+    /// chain of `with_*` calls.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::xterm::TerminalOptions;
+    /// let options = TerminalOptions::default().with_tab_stop_width(0);
+    /// assert!(options.validated().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if any option is out of range.
+    pub fn validated(self) -> Result<Self, OptionsError> {
+        self.validate().map(|()| self)
+    }
+}
+
+impl Terminal {
+    /// Creates a new [`Terminal`] with the given options, if they
+    /// [validate](TerminalOptions::validate).
+    ///
+    /// The options are passed as a plain object (see
+    /// [`Terminal::new_with_options_object`]), so xterm.js sees all of them.
+    ///
+    /// # Errors
+    ///
+    /// Fails, without creating a terminal, if any option is out of range.
+    pub fn try_new(options: TerminalOptions) -> Result<Terminal, OptionsError> {
+        let options = options.validated()?;
+        Ok(Terminal::new_with_options_object(&options.to_object()))
+    }
+
+    /// Sets the options that are set in `options`, if they
+    /// [validate](TerminalOptions::validate); options that are unset are left
+    /// alone.
+    ///
+    /// Unlike [`Terminal::set_options`] this can change [`cols`] and [`rows`]
+    /// (by [resizing](Terminal::resize) the terminal).
+    ///
+    /// # Errors
+    ///
+    /// Fails, without setting anything, if any option is out of range.
+    ///
+    /// [`cols`]: TerminalOptions::cols
+    /// [`rows`]: TerminalOptions::rows
+    pub fn try_set_options(
+        &self,
+        options: &TerminalOptions,
+    ) -> Result<(), OptionsError> {
+        // Everything that's set differs from the defaults (which are all
+        // unset), so the diff has exactly the options that are set.
+        TerminalOptions::default().diff(options).apply(self)
+    }
+}
+
+/// The most `cols` or `rows` a terminal can have.
+///
+/// xterm.js doesn't cap these itself, but it allocates (and renders) every
+/// cell of the viewport, so a terminal this big is already unusable; anything
+/// bigger is treated as a mistake (i.e. a size in pixels rather than cells).
+pub const MAX_SIZE: f32 = 4096.0;
+
+/// The range most size-like options take.
+const AT_LEAST_1: Allowed = Allowed::AtLeast(1.0);
+
+/// The range numeric font weights take.
+const FONT_WEIGHTS: Allowed = Allowed::Between(1.0, 1000.0);
@@ -0,0 +1,232 @@
+//! A panic hook that reports Rust panics in a [`Terminal`].
+//!
+//! When a wasm app panics the terminal just stops responding and the panic
+//! message only makes it to the dev console (if that). [`PanicHook`] writes
+//! the message and where it came from to a terminal, tidying up the terminal
+//! first so the message is actually visible.
+
+use crate::xterm::{BufferType, Terminal};
+
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
+
+use core::fmt::Write;
+use core::panic::Location;
+use core::sync::atomic::{AtomicBool, Ordering};
+use std::cell::RefCell;
+
+thread_local! {
+    /// The terminal panics are reported in, and how.
+    static TARGET: RefCell<Option<(Terminal, PanicHook)>> =
+        const { RefCell::new(None) };
+}
+
+/// Whether our hook has been added to the chain of panic hooks.
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Sequences that put the modes a program is likely to have changed back to
+/// their defaults (without clearing the screen, unlike a full reset).
+const RESET_MODES: &str = concat!(
+    "\x1b[?1l",    // normal cursor keys
+    "\x1b[?66l",   // normal keypad
+    "\x1b[?2004l", // no bracketed paste
+    "\x1b[4l",     // replace mode
+    "\x1b[?6l",    // absolute origin
+    "\x1b[?45l",   // no reverse wraparound
+    "\x1b[?1004l", // no focus events
+    "\x1b[?7h",    // wraparound
+    "\x1b[?9l",    // no X10 mouse tracking
+    "\x1b[?1000l", // no VT200 mouse tracking
+    "\x1b[?1002l", // no drag mouse tracking
+    "\x1b[?1003l", // no any-event mouse tracking
+    "\x1b[r",      // full screen scroll region
+    "\x1b[0m",     // default character attributes
+);
+
+/// Leaves the alternate buffer.
+const LEAVE_ALT_BUFFER: &str = "\x1b[?1049l";
+
+/// Shows the cursor.
+const SHOW_CURSOR: &str = "\x1b[?25h";
+
+/// Reports panics in a [`Terminal`] (in bold on red so they stand out).
+///
+/// Before the report is written the terminal can (optionally, but by
+/// default) be switched back to the normal buffer, have its modes reset and
+/// have its cursor shown; programs that use the alternate buffer or hide the
+/// cursor would otherwise hide the report.
+///
+/// The hook is added in front of whatever panic hook was installed already
+/// (i.e. the default one, or [`console_error_panic_hook`]'s), which still
+/// runs afterwards.
+///
+/// ```rust,no_run
+/// # use xterm_js_sys::{ext::panic::PanicHook, Terminal};
+/// let terminal = Terminal::new(None);
+/// PanicHook::new().with_leave_alt_buffer(false).install(&terminal);
+/// ```
+///
+/// [`console_error_panic_hook`]: https://docs.rs/console_error_panic_hook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PanicHook {
+    /// Whether to leave the alternate buffer first.
+    leave_alt_buffer: bool,
+    /// Whether to reset modes (and character attributes) first.
+    reset_modes: bool,
+    /// Whether to show the cursor first.
+    show_cursor: bool,
+}
+
+impl Default for PanicHook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PanicHook {
+    /// A hook that leaves the alternate buffer, resets modes and shows the
+    /// cursor before writing the report.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            leave_alt_buffer: true,
+            reset_modes: true,
+            show_cursor: true,
+        }
+    }
+
+    /// Sets whether to switch back to the normal buffer (if the alternate
+    /// buffer is active) before writing the report.
+    #[must_use]
+    pub const fn with_leave_alt_buffer(
+        mut self,
+        leave_alt_buffer: bool,
+    ) -> Self {
+        self.leave_alt_buffer = leave_alt_buffer;
+        self
+    }
+
+    /// Sets whether to reset modes (cursor keys, mouse tracking, scroll
+    /// region, character attributes, etc.) before writing the report.
+    #[must_use]
+    pub const fn with_reset_modes(mut self, reset_modes: bool) -> Self {
+        self.reset_modes = reset_modes;
+        self
+    }
+
+    /// Sets whether to show the cursor before writing the report.
+    #[must_use]
+    pub const fn with_show_cursor(mut self, show_cursor: bool) -> Self {
+        self.show_cursor = show_cursor;
+        self
+    }
+
+    /// Reports panics (on this thread) in `terminal`.
+    ///
+    /// Installing again swaps out the terminal and settings; the hook is only
+    /// added to the chain once.
+    pub fn install(self, terminal: &Terminal) {
+        TARGET.with(|t| *t.borrow_mut() = Some((terminal.clone(), self)));
+
+        if !INSTALLED.swap(true, Ordering::SeqCst) {
+            let previous = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                let message = info
+                    .payload()
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| {
+                        info.payload().downcast_ref::<String>().map(|s| &**s)
+                    })
+                    .unwrap_or("Box<dyn Any>");
+
+                report(message, info.location());
+                previous(info);
+            }));
+        }
+    }
+
+    /// Stops reporting panics (on this thread) in a terminal, returning the
+    /// terminal.
+    ///
+    /// Panics go straight on to the panic hook that was installed before
+    /// [`install`](PanicHook::install) again (ours stays in the chain but
+    /// does nothing until it's installed again).
+    #[must_use]
+    pub fn uninstall() -> Option<Terminal> {
+        TARGET
+            .try_with(|t| t.try_borrow_mut().ok()?.take())
+            .ok()
+            .flatten()
+            .map(|(terminal, _)| terminal)
+    }
+
+    /// Renders a panic report (including the sequences that tidy up the
+    /// terminal, apart from leaving the alternate buffer).
+    #[must_use]
+    pub fn render(
+        &self,
+        message: &str,
+        location: Option<&Location<'_>>,
+    ) -> String {
+        let mut out = String::new();
+        if self.reset_modes {
+            out.push_str(RESET_MODES);
+        }
+        if self.show_cursor {
+            out.push_str(SHOW_CURSOR);
+        }
+
+        out.push_str("\r\n\x1b[1;97;41m panicked \x1b[0m ");
+        out.push_str("\x1b[1;31m");
+        out.push_str(&message.replace('\n', "\r\n"));
+        out.push_str("\x1b[0m\r\n");
+
+        if let Some(location) = location {
+            let _ = write!(
+                out,
+                "  \x1b[2mat {}:{}:{}\x1b[0m\r\n",
+                location.file(),
+                location.line(),
+                location.column(),
+            );
+        }
+
+        out
+    }
+}
+
+/// Writes a report to the terminal, if there is one.
+fn report(message: &str, location: Option<&Location<'_>>) {
+    let target = TARGET
+        .try_with(|t| t.try_borrow().ok().and_then(|t| t.clone()))
+        .ok()
+        .flatten();
+    let Some((terminal, hook)) = target else {
+        return;
+    };
+
+    let mut out = String::new();
+    if hook.leave_alt_buffer && in_alt_buffer(&terminal) == Some(true) {
+        out.push_str(LEAVE_ALT_BUFFER);
+    }
+    out.push_str(&hook.render(message, location));
+
+    // Nothing to be done if the terminal's gone.
+    let _ = terminal.try_write(out);
+}
+
+/// Whether the alternate buffer is active, if that can be found out.
+///
+/// This runs in a panic hook, where panicking again aborts, so it goes
+/// through [`Reflect`] (which catches exceptions, i.e. from a disposed
+/// terminal) rather than the [`Terminal::buffer`] bindings (which don't).
+fn in_alt_buffer(terminal: &Terminal) -> Option<bool> {
+    let get =
+        |object: &JsValue, key: &str| Reflect::get(object, &key.into()).ok();
+
+    let buffer = get(terminal, "buffer")?;
+    let active = get(&buffer, "active")?;
+    let buffer_type = BufferType::from_js_value(&get(&active, "type")?)?;
+    Some(buffer_type == BufferType::Alternate)
+}
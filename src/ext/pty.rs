@@ -0,0 +1,936 @@
+//! Connects a [`Terminal`] to a remote PTY over a [`WebSocket`].
+//!
+//! Two wire protocols are supported:
+//!   - [ttyd]'s: binary frames prefixed with an ASCII command byte, with the
+//!     `tty` subprotocol.
+//!   - [gotty]'s: text frames prefixed with an ASCII command byte (output is
+//!     base64 encoded), with the `webtty` subprotocol.
+//!
+//! The framing lives in [`PtyProtocol`] and is independent of the browser so
+//! that it can also be used to write (or test against) a stand-in server. The
+//! actual connection is [`PtyConnection`].
+//!
+//! [ttyd]: https://github.com/tsl0922/ttyd
+//! [gotty]: https://github.com/yudai/gotty
+//! [`WebSocket`]: web_sys::WebSocket
+
+use super::{Disposable, DisposableWrapper};
+use crate::xterm::{ResizeEventData, Str, Terminal};
+
+use js_sys::{ArrayBuffer, Reflect, Uint8Array, JSON};
+use wasm_bindgen::{prelude::*, JsCast};
+use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
+
+use core::cell::{Cell, RefCell};
+use core::fmt::{self, Display, Write};
+use std::error::Error;
+use std::rc::{Rc, Weak};
+
+/// The wire protocol spoken by the remote end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PtyProtocol {
+    /// [ttyd](https://github.com/tsl0922/ttyd)'s protocol.
+    Ttyd,
+    /// [gotty](https://github.com/yudai/gotty)'s protocol.
+    Gotty,
+}
+
+/// A message sent from the terminal to the server.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClientMessage {
+    /// Input typed into (or pasted into) the terminal.
+    Input(Str),
+    /// The terminal was resized.
+    Resize {
+        /// The new number of columns.
+        cols: u16,
+        /// The new number of rows.
+        rows: u16,
+    },
+    /// Asks the server to stop sending output (ttyd only).
+    Pause,
+    /// Asks the server to resume sending output (ttyd only).
+    Resume,
+    /// A keep-alive (gotty only).
+    Ping,
+}
+
+/// A message sent from the server to the terminal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServerMessage {
+    /// Output from the PTY.
+    Output(Vec<u8>),
+    /// The server wants to change the window title.
+    SetTitle(Str),
+    /// Client preferences, as a JSON object.
+    SetPreferences(Str),
+    /// Reply to a [`ClientMessage::Ping`] (gotty only).
+    Pong,
+    /// The server asks clients to reconnect after this many seconds when
+    /// the connection drops (gotty only).
+    SetReconnect(u32),
+}
+
+/// Error produced when a frame can't be decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub enum PtyProtocolError {
+    /// The frame had no command byte.
+    Empty,
+    /// The command byte isn't one the protocol defines.
+    UnknownCommand(u8),
+    /// A payload that should have been UTF-8 wasn't.
+    InvalidUtf8,
+    /// A payload that should have been base64 wasn't.
+    InvalidBase64,
+    /// A payload didn't have the shape the command calls for.
+    MalformedPayload,
+}
+
+impl Display for PtyProtocolError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(fmt, "empty frame"),
+            Self::UnknownCommand(c) => {
+                write!(fmt, "unknown command byte `{c:#04x}`")
+            }
+            Self::InvalidUtf8 => write!(fmt, "payload is not valid UTF-8"),
+            Self::InvalidBase64 => write!(fmt, "payload is not valid base64"),
+            Self::MalformedPayload => write!(fmt, "payload is malformed"),
+        }
+    }
+}
+
+impl Error for PtyProtocolError {}
+
+impl PtyProtocol {
+    /// The `WebSocket` subprotocol the server expects.
+    #[must_use]
+    pub fn subprotocol(self) -> &'static str {
+        match self {
+            Self::Ttyd => "tty",
+            Self::Gotty => "webtty",
+        }
+    }
+
+    /// Whether frames are sent as text (rather than binary) frames.
+    #[must_use]
+    pub fn uses_text_frames(self) -> bool {
+        matches!(self, Self::Gotty)
+    }
+
+    /// The first message a client sends once the socket opens.
+    ///
+    /// ttyd expects the initial size here; gotty expects the URL arguments
+    /// and gets the size in a separate [`ClientMessage::Resize`].
+    #[must_use]
+    pub fn handshake(
+        self,
+        auth_token: Option<&str>,
+        arguments: Option<&str>,
+        cols: u16,
+        rows: u16,
+    ) -> Vec<u8> {
+        let token = json_string(auth_token.unwrap_or(""));
+
+        match self {
+            Self::Ttyd => format!(
+                r#"{{"AuthToken":{token},"columns":{cols},"rows":{rows}}}"#,
+            ),
+            Self::Gotty => format!(
+                r#"{{"Arguments":{},"AuthToken":{}}}"#,
+                json_string(arguments.unwrap_or("")),
+                token
+            ),
+        }
+        .into_bytes()
+    }
+
+    /// Encodes a client message.
+    ///
+    /// Returns `None` if the protocol has no way to express the message.
+    #[must_use]
+    pub fn encode_client(self, msg: &ClientMessage) -> Option<Vec<u8>> {
+        use ClientMessage::{Input, Pause, Ping, Resize, Resume};
+
+        let (cmd, payload) = match (self, msg) {
+            (Self::Ttyd, Input(data)) => (b'0', data.clone()),
+            (Self::Ttyd, Resize { cols, rows }) => {
+                (b'1', resize_json(*cols, *rows))
+            }
+            (Self::Ttyd, Pause) | (Self::Gotty, Ping) => (b'2', Str::new()),
+            (Self::Ttyd, Resume) => (b'3', Str::new()),
+            (Self::Gotty, Input(data)) => (b'1', data.clone()),
+            (Self::Gotty, Resize { cols, rows }) => {
+                (b'3', resize_json(*cols, *rows))
+            }
+            (Self::Ttyd, Ping) | (Self::Gotty, Pause | Resume) => return None,
+        };
+
+        Some(frame(cmd, payload.as_bytes()))
+    }
+
+    /// Decodes a client message.
+    ///
+    /// Clients don't need this; it's here for stand-in servers.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PtyProtocolError`] if the frame is malformed.
+    pub fn decode_client(
+        self,
+        frame: &[u8],
+    ) -> Result<ClientMessage, PtyProtocolError> {
+        let (&cmd, payload) =
+            frame.split_first().ok_or(PtyProtocolError::Empty)?;
+        let text = || {
+            core::str::from_utf8(payload)
+                .map_err(|_| PtyProtocolError::InvalidUtf8)
+        };
+        let resize = || -> Result<ClientMessage, PtyProtocolError> {
+            let json = text()?;
+            Ok(ClientMessage::Resize {
+                cols: json_u16_field(json, "columns")?,
+                rows: json_u16_field(json, "rows")?,
+            })
+        };
+
+        match (self, cmd) {
+            (Self::Ttyd, b'0') | (Self::Gotty, b'1') => {
+                Ok(ClientMessage::Input(text()?.into()))
+            }
+            (Self::Ttyd, b'1') | (Self::Gotty, b'3') => resize(),
+            (Self::Ttyd, b'2') => Ok(ClientMessage::Pause),
+            (Self::Ttyd, b'3') => Ok(ClientMessage::Resume),
+            (Self::Gotty, b'2') => Ok(ClientMessage::Ping),
+            (_, c) => Err(PtyProtocolError::UnknownCommand(c)),
+        }
+    }
+
+    /// Encodes a server message.
+    ///
+    /// Clients don't need this; it's here for stand-in servers.
+    ///
+    /// Returns `None` if the protocol has no way to express the message.
+    #[must_use]
+    pub fn encode_server(self, msg: &ServerMessage) -> Option<Vec<u8>> {
+        use ServerMessage::{
+            Output, Pong, SetPreferences, SetReconnect, SetTitle,
+        };
+
+        Some(match (self, msg) {
+            (Self::Ttyd, Output(data)) => frame(b'0', data),
+            (Self::Ttyd, SetTitle(t)) => frame(b'1', t.as_bytes()),
+            (Self::Ttyd, SetPreferences(p)) => frame(b'2', p.as_bytes()),
+            (Self::Gotty, Output(data)) => {
+                frame(b'1', base64_encode(data).as_bytes())
+            }
+            (Self::Gotty, Pong) => frame(b'2', &[]),
+            (Self::Gotty, SetTitle(t)) => frame(b'3', t.as_bytes()),
+            (Self::Gotty, SetPreferences(p)) => frame(b'4', p.as_bytes()),
+            (Self::Gotty, SetReconnect(s)) => {
+                frame(b'5', s.to_string().as_bytes())
+            }
+            (Self::Ttyd, Pong | SetReconnect(_)) => return None,
+        })
+    }
+
+    /// Decodes a server message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PtyProtocolError`] if the frame is malformed.
+    pub fn decode_server(
+        self,
+        frame: &[u8],
+    ) -> Result<ServerMessage, PtyProtocolError> {
+        let (&cmd, payload) =
+            frame.split_first().ok_or(PtyProtocolError::Empty)?;
+        let text = || {
+            Str::from_utf8(payload.to_vec())
+                .map_err(|_| PtyProtocolError::InvalidUtf8)
+        };
+
+        match (self, cmd) {
+            (Self::Ttyd, b'0') => Ok(ServerMessage::Output(payload.to_vec())),
+            (Self::Ttyd, b'1') | (Self::Gotty, b'3') => {
+                Ok(ServerMessage::SetTitle(text()?))
+            }
+            (Self::Ttyd, b'2') | (Self::Gotty, b'4') => {
+                Ok(ServerMessage::SetPreferences(text()?))
+            }
+            (Self::Gotty, b'1') => base64_decode(payload)
+                .map(ServerMessage::Output)
+                .ok_or(PtyProtocolError::InvalidBase64),
+            (Self::Gotty, b'2') => Ok(ServerMessage::Pong),
+            (Self::Gotty, b'5') => text()?
+                .trim()
+                .parse()
+                .map(ServerMessage::SetReconnect)
+                .map_err(|_| PtyProtocolError::MalformedPayload),
+            (_, c) => Err(PtyProtocolError::UnknownCommand(c)),
+        }
+    }
+}
+
+/// Prepends the command byte to a payload.
+fn frame(cmd: u8, payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 1);
+    out.push(cmd);
+    out.extend_from_slice(payload);
+    out
+}
+
+/// The JSON payload both protocols use for resizes.
+fn resize_json(cols: u16, rows: u16) -> Str {
+    format!(r#"{{"columns":{cols},"rows":{rows}}}"#)
+}
+
+/// Quotes and escapes a string as a JSON string literal.
+fn json_string(s: &str) -> Str {
+    let mut out = Str::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Pulls a `u16` field out of a JSON object.
+///
+/// The field has to be a whole number in range; anything else (including JSON
+/// that doesn't parse) is a [`PtyProtocolError::MalformedPayload`].
+fn json_u16_field(
+    json: &str,
+    field: &'static str,
+) -> Result<u16, PtyProtocolError> {
+    let object =
+        JSON::parse(json).map_err(|_| PtyProtocolError::MalformedPayload)?;
+    if !object.is_object() {
+        return Err(PtyProtocolError::MalformedPayload);
+    }
+
+    let value = Reflect::get(&object, &field.into())
+        .ok()
+        .and_then(|v| v.as_f64())
+        .ok_or(PtyProtocolError::MalformedPayload)?;
+
+    if value.fract() == 0.0 && (0.0..=f64::from(u16::MAX)).contains(&value) {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(value as u16)
+    } else {
+        Err(PtyProtocolError::MalformedPayload)
+    }
+}
+
+/// The standard base64 alphabet.
+const BASE64: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard (padded) base64 encoding.
+fn base64_encode(data: &[u8]) -> Str {
+    let mut out = Str::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = u32::from(b[0]) << 16 | u32::from(b[1]) << 8 | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+/// Standard base64 decoding; padding is optional and whitespace is skipped.
+fn base64_decode(data: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len() / 4 * 3);
+    let mut acc = 0u32;
+    let mut bits = 0;
+
+    for &c in data {
+        let v = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            c if c.is_ascii_whitespace() => continue,
+            _ => return None,
+        };
+
+        acc = acc << 6 | u32::from(v);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            #[allow(clippy::cast_possible_truncation)]
+            out.push((acc >> bits) as u8);
+        }
+    }
+
+    Some(out)
+}
+
+/// How (and whether) to reconnect when the connection drops.
+///
+/// Delays grow exponentially: the `n`th attempt waits
+/// `initial_delay_ms * multiplier^(n - 1)` milliseconds, capped at
+/// `max_delay_ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReconnectPolicy {
+    /// The delay before the first reconnect attempt.
+    pub initial_delay_ms: u32,
+    /// The longest we'll ever wait between attempts.
+    pub max_delay_ms: u32,
+    /// What the delay is multiplied by after each failed attempt.
+    pub multiplier: u32,
+    /// How many consecutive attempts to make before giving up; `None` for no
+    /// limit.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: 500,
+            max_delay_ms: 30_000,
+            multiplier: 2,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// A policy that never reconnects.
+    #[must_use]
+    pub const fn never() -> Self {
+        Self {
+            initial_delay_ms: 0,
+            max_delay_ms: 0,
+            multiplier: 1,
+            max_attempts: Some(0),
+        }
+    }
+
+    /// The delay before reconnect attempt number `attempt` (starting at 1).
+    ///
+    /// Returns `None` if we've run out of attempts.
+    #[must_use]
+    pub fn delay_for(&self, attempt: u32) -> Option<u32> {
+        if attempt == 0 || self.max_attempts.is_some_and(|m| attempt > m) {
+            return None;
+        }
+
+        let delay = self
+            .initial_delay_ms
+            .saturating_mul(self.multiplier.saturating_pow(attempt - 1));
+
+        Some(delay.min(self.max_delay_ms))
+    }
+}
+
+/// Everything needed to open a [`PtyConnection`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct PtyConfig {
+    /// The `ws://` or `wss://` URL of the server's `WebSocket` endpoint.
+    pub url: Str,
+    /// The protocol the server speaks.
+    pub protocol: PtyProtocol,
+    /// Sent to the server in the handshake, if set.
+    pub auth_token: Option<Str>,
+    /// Arguments for the command the server runs (gotty only).
+    pub arguments: Option<Str>,
+    /// What to do when the connection drops.
+    pub reconnect: ReconnectPolicy,
+}
+
+impl PtyConfig {
+    /// Constructor; uses the default [`ReconnectPolicy`].
+    pub fn new<S: Into<Str>>(url: S, protocol: PtyProtocol) -> Self {
+        Self {
+            url: url.into(),
+            protocol,
+            auth_token: None,
+            arguments: None,
+            reconnect: ReconnectPolicy::default(),
+        }
+    }
+
+    /// Set the auth token and return self for chaining.
+    #[must_use]
+    pub fn with_auth_token<S: Into<Str>>(mut self, token: S) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+
+    /// Set the command arguments and return self for chaining.
+    #[must_use]
+    pub fn with_arguments<S: Into<Str>>(mut self, arguments: S) -> Self {
+        self.arguments = Some(arguments.into());
+        self
+    }
+
+    /// Set the reconnect policy and return self for chaining.
+    #[must_use]
+    pub fn with_reconnect(mut self, reconnect: ReconnectPolicy) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+}
+
+/// The state of a [`PtyConnection`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// The socket is being opened; `attempt` is 0 for the initial connection.
+    Connecting {
+        /// Which reconnect attempt this is.
+        attempt: u32,
+    },
+    /// The socket is open and the handshake has been sent.
+    Open,
+    /// The socket dropped and we'll try again after `delay_ms`.
+    Reconnecting {
+        /// Which reconnect attempt is coming up.
+        attempt: u32,
+        /// How long until we try.
+        delay_ms: u32,
+    },
+    /// The socket is closed and we won't be reconnecting.
+    Closed {
+        /// The close code the socket reported.
+        code: u16,
+        /// The close reason the socket reported.
+        reason: Str,
+    },
+}
+
+/// An open [`WebSocket`] along with the handlers attached to it.
+///
+/// [`WebSocket`]: web_sys::WebSocket
+struct Socket {
+    /// The socket.
+    ws: WebSocket,
+    /// `onopen` handler.
+    _on_open: Closure<dyn FnMut()>,
+    /// `onmessage` handler.
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    /// `onclose` handler.
+    _on_close: Closure<dyn FnMut(CloseEvent)>,
+    /// `onerror` handler.
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        // Detach first so that JS doesn't call into closures that are about to
+        // be freed.
+        self.ws.set_onopen(None);
+        self.ws.set_onmessage(None);
+        self.ws.set_onclose(None);
+        self.ws.set_onerror(None);
+        let _ = self.ws.close();
+    }
+}
+
+/// Listener for state changes.
+type StateListener = Box<dyn FnMut(&ConnectionState)>;
+/// Listener for title changes.
+type TitleListener = Box<dyn FnMut(&str)>;
+
+/// Shared state behind a [`PtyConnection`].
+///
+/// The closures handed to JS only hold [`Weak`] references to this so that
+/// dropping the [`PtyConnection`] tears everything down.
+struct Inner {
+    /// The terminal we're driving.
+    terminal: Terminal,
+    /// What we're connecting to.
+    config: PtyConfig,
+    /// The current socket, if any.
+    socket: RefCell<Option<Socket>>,
+    /// The current state.
+    state: RefCell<ConnectionState>,
+    /// Consecutive reconnect attempts since the last successful open.
+    attempt: Cell<u32>,
+    /// Reconnect delay requested by the server (gotty), in milliseconds.
+    server_delay_ms: Cell<Option<u32>>,
+    /// Set once [`PtyConnection::close`] is called.
+    closed_by_user: Cell<bool>,
+    /// Handle of the pending reconnect timer, if any.
+    timer: Cell<Option<i32>>,
+    /// Called by the reconnect timer.
+    reconnect: RefCell<Option<Closure<dyn FnMut()>>>,
+    /// State change listeners.
+    state_listeners: RefCell<Vec<StateListener>>,
+    /// Title change listeners.
+    title_listeners: RefCell<Vec<TitleListener>>,
+    /// Our `on_data` and `on_resize` listeners on the terminal.
+    terminal_listeners: RefCell<Vec<DisposableWrapper<Disposable>>>,
+}
+
+impl Inner {
+    /// Opens a new socket, replacing the current one.
+    fn open(self: &Rc<Self>) -> Result<(), JsValue> {
+        self.timer.set(None);
+        self.set_state(ConnectionState::Connecting {
+            attempt: self.attempt.get(),
+        });
+
+        let ws = WebSocket::new_with_str(
+            &self.config.url,
+            self.config.protocol.subprotocol(),
+        )?;
+        ws.set_binary_type(BinaryType::Arraybuffer);
+
+        let weak = Rc::downgrade(self);
+        let on_open: Box<dyn FnMut()> = Box::new(move || {
+            if let Some(inner) = weak.upgrade() {
+                inner.on_open();
+            }
+        });
+        let on_open = Closure::wrap(on_open);
+
+        let weak = Rc::downgrade(self);
+        let on_message: Box<dyn FnMut(MessageEvent)> = Box::new(move |ev| {
+            if let Some(inner) = weak.upgrade() {
+                inner.on_message(&ev.data());
+            }
+        });
+        let on_message = Closure::wrap(on_message);
+
+        let weak = Rc::downgrade(self);
+        let on_close: Box<dyn FnMut(CloseEvent)> = Box::new(move |ev| {
+            if let Some(inner) = weak.upgrade() {
+                inner.on_close(ev.code(), ev.reason());
+            }
+        });
+        let on_close = Closure::wrap(on_close);
+
+        // Errors are always followed by a `close` event; that's where we deal
+        // with them.
+        let on_error: Box<dyn FnMut(Event)> = Box::new(|_| {});
+        let on_error = Closure::wrap(on_error);
+
+        ws.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        ws.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        ws.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        ws.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+
+        *self.socket.borrow_mut() = Some(Socket {
+            ws,
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_close: on_close,
+            _on_error: on_error,
+        });
+
+        Ok(())
+    }
+
+    /// Sends the handshake (and, for gotty, the initial size).
+    fn on_open(&self) {
+        let protocol = self.config.protocol;
+        let (cols, rows) = (self.terminal.cols(), self.terminal.rows());
+
+        self.attempt.set(0);
+        self.send_raw(&protocol.handshake(
+            self.config.auth_token.as_deref(),
+            self.config.arguments.as_deref(),
+            cols,
+            rows,
+        ));
+        if protocol == PtyProtocol::Gotty {
+            self.send(&ClientMessage::Resize { cols, rows });
+        }
+
+        self.set_state(ConnectionState::Open);
+    }
+
+    /// Dispatches an incoming frame.
+    fn on_message(&self, data: &JsValue) {
+        let frame = if let Some(s) = data.as_string() {
+            s.into_bytes()
+        } else if let Some(buf) = data.dyn_ref::<ArrayBuffer>() {
+            Uint8Array::new(buf).to_vec()
+        } else {
+            return;
+        };
+
+        match self.config.protocol.decode_server(&frame) {
+            Ok(ServerMessage::Output(bytes)) => {
+                self.terminal.write_bytes(&bytes);
+            }
+            Ok(ServerMessage::SetTitle(title)) => {
+                let mut listeners = self.title_listeners.take();
+                for listener in &mut listeners {
+                    listener(&title);
+                }
+                let mut current = self.title_listeners.borrow_mut();
+                listeners.append(&mut current);
+                *current = listeners;
+            }
+            Ok(ServerMessage::SetReconnect(secs)) => {
+                self.server_delay_ms.set(Some(secs.saturating_mul(1000)));
+            }
+            // Preferences are for ttyd/gotty's own frontends; pongs and
+            // malformed frames carry nothing we can act on.
+            Ok(ServerMessage::SetPreferences(_) | ServerMessage::Pong)
+            | Err(_) => {}
+        }
+    }
+
+    /// Either schedules a reconnect or gives up.
+    fn on_close(self: &Rc<Self>, code: u16, reason: Str) {
+        if self.closed_by_user.get() {
+            self.set_state(ConnectionState::Closed { code, reason });
+            return;
+        }
+
+        let attempt = self.attempt.get() + 1;
+        let delay = self
+            .config
+            .reconnect
+            .delay_for(attempt)
+            .map(|d| self.server_delay_ms.get().map_or(d, |s| d.max(s)));
+
+        let scheduled = delay.and_then(|delay_ms| {
+            let window = web_sys::window()?;
+            let reconnect = self.reconnect.borrow();
+            let handle = window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    reconnect.as_ref()?.as_ref().unchecked_ref(),
+                    i32::try_from(delay_ms).unwrap_or(i32::MAX),
+                )
+                .ok()?;
+
+            Some((handle, delay_ms))
+        });
+
+        if let Some((handle, delay_ms)) = scheduled {
+            self.attempt.set(attempt);
+            self.timer.set(Some(handle));
+            self.set_state(ConnectionState::Reconnecting { attempt, delay_ms });
+        } else {
+            self.set_state(ConnectionState::Closed { code, reason });
+        }
+    }
+
+    /// Encodes and sends a message; messages the protocol can't express and
+    /// messages sent while the socket isn't open are dropped.
+    fn send(&self, msg: &ClientMessage) {
+        if let Some(frame) = self.config.protocol.encode_client(msg) {
+            self.send_raw(&frame);
+        }
+    }
+
+    /// Sends an already encoded frame.
+    fn send_raw(&self, frame: &[u8]) {
+        if let Some(Socket { ws, .. }) = &*self.socket.borrow() {
+            if ws.ready_state() != WebSocket::OPEN {
+                return;
+            }
+
+            let _ = if self.config.protocol.uses_text_frames() {
+                ws.send_with_str(&String::from_utf8_lossy(frame))
+            } else {
+                ws.send_with_u8_array(frame)
+            };
+        }
+    }
+
+    /// Updates the state and notifies listeners.
+    fn set_state(&self, state: ConnectionState) {
+        let _ = self.state.replace(state);
+        let state = self.state.borrow().clone();
+
+        // Listeners are allowed to register more listeners so we can't hold
+        // the borrow while calling them.
+        let mut listeners = self.state_listeners.take();
+        for listener in &mut listeners {
+            listener(&state);
+        }
+        let mut current = self.state_listeners.borrow_mut();
+        listeners.append(&mut current);
+        *current = listeners;
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        if let (Some(handle), Some(window)) =
+            (self.timer.get(), web_sys::window())
+        {
+            window.clear_timeout_with_handle(handle);
+        }
+    }
+}
+
+/// A [`Terminal`] connected to a remote PTY over a [`WebSocket`].
+///
+/// Input from the terminal ([`Terminal::on_data`]) and size changes
+/// ([`Terminal::on_resize`]) are forwarded to the server and output from the
+/// server is written to the terminal. When the socket drops the connection is
+/// retried according to the config's [`ReconnectPolicy`].
+///
+/// Dropping this closes the socket and detaches from the terminal.
+///
+/// [`WebSocket`]: web_sys::WebSocket
+#[allow(clippy::module_name_repetitions)]
+pub struct PtyConnection {
+    /// Shared state.
+    inner: Rc<Inner>,
+}
+
+impl fmt::Debug for PtyConnection {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("PtyConnection")
+            .field("config", &self.inner.config)
+            .field("state", &self.inner.state.borrow())
+            .finish()
+    }
+}
+
+impl PtyConnection {
+    /// Connects `terminal` to the server described by `config`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the [`WebSocket`] can't be constructed (i.e. the URL is
+    /// malformed).
+    ///
+    /// [`WebSocket`]: web_sys::WebSocket
+    pub fn connect(
+        terminal: &Terminal,
+        config: PtyConfig,
+    ) -> Result<Self, JsValue> {
+        let inner = Rc::new(Inner {
+            terminal: terminal.clone(),
+            config,
+            socket: RefCell::new(None),
+            state: RefCell::new(ConnectionState::Connecting { attempt: 0 }),
+            attempt: Cell::new(0),
+            server_delay_ms: Cell::new(None),
+            closed_by_user: Cell::new(false),
+            timer: Cell::new(None),
+            reconnect: RefCell::new(None),
+            state_listeners: RefCell::new(Vec::new()),
+            title_listeners: RefCell::new(Vec::new()),
+            terminal_listeners: RefCell::new(Vec::new()),
+        });
+
+        let weak: Weak<Inner> = Rc::downgrade(&inner);
+        let reconnect: Box<dyn FnMut()> = Box::new(move || {
+            if let Some(inner) = weak.upgrade() {
+                if inner.open().is_err() {
+                    inner.on_close(0, "failed to reopen the socket".into());
+                }
+            }
+        });
+        *inner.reconnect.borrow_mut() = Some(Closure::wrap(reconnect));
+
+        let weak = Rc::downgrade(&inner);
+        let on_data = terminal.attach_data_event_listener(move |data: Str| {
+            if let Some(inner) = weak.upgrade() {
+                inner.send(&ClientMessage::Input(data));
+            }
+        });
+
+        let weak = Rc::downgrade(&inner);
+        let on_resize = terminal.attach_resize_event_listener(
+            move |ev: ResizeEventData| {
+                if let Some(inner) = weak.upgrade() {
+                    inner.send(&ClientMessage::Resize {
+                        cols: ev.cols(),
+                        rows: ev.rows(),
+                    });
+                }
+            },
+        );
+
+        inner
+            .terminal_listeners
+            .borrow_mut()
+            .extend(vec![on_data, on_resize]);
+
+        inner.open()?;
+
+        Ok(Self { inner })
+    }
+
+    /// The current state of the connection.
+    #[must_use]
+    pub fn state(&self) -> ConnectionState {
+        self.inner.state.borrow().clone()
+    }
+
+    /// Registers a listener that's called whenever the state changes.
+    pub fn on_state_change<F>(&self, listener: F)
+    where
+        F: FnMut(&ConnectionState) + 'static,
+    {
+        self.inner
+            .state_listeners
+            .borrow_mut()
+            .push(Box::new(listener));
+    }
+
+    /// Registers a listener that's called when the server sets the title.
+    pub fn on_title_change<F>(&self, listener: F)
+    where
+        F: FnMut(&str) + 'static,
+    {
+        self.inner
+            .title_listeners
+            .borrow_mut()
+            .push(Box::new(listener));
+    }
+
+    /// Sends a message to the server.
+    ///
+    /// Messages the protocol doesn't support (i.e. [`ClientMessage::Pause`]
+    /// for gotty) and messages sent while the socket isn't open are dropped.
+    pub fn send(&self, msg: &ClientMessage) {
+        self.inner.send(msg);
+    }
+
+    /// Closes the connection without reconnecting.
+    pub fn close(&self) {
+        self.inner.closed_by_user.set(true);
+
+        if let (Some(handle), Some(window)) =
+            (self.inner.timer.take(), web_sys::window())
+        {
+            window.clear_timeout_with_handle(handle);
+            self.inner.set_state(ConnectionState::Closed {
+                code: 1000,
+                reason: Str::new(),
+            });
+        }
+
+        if let Some(Socket { ws, .. }) = &*self.inner.socket.borrow() {
+            let _ = ws.close();
+        }
+    }
+}
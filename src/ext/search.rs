@@ -0,0 +1,18 @@
+//! Extra functions for the [search addon].
+//!
+//! [search addon]: crate::addons::search
+
+use crate::addons::search::SearchResults;
+use crate::idx_to_opt;
+
+impl SearchResults {
+    /// The index of the active match.
+    ///
+    /// Like [`result_index`], but returns an `Option` instead of `-1`.
+    ///
+    /// [`result_index`]: SearchResults::result_index
+    #[must_use]
+    pub fn get_result_index(&self) -> Option<u32> {
+        idx_to_opt(self.result_index())
+    }
+}
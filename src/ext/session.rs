@@ -0,0 +1,178 @@
+//! Saving and restoring terminal sessions with the [serialize addon].
+//!
+//! [serialize addon]: crate::addons::serialize
+
+use super::capabilities::{CapabilityError, Kind};
+use crate::addons::serialize::{SerializeAddon, SerializeOptions};
+use crate::xterm::{Modes, MouseTrackingMode, Str, Terminal, TerminalOptions};
+
+use js_sys::Reflect;
+use wasm_bindgen::JsValue;
+
+/// Everything needed to put a terminal back the way it was: its contents (as
+/// produced by [`SerializeAddon::serialize`]), options, size and modes.
+///
+/// This is useful for keeping a terminal's contents around across page
+/// reloads; store the fields somewhere (or, with the `serde` feature, the
+/// whole snapshot) and [`restore`] the snapshot later.
+///
+/// [`restore`]: SessionSnapshot::restore
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SessionSnapshot {
+    /// The terminal's options.
+    pub options: TerminalOptions,
+    /// The number of columns the terminal had.
+    pub cols: u16,
+    /// The number of rows the terminal had.
+    pub rows: u16,
+    /// The terminal's modes.
+    pub modes: Modes,
+    /// The serialized buffer, without any mode sequences.
+    pub data: Str,
+}
+
+impl SessionSnapshot {
+    /// Takes a snapshot of `terminal`, which must have `addon` loaded.
+    ///
+    /// `scrollback` is the number of scrollback rows to save, starting from
+    /// the bottom of the scrollback; when `None`, all of it is saved.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the loaded xterm.js doesn't have the `options` or `modes`
+    /// properties; see [`Terminal::capabilities`].
+    pub fn capture(
+        terminal: &Terminal,
+        addon: &SerializeAddon,
+        scrollback: Option<u32>,
+    ) -> Result<Self, CapabilityError> {
+        let capabilities = terminal.capabilities();
+        capabilities.require(Kind::Method, "options")?;
+        capabilities.require(Kind::Method, "modes")?;
+
+        // The modes are saved separately (and written back by `restore_data`)
+        // so we don't have the addon serialize them too.
+        let data = addon.serialize(Some(SerializeOptions {
+            scrollback,
+            exclude_modes: Some(true),
+            ..SerializeOptions::default()
+        }));
+
+        // xterm.js hands back plain objects, which `Terminal::options` and
+        // `Terminal::modes` can't convert.
+        let get = |name: &str| {
+            Reflect::get(terminal, &name.into()).unwrap_or(JsValue::UNDEFINED)
+        };
+
+        Ok(Self {
+            options: TerminalOptions::from_object(&get("options"))
+                .unwrap_or_default(),
+            cols: terminal.cols(),
+            rows: terminal.rows(),
+            modes: Modes::from_object(&get("modes")).unwrap_or_default(),
+            data,
+        })
+    }
+
+    /// The string to write to a terminal to restore its contents and modes.
+    #[must_use]
+    pub fn restore_data(&self) -> Str {
+        let mut data = self.data.clone();
+        data.push_str(&self.modes.to_escape_sequences());
+
+        data
+    }
+
+    /// Resizes `terminal` to the snapshot's size and writes the snapshot's
+    /// contents and modes to it.
+    ///
+    /// This does not change the terminal's options; the terminal should
+    /// usually be fresh (or [`reset`]) for the result to match the snapshot.
+    ///
+    /// [`reset`]: Terminal::reset
+    pub fn restore_into(&self, terminal: &Terminal) {
+        terminal.resize(self.cols, self.rows);
+        terminal.write(self.restore_data());
+    }
+
+    /// Creates a new [`Terminal`] with the snapshot's options and size and
+    /// restores the snapshot into it.
+    ///
+    /// The terminal still needs to be [opened] (and have any addons loaded).
+    ///
+    /// [opened]: Terminal::open
+    #[must_use]
+    pub fn restore(&self) -> Terminal {
+        let mut options = self.options.clone();
+        options.cols = Some(self.cols);
+        options.rows = Some(self.rows);
+
+        let terminal = Terminal::new_with_options_object(&options.to_object());
+        terminal.write(self.restore_data());
+
+        terminal
+    }
+}
+
+impl Modes {
+    /// Produces the SM/DECSET (and RM/DECRST) sequences that put a terminal
+    /// into these modes.
+    ///
+    /// Modes that are `None` are left alone.
+    #[must_use]
+    pub fn to_escape_sequences(&self) -> Str {
+        /// `(mode, is this a DEC private mode?, state)`
+        type Mode = (u16, bool, Option<bool>);
+
+        let modes: [Mode; 8] = [
+            (1, true, self.application_cursor_keys_mode),
+            (66, true, self.application_keypad_mode),
+            (2004, true, self.bracketed_paste_mode),
+            (4, false, self.insert_mode),
+            (6, true, self.origin_mode),
+            (45, true, self.reverse_wraparound_mode),
+            (1004, true, self.send_focus_mode),
+            (7, true, self.wraparound_mode),
+        ];
+
+        let mut out = Str::new();
+        let mut push = |mode: u16, private: bool, set: bool| {
+            out.push_str("\x1b[");
+            if private {
+                out.push('?');
+            }
+            out.push_str(&mode.to_string());
+            out.push(if set { 'h' } else { 'l' });
+        };
+
+        for (mode, private, state) in modes {
+            if let Some(set) = state {
+                push(mode, private, set);
+            }
+        }
+
+        if let Some(tracking) = self.mouse_tracking_mode {
+            // Only one tracking mode can be active; turn the rest off first.
+            let active = match tracking {
+                MouseTrackingMode::X10 => Some(9),
+                MouseTrackingMode::Vt200 => Some(1000),
+                MouseTrackingMode::Drag => Some(1002),
+                MouseTrackingMode::Any => Some(1003),
+                _ => None,
+            };
+
+            for mode in [9, 1000, 1002, 1003] {
+                if Some(mode) != active {
+                    push(mode, true, false);
+                }
+            }
+            if let Some(mode) = active {
+                push(mode, true, true);
+            }
+        }
+
+        out
+    }
+}
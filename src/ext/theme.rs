@@ -1,8 +1,40 @@
-//! Some [`Theme`]s.
+//! Some [`Theme`]s, and (with the `serde` feature) importers and exporters
+//! for other terminals' color schemes:
+//!   - [iTerm2](iterm) (`.itermcolors`)
+//!   - [Alacritty](alacritty) (the `colors` section of its config)
+//!   - [Windows Terminal](windows_terminal) (`schemes` entries)
+//!   - [base16](base16)
+//!
+//! There are also typed ([`Color`]) getters and setters for [`Theme`]'s
+//! colors, and [`Theme::validate`] for checking that a theme's colors are
+//! all well-formed. [`palette`] generates colors for a theme: 256-color
+//! palettes and light or dark variants.
 //!
 //! [`Theme`]: crate::xterm::Theme
 
-use crate::xterm::Theme;
+use super::color::{Color, ParseColorError};
+use crate::xterm::{Str, Theme};
+
+use core::fmt::{self, Display};
+use std::error::Error;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+pub mod alacritty;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+pub mod base16;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+pub mod iterm;
+
+pub mod palette;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+pub mod windows_terminal;
 
 macro_rules! c {
     ($hex:literal) => {{
@@ -51,3 +83,389 @@ impl Theme {
         theme
     }
 }
+
+impl Theme {
+    /// Gets one of the 256 ANSI colors (0-15 are the named colors, 16-255
+    /// come from [`extended_ansi`](Theme::extended_ansi)).
+    #[must_use]
+    pub fn ansi_color(&self, index: u8) -> Option<Str> {
+        match index {
+            0 => self.black(),
+            1 => self.red(),
+            2 => self.green(),
+            3 => self.yellow(),
+            4 => self.blue(),
+            5 => self.magenta(),
+            6 => self.cyan(),
+            7 => self.white(),
+            8 => self.bright_black(),
+            9 => self.bright_red(),
+            10 => self.bright_green(),
+            11 => self.bright_yellow(),
+            12 => self.bright_blue(),
+            13 => self.bright_magenta(),
+            14 => self.bright_cyan(),
+            15 => self.bright_white(),
+            _ => self
+                .extended_ansi()
+                .and_then(|ext| ext.get(usize::from(index - 16)).cloned()),
+        }
+    }
+
+    /// Sets one of the 256 ANSI colors (see [`Theme::ansi_color`]).
+    ///
+    /// [`extended_ansi`](Theme::extended_ansi) has to be contiguous so
+    /// setting a color past its end fills the gap with xterm's default
+    /// colors; unsetting an extended color resets it to xterm's default.
+    pub fn set_ansi_color(&mut self, index: u8, color: Option<Str>) {
+        match index {
+            0 => self.set_black(color),
+            1 => self.set_red(color),
+            2 => self.set_green(color),
+            3 => self.set_yellow(color),
+            4 => self.set_blue(color),
+            5 => self.set_magenta(color),
+            6 => self.set_cyan(color),
+            7 => self.set_white(color),
+            8 => self.set_bright_black(color),
+            9 => self.set_bright_red(color),
+            10 => self.set_bright_green(color),
+            11 => self.set_bright_yellow(color),
+            12 => self.set_bright_blue(color),
+            13 => self.set_bright_magenta(color),
+            14 => self.set_bright_cyan(color),
+            15 => self.set_bright_white(color),
+            _ => {
+                let pos = usize::from(index - 16);
+                let mut ext = self.extended_ansi().unwrap_or_default();
+                if color.is_some() || pos < ext.len() {
+                    while ext.len() <= pos {
+                        #[allow(clippy::cast_possible_truncation)]
+                        ext.push(xterm_color(16 + ext.len() as u8).to_hex());
+                    }
+                    ext[pos] =
+                        color.unwrap_or_else(|| xterm_color(index).to_hex());
+                }
+
+                self.set_extended_ansi((!ext.is_empty()).then_some(ext));
+            }
+        }
+    }
+}
+
+/// Generates typed ([`Color`]) getters and setters for [`Theme`]'s colors,
+/// along with a list of the colors (for [`Theme::validate`]).
+macro_rules! typed_colors {
+    ($(
+        $field:ident / $set_field:ident => $get:ident / $set:ident, $js:literal;
+    )*) => {
+        impl Theme {
+            $(
+                crate::calculated_doc! {
+                    #[doc = concat!(
+                        " Parses [`", stringify!($field), "`](Theme::",
+                        stringify!($field), ") as a [`Color`].",
+                    )]
+                    #[doc = ""]
+                    #[doc = " # Errors"]
+                    #[doc = ""]
+                    #[doc = " Fails if the color is malformed."]
+                    >>>
+                    pub fn $get(&self) -> Result<Option<Color>, ParseColorError> {
+                        self.$field().map(|c| c.parse()).transpose()
+                    }
+                }
+
+                crate::calculated_doc! {
+                    #[doc = concat!(
+                        " Sets [`", stringify!($field), "`](Theme::",
+                        stringify!($field), ") to a [`Color`].",
+                    )]
+                    >>>
+                    pub fn $set(&mut self, color: Option<Color>) {
+                        self.$set_field(color.map(Color::to_hex));
+                    }
+                }
+            )*
+        }
+
+        /// [`Theme`]'s colors (apart from `extendedAnsi`), by their xterm.js
+        /// names.
+        const COLORS: &[(&str, fn(&Theme) -> Option<Str>)] =
+            &[$(($js, Theme::$field)),*];
+    };
+}
+
+typed_colors! {
+    foreground / set_foreground
+        => foreground_color / set_foreground_color, "foreground";
+    background / set_background
+        => background_color / set_background_color, "background";
+    cursor / set_cursor => cursor_color / set_cursor_color, "cursor";
+    cursor_accent / set_cursor_accent
+        => cursor_accent_color / set_cursor_accent_color, "cursorAccent";
+    selection_background / set_selection_background
+        => selection_background_color / set_selection_background_color,
+        "selectionBackground";
+    selection_foreground / set_selection_foreground
+        => selection_foreground_color / set_selection_foreground_color,
+        "selectionForeground";
+    selection_inactive_background / set_selection_inactive_background
+        => selection_inactive_background_color
+        / set_selection_inactive_background_color,
+        "selectionInactiveBackground";
+    black / set_black => black_color / set_black_color, "black";
+    red / set_red => red_color / set_red_color, "red";
+    green / set_green => green_color / set_green_color, "green";
+    yellow / set_yellow => yellow_color / set_yellow_color, "yellow";
+    blue / set_blue => blue_color / set_blue_color, "blue";
+    magenta / set_magenta => magenta_color / set_magenta_color, "magenta";
+    cyan / set_cyan => cyan_color / set_cyan_color, "cyan";
+    white / set_white => white_color / set_white_color, "white";
+    bright_black / set_bright_black
+        => bright_black_color / set_bright_black_color, "brightBlack";
+    bright_red / set_bright_red
+        => bright_red_color / set_bright_red_color, "brightRed";
+    bright_green / set_bright_green
+        => bright_green_color / set_bright_green_color, "brightGreen";
+    bright_yellow / set_bright_yellow
+        => bright_yellow_color / set_bright_yellow_color, "brightYellow";
+    bright_blue / set_bright_blue
+        => bright_blue_color / set_bright_blue_color, "brightBlue";
+    bright_magenta / set_bright_magenta
+        => bright_magenta_color / set_bright_magenta_color, "brightMagenta";
+    bright_cyan / set_bright_cyan
+        => bright_cyan_color / set_bright_cyan_color, "brightCyan";
+    bright_white / set_bright_white
+        => bright_white_color / set_bright_white_color, "brightWhite";
+}
+
+impl Theme {
+    /// Parses [`extended_ansi`](Theme::extended_ansi) as [`Color`]s.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the colors are malformed.
+    pub fn extended_ansi_colors(
+        &self,
+    ) -> Result<Option<Vec<Color>>, ParseColorError> {
+        self.extended_ansi()
+            .map(|ext| ext.iter().map(|c| c.parse()).collect())
+            .transpose()
+    }
+
+    /// Sets [`extended_ansi`](Theme::extended_ansi) to [`Color`]s.
+    pub fn set_extended_ansi_colors(&mut self, colors: Option<Vec<Color>>) {
+        self.set_extended_ansi(
+            colors.map(|ext| ext.into_iter().map(Color::to_hex).collect()),
+        );
+    }
+
+    /// Checks that all of the theme's colors are well-formed; the error lists
+    /// each color that isn't (by its xterm.js name).
+    ///
+    /// xterm.js doesn't complain about malformed colors, it just renders
+    /// with its default colors instead.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::xterm::Theme;
+    /// let mut theme = Theme::nord();
+    /// assert_eq!(theme.validate(), Ok(()));
+    ///
+    /// theme.set_cursor(Some("#12345".into()));
+    /// theme.set_extended_ansi(Some(vec!["red".into(), "rebeccapurple ".into()]));
+    /// assert_eq!(
+    ///     theme.validate().unwrap_err().to_string(),
+    ///     "invalid color scheme: `cursor`: `#12345`: invalid hex color; \
+    ///      `extendedAnsi[1]`: `rebeccapurple `: not a CSS color",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the colors are malformed.
+    pub fn validate(&self) -> Result<(), ThemeError> {
+        let mut fields = Fields::default();
+        for (name, get) in COLORS {
+            let _ = fields.parse(name, get(self).as_deref());
+        }
+
+        for (idx, color) in
+            self.extended_ansi().unwrap_or_default().iter().enumerate()
+        {
+            let _ = fields.parse(&format!("extendedAnsi[{idx}]"), Some(color));
+        }
+
+        fields.finish(())
+    }
+}
+
+/// The xterm.js names of ANSI colors 0-15.
+pub(super) const ANSI_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "brightBlack",
+    "brightRed",
+    "brightGreen",
+    "brightYellow",
+    "brightBlue",
+    "brightMagenta",
+    "brightCyan",
+    "brightWhite",
+];
+
+/// xterm.js's default foreground color.
+pub(super) const DEFAULT_FOREGROUND: Color = Color::rgb(0xFF, 0xFF, 0xFF);
+
+/// xterm.js's default background color.
+pub(super) const DEFAULT_BACKGROUND: Color = Color::rgb(0x00, 0x00, 0x00);
+
+/// xterm.js's default cursor color.
+const DEFAULT_CURSOR: Color = Color::rgb(0xFF, 0xFF, 0xFF);
+
+/// xterm.js's default cursor accent color.
+const DEFAULT_CURSOR_ACCENT: Color = Color::rgb(0x00, 0x00, 0x00);
+
+/// xterm.js's default selection background color.
+const DEFAULT_SELECTION_BACKGROUND: Color = Color::rgba(0xFF, 0xFF, 0xFF, 0x4D);
+
+/// xterm.js's default ANSI colors 0-15.
+pub(super) const DEFAULT_ANSI: [Color; 16] = [
+    Color::rgb(0x2E, 0x34, 0x36),
+    Color::rgb(0xCC, 0x00, 0x00),
+    Color::rgb(0x4E, 0x9A, 0x06),
+    Color::rgb(0xC4, 0xA0, 0x00),
+    Color::rgb(0x34, 0x65, 0xA4),
+    Color::rgb(0x75, 0x50, 0x7B),
+    Color::rgb(0x06, 0x98, 0x9A),
+    Color::rgb(0xD3, 0xD7, 0xCF),
+    Color::rgb(0x55, 0x57, 0x53),
+    Color::rgb(0xEF, 0x29, 0x29),
+    Color::rgb(0x8A, 0xE2, 0x34),
+    Color::rgb(0xFC, 0xE9, 0x4F),
+    Color::rgb(0x72, 0x9F, 0xCF),
+    Color::rgb(0xAD, 0x7F, 0xA8),
+    Color::rgb(0x34, 0xE2, 0xE2),
+    Color::rgb(0xEE, 0xEE, 0xEC),
+];
+
+/// xterm's default color for an extended (16-255) ANSI color: a 6x6x6 color
+/// cube followed by a 24 step grayscale ramp.
+fn xterm_color(index: u8) -> Color {
+    let (r, g, b) = if index >= 232 {
+        let level = 8 + (index - 232) * 10;
+        (level, level, level)
+    } else {
+        let cube = index.saturating_sub(16);
+        let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+        (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+    };
+
+    Color::rgb(r, g, b)
+}
+
+/// A problem with one field of a theme, or of a color scheme being imported
+/// or exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ThemeFieldError {
+    /// The field, as it's named in the color scheme's format (or in
+    /// [`Theme`], when exporting).
+    pub field: Str,
+    /// What's wrong with it.
+    pub message: Str,
+}
+
+impl Display for ThemeFieldError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "`{}`: {}", self.field, self.message)
+    }
+}
+
+/// Error produced when a color scheme can't be converted to or from a
+/// [`Theme`] (or when a theme is [validated](Theme::validate)); lists every
+/// field that had a problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct ThemeError(pub Vec<ThemeFieldError>);
+
+impl Display for ThemeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "invalid color scheme")?;
+        for (idx, err) in self.0.iter().enumerate() {
+            write!(fmt, "{} {err}", if idx == 0 { ":" } else { ";" })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ThemeError {}
+
+/// Collects [`ThemeFieldError`]s while checking or converting a theme.
+#[derive(Debug, Default)]
+struct Fields(Vec<ThemeFieldError>);
+
+impl Fields {
+    /// Records a problem with `field`.
+    fn error(&mut self, field: impl Into<Str>, message: impl Into<Str>) {
+        self.0.push(ThemeFieldError {
+            field: field.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Parses a color, recording an error if it's malformed.
+    ///
+    /// Accepts exactly what [`Color`]'s `FromStr` does, so every color that
+    /// passes [`Theme::validate`] parses.
+    fn parse(&mut self, field: &str, color: Option<&str>) -> Option<Color> {
+        let color = color?;
+        color
+            .parse()
+            .map_err(|err: ParseColorError| {
+                self.error(field, format!("`{color}`: {err}"));
+            })
+            .ok()
+    }
+
+    /// Like [`Fields::parse`] but normalizes the color for a [`Theme`].
+    #[cfg(feature = "serde")]
+    fn import(&mut self, field: &str, color: Option<&str>) -> Option<Str> {
+        self.parse(field, color).map(Color::to_hex)
+    }
+
+    /// Parses a color from a [`Theme`] for exporting.
+    #[cfg(feature = "serde")]
+    fn export(&mut self, field: &str, color: Option<&str>) -> Option<Color> {
+        self.parse(field, color)
+    }
+
+    /// Parses one of the 256 ANSI colors from a [`Theme`] for exporting.
+    #[cfg(feature = "serde")]
+    fn export_ansi(&mut self, theme: &Theme, index: u8) -> Option<Color> {
+        let color = theme.ansi_color(index);
+        let color = color.as_deref();
+        match ANSI_NAMES.get(usize::from(index)) {
+            Some(name) => self.export(name, color),
+            None => {
+                self.export(&format!("extendedAnsi[{}]", index - 16), color)
+            }
+        }
+    }
+
+    /// Returns `value` if there weren't any errors.
+    fn finish<T>(self, value: T) -> Result<T, ThemeError> {
+        if self.0.is_empty() {
+            Ok(value)
+        } else {
+            Err(ThemeError(self.0))
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! [Alacritty] color schemes (the `colors` section of its config).
+//!
+//! Only the colors xterm.js has a use for are kept: the dim colors (and the
+//! `dim_foreground` and `bright_foreground` colors), the vi mode cursor and
+//! the search colors are ignored.
+//!
+//! [Alacritty]: https://alacritty.org/config-alacritty.html#s20
+
+use super::{Fields, ThemeError};
+use crate::ext::color::Color;
+use crate::xterm::{Str, Theme};
+
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// An Alacritty config; everything but the colors is ignored.
+///
+/// ```rust
+/// # use xterm_js_sys::{ext::theme::alacritty::{Colors, Config}, xterm::Theme};
+/// # use std::convert::TryFrom;
+/// let config: Config = serde_json::from_str(r#"{
+///     "font": { "size": 12 },
+///     "colors": {
+///         "primary": { "background": "0x1d1f21", "foreground": "0xc5c8c6" },
+///         "cursor": { "text": "CellBackground", "cursor": "CellForeground" },
+///         "normal": { "red": "0xcc6666" },
+///         "indexed_colors": [{ "index": 16, "color": "0xde935f" }]
+///     }
+/// }"#).unwrap();
+///
+/// let theme = Theme::try_from(&config.colors).unwrap();
+/// assert_eq!(theme.cursor().as_deref(), Some("#c5c8c6"));
+/// assert_eq!(theme.ansi_color(16).as_deref(), Some("#de935f"));
+///
+/// let exported = Colors::try_from(&theme).unwrap();
+/// assert_eq!(exported.normal.red.as_deref(), Some("#cc6666"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// The `colors` section.
+    pub colors: Colors,
+}
+
+/// The `colors` section of an Alacritty config.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Colors {
+    /// The default foreground and background colors.
+    pub primary: Primary,
+    /// The cursor colors.
+    pub cursor: Cursor,
+    /// The selection colors.
+    pub selection: Selection,
+    /// ANSI colors 0-7.
+    pub normal: Ansi,
+    /// ANSI colors 8-15.
+    pub bright: Ansi,
+    /// Any of the 256 ANSI colors (usually 16-255).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub indexed_colors: Vec<IndexedColor>,
+}
+
+/// `colors.primary`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[allow(missing_docs)]
+pub struct Primary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreground: Option<Str>,
+}
+
+/// `colors.cursor`.
+///
+/// Either color can also be `CellForeground` or `CellBackground`, which
+/// are imported as the default foreground and background colors.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Cursor {
+    /// The color of the character under the cursor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Str>,
+    /// The color of the cursor itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<Str>,
+}
+
+/// `colors.selection`.
+///
+/// Like [`Cursor`], either color can be `CellForeground` or
+/// `CellBackground`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[allow(missing_docs)]
+pub struct Selection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<Str>,
+}
+
+/// `colors.normal` or `colors.bright`.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+#[allow(missing_docs)]
+pub struct Ansi {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub black: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub red: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub green: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yellow: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blue: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub magenta: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cyan: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub white: Option<Str>,
+}
+
+impl Ansi {
+    /// The names of the 8 colors, in order.
+    const NAMES: [&'static str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+
+    /// The 8 colors, in order.
+    fn colors(&self) -> [&Option<Str>; 8] {
+        [
+            &self.black,
+            &self.red,
+            &self.green,
+            &self.yellow,
+            &self.blue,
+            &self.magenta,
+            &self.cyan,
+            &self.white,
+        ]
+    }
+
+    /// The 8 colors, in order.
+    fn colors_mut(&mut self) -> [&mut Option<Str>; 8] {
+        [
+            &mut self.black,
+            &mut self.red,
+            &mut self.green,
+            &mut self.yellow,
+            &mut self.blue,
+            &mut self.magenta,
+            &mut self.cyan,
+            &mut self.white,
+        ]
+    }
+}
+
+/// An entry in `colors.indexed_colors`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexedColor {
+    /// Which of the 256 ANSI colors this is.
+    pub index: u8,
+    /// The color.
+    pub color: Str,
+}
+
+/// Imports a color, accepting the `0x` prefixed hex colors Alacritty writes.
+fn import(
+    fields: &mut Fields,
+    field: &str,
+    color: Option<&str>,
+) -> Option<Str> {
+    match color.and_then(|c| c.strip_prefix("0x")) {
+        // Errors quote the color as it was written.
+        Some(hex) => match format!("#{hex}").parse::<Color>() {
+            Ok(parsed) => Some(parsed.to_hex()),
+            Err(err) => {
+                fields.error(field, format!("`0x{hex}`: {err}"));
+                None
+            }
+        },
+        None => fields.import(field, color),
+    }
+}
+
+/// Imports a cursor or selection color, resolving `CellForeground` and
+/// `CellBackground`.
+fn import_cell(
+    fields: &mut Fields,
+    field: &str,
+    color: Option<&str>,
+    theme: &Theme,
+) -> Option<Str> {
+    match color {
+        Some("CellForeground") => theme.foreground(),
+        Some("CellBackground") => theme.background(),
+        color => import(fields, field, color),
+    }
+}
+
+impl TryFrom<&Colors> for Theme {
+    type Error = ThemeError;
+
+    /// Imports Alacritty colors; fails if any of them are malformed.
+    fn try_from(colors: &Colors) -> Result<Theme, ThemeError> {
+        let mut fields = Fields::default();
+        let mut theme = Theme::default();
+
+        let primary = &colors.primary;
+        theme.set_background(import(
+            &mut fields,
+            "colors.primary.background",
+            primary.background.as_deref(),
+        ));
+        theme.set_foreground(import(
+            &mut fields,
+            "colors.primary.foreground",
+            primary.foreground.as_deref(),
+        ));
+
+        let cursor = &colors.cursor;
+        theme.set_cursor(import_cell(
+            &mut fields,
+            "colors.cursor.cursor",
+            cursor.cursor.as_deref(),
+            &theme,
+        ));
+        theme.set_cursor_accent(import_cell(
+            &mut fields,
+            "colors.cursor.text",
+            cursor.text.as_deref(),
+            &theme,
+        ));
+
+        let selection = &colors.selection;
+        theme.set_selection_background(import_cell(
+            &mut fields,
+            "colors.selection.background",
+            selection.background.as_deref(),
+            &theme,
+        ));
+        theme.set_selection_foreground(import_cell(
+            &mut fields,
+            "colors.selection.text",
+            selection.text.as_deref(),
+            &theme,
+        ));
+
+        for (section, ansi, offset) in
+            [("normal", &colors.normal, 0), ("bright", &colors.bright, 8)]
+        {
+            for ((index, name), color) in
+                (offset..).zip(Ansi::NAMES).zip(ansi.colors())
+            {
+                let field = format!("colors.{section}.{name}");
+                theme.set_ansi_color(
+                    index,
+                    import(&mut fields, &field, color.as_deref()),
+                );
+            }
+        }
+
+        for (pos, indexed) in colors.indexed_colors.iter().enumerate() {
+            let field = format!("colors.indexed_colors[{pos}].color");
+            let color = import(&mut fields, &field, Some(&indexed.color));
+            if let Some(color) = color {
+                theme.set_ansi_color(indexed.index, Some(color));
+            }
+        }
+
+        fields.finish(theme)
+    }
+}
+
+impl TryFrom<&Theme> for Colors {
+    type Error = ThemeError;
+
+    /// Exports a [`Theme`]; fails if any of its colors are malformed.
+    ///
+    /// Alacritty doesn't do transparency so any alpha is dropped.
+    fn try_from(theme: &Theme) -> Result<Colors, ThemeError> {
+        let mut fields = Fields::default();
+        let hex = |c: Color| c.opaque().to_hex();
+
+        let mut colors = Colors {
+            primary: Primary {
+                background: fields
+                    .export("background", theme.background().as_deref())
+                    .map(hex),
+                foreground: fields
+                    .export("foreground", theme.foreground().as_deref())
+                    .map(hex),
+            },
+            cursor: Cursor {
+                text: fields
+                    .export("cursorAccent", theme.cursor_accent().as_deref())
+                    .map(hex),
+                cursor: fields
+                    .export("cursor", theme.cursor().as_deref())
+                    .map(hex),
+            },
+            selection: Selection {
+                text: fields
+                    .export(
+                        "selectionForeground",
+                        theme.selection_foreground().as_deref(),
+                    )
+                    .map(hex),
+                background: fields
+                    .export(
+                        "selectionBackground",
+                        theme.selection_background().as_deref(),
+                    )
+                    .map(hex),
+            },
+            ..Colors::default()
+        };
+
+        for (index, color) in (0..).zip(colors.normal.colors_mut()) {
+            *color = fields.export_ansi(theme, index).map(hex);
+        }
+        for (index, color) in (8..).zip(colors.bright.colors_mut()) {
+            *color = fields.export_ansi(theme, index).map(hex);
+        }
+
+        let extended = theme.extended_ansi().unwrap_or_default();
+        for index in (16..=255).take(extended.len()) {
+            if let Some(color) = fields.export_ansi(theme, index) {
+                colors.indexed_colors.push(IndexedColor {
+                    index,
+                    color: hex(color),
+                });
+            }
+        }
+
+        fields.finish(colors)
+    }
+}
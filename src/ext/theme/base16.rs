@@ -0,0 +1,214 @@
+//! [base16] color schemes.
+//!
+//! The 16 base colors are mapped onto ANSI colors the way [base16-shell]
+//! does it: the 8 normal and 8 bright colors, with the 6 colors that are
+//! left over as ANSI colors 16-21.
+//!
+//! [base16]: https://github.com/chriskempson/base16
+//! [base16-shell]: https://github.com/chriskempson/base16-shell
+
+use super::{Fields, ThemeError};
+use crate::xterm::{Str, Theme};
+
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// A base16 color scheme.
+///
+/// The colors are hex colors, usually without the `#`.
+///
+/// ```rust
+/// # use xterm_js_sys::{ext::theme::base16::Scheme, xterm::Theme};
+/// # use std::convert::TryFrom;
+/// let scheme: Scheme = serde_json::from_str(r#"{
+///     "scheme": "Default Dark",
+///     "author": "Chris Kempson",
+///     "base00": "181818", "base01": "282828", "base02": "383838",
+///     "base03": "585858", "base04": "b8b8b8", "base05": "d8d8d8",
+///     "base06": "e8e8e8", "base07": "f8f8f8", "base08": "ab4642",
+///     "base09": "dc9656", "base0A": "f7ca88", "base0B": "a1b56c",
+///     "base0C": "86c1b9", "base0D": "7cafc2", "base0E": "ba8baf",
+///     "base0F": "a16946"
+/// }"#).unwrap();
+///
+/// let theme = Theme::try_from(&scheme).unwrap();
+/// assert_eq!(theme.red().as_deref(), Some("#ab4642"));
+/// assert_eq!(theme.ansi_color(16).as_deref(), Some("#dc9656"));
+///
+/// let exported = Scheme::from_theme("Default Dark", "", &theme).unwrap();
+/// assert_eq!(exported, Scheme { author: "".into(), ..scheme });
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct Scheme {
+    pub scheme: Str,
+    pub author: Str,
+    pub base00: Str,
+    pub base01: Str,
+    pub base02: Str,
+    pub base03: Str,
+    pub base04: Str,
+    pub base05: Str,
+    pub base06: Str,
+    pub base07: Str,
+    pub base08: Str,
+    pub base09: Str,
+    #[serde(rename = "base0A")]
+    pub base0a: Str,
+    #[serde(rename = "base0B")]
+    pub base0b: Str,
+    #[serde(rename = "base0C")]
+    pub base0c: Str,
+    #[serde(rename = "base0D")]
+    pub base0d: Str,
+    #[serde(rename = "base0E")]
+    pub base0e: Str,
+    #[serde(rename = "base0F")]
+    pub base0f: Str,
+}
+
+/// Which base color (by number) each of ANSI colors 0-21 is.
+const ANSI: [usize; 22] = [
+    0x0, 0x8, 0xB, 0xA, 0xD, 0xE, 0xC, 0x5, 0x3, 0x8, 0xB, 0xA, 0xD, 0xE, 0xC,
+    0x7, 0x9, 0xF, 0x1, 0x2, 0x4, 0x6,
+];
+
+/// Which ANSI color each of the base colors is exported from (the first
+/// one, in [`ANSI`], that's mapped to it).
+const BASES: [u8; 16] = [0, 18, 19, 8, 20, 7, 21, 15, 1, 16, 3, 2, 6, 4, 5, 17];
+
+impl Scheme {
+    /// The names of the base colors, in order.
+    const NAMES: [&'static str; 16] = [
+        "base00", "base01", "base02", "base03", "base04", "base05", "base06",
+        "base07", "base08", "base09", "base0A", "base0B", "base0C", "base0D",
+        "base0E", "base0F",
+    ];
+
+    /// The base colors, in order.
+    fn bases(&self) -> [&Str; 16] {
+        [
+            &self.base00,
+            &self.base01,
+            &self.base02,
+            &self.base03,
+            &self.base04,
+            &self.base05,
+            &self.base06,
+            &self.base07,
+            &self.base08,
+            &self.base09,
+            &self.base0a,
+            &self.base0b,
+            &self.base0c,
+            &self.base0d,
+            &self.base0e,
+            &self.base0f,
+        ]
+    }
+
+    /// The base colors, in order.
+    fn bases_mut(&mut self) -> [&mut Str; 16] {
+        [
+            &mut self.base00,
+            &mut self.base01,
+            &mut self.base02,
+            &mut self.base03,
+            &mut self.base04,
+            &mut self.base05,
+            &mut self.base06,
+            &mut self.base07,
+            &mut self.base08,
+            &mut self.base09,
+            &mut self.base0a,
+            &mut self.base0b,
+            &mut self.base0c,
+            &mut self.base0d,
+            &mut self.base0e,
+            &mut self.base0f,
+        ]
+    }
+
+    /// Exports a [`Theme`] as a scheme called `name`.
+    ///
+    /// The background and foreground colors are used for `base00` and
+    /// `base05` when set; the theme must have all of ANSI colors 0-21 (apart
+    /// from those two, if they're set) since base16 colors can't be left
+    /// out. Transparency is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are missing or malformed.
+    pub fn from_theme(
+        name: impl Into<Str>,
+        author: impl Into<Str>,
+        theme: &Theme,
+    ) -> Result<Self, ThemeError> {
+        let mut fields = Fields::default();
+        let mut scheme = Self {
+            scheme: name.into(),
+            author: author.into(),
+            ..Self::default()
+        };
+
+        let background =
+            fields.export("background", theme.background().as_deref());
+        let foreground =
+            fields.export("foreground", theme.foreground().as_deref());
+
+        for ((base, index), color) in (0..).zip(BASES).zip(scheme.bases_mut()) {
+            let rgba = match (base, background, foreground) {
+                (0x0, Some(bg), _) => Some(bg),
+                (0x5, _, Some(fg)) => Some(fg),
+                _ => match fields.export_ansi(theme, index) {
+                    None if theme.ansi_color(index).is_none() => {
+                        fields.error(
+                            Self::NAMES[base],
+                            format!("missing (ANSI color {index})"),
+                        );
+                        None
+                    }
+                    rgba => rgba,
+                },
+            };
+
+            if let Some(rgba) = rgba {
+                let hex = rgba.opaque().to_hex();
+                *color = hex.trim_start_matches('#').into();
+            }
+        }
+
+        fields.finish(scheme)
+    }
+}
+
+impl TryFrom<&Scheme> for Theme {
+    type Error = ThemeError;
+
+    /// Imports a base16 scheme; fails if any of its colors are malformed.
+    fn try_from(scheme: &Scheme) -> Result<Theme, ThemeError> {
+        let mut fields = Fields::default();
+        let bases = scheme
+            .bases()
+            .into_iter()
+            .zip(Scheme::NAMES)
+            .map(|(color, name)| {
+                let color = color.strip_prefix('#').unwrap_or(color);
+                fields.import(name, Some(&format!("#{color}")))
+            })
+            .collect::<Vec<_>>();
+
+        let mut theme = Theme::default();
+        for (index, &base) in (0..).zip(ANSI.iter()) {
+            theme.set_ansi_color(index, bases[base].clone());
+        }
+
+        theme.set_background(bases[0x0].clone());
+        theme.set_foreground(bases[0x5].clone());
+        theme.set_cursor(bases[0x5].clone());
+        theme.set_cursor_accent(bases[0x0].clone());
+        theme.set_selection_background(bases[0x2].clone());
+
+        fields.finish(theme)
+    }
+}
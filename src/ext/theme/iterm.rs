@@ -0,0 +1,217 @@
+//! [iTerm2] color schemes (`.itermcolors` files).
+//!
+//! These are property lists so parsing one takes a plist crate (like
+//! [`plist`](https://docs.rs/plist)) that works with `serde`.
+//!
+//! Colors are taken as being sRGB; the `Color Space` of each color is
+//! ignored when importing.
+//!
+//! [iTerm2]: https://iterm2.com/documentation-preferences-profiles-colors.html
+
+use super::{Fields, ThemeError};
+use crate::ext::color::Color as CssColor;
+use crate::xterm::{Str, Theme};
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+/// A color in an iTerm2 color scheme; each component is between 0 and 1.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[allow(missing_docs)]
+pub struct Color {
+    #[serde(rename = "Red Component")]
+    pub red: f64,
+    #[serde(rename = "Green Component")]
+    pub green: f64,
+    #[serde(rename = "Blue Component")]
+    pub blue: f64,
+    #[serde(
+        rename = "Alpha Component",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub alpha: Option<f64>,
+    #[serde(
+        rename = "Color Space",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color_space: Option<Str>,
+}
+
+impl Color {
+    /// Converts to a hex color, recording an error if a component is out of
+    /// range.
+    fn import(&self, fields: &mut Fields, field: &str) -> Option<Str> {
+        let mut component = |name: &str, value: f64| {
+            if (0.0..=1.0).contains(&value) {
+                // In range, so this can't truncate or lose the sign.
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss
+                )]
+                Some((value * 255.0).round() as u8)
+            } else {
+                fields.error(
+                    field,
+                    format!(
+                        "{name} component `{value}` is not between 0 and 1"
+                    ),
+                );
+                None
+            }
+        };
+
+        let r = component("red", self.red);
+        let g = component("green", self.green);
+        let b = component("blue", self.blue);
+        let a = component("alpha", self.alpha.unwrap_or(1.0));
+
+        Some(CssColor::rgba(r?, g?, b?, a?).to_hex())
+    }
+
+    /// Converts from a parsed color.
+    fn export(color: CssColor) -> Self {
+        let component = |c: u8| f64::from(c) / 255.0;
+        Self {
+            red: component(color.r),
+            green: component(color.g),
+            blue: component(color.b),
+            alpha: Some(component(color.a)),
+            color_space: Some("sRGB".into()),
+        }
+    }
+}
+
+/// An iTerm2 color scheme: colors keyed by names like `Ansi 1 Color` and
+/// `Background Color`.
+///
+/// Unknown keys (like `Bold Color` and `Link Color`) are kept but aren't
+/// imported.
+///
+/// ```rust
+/// # use xterm_js_sys::{ext::theme::iterm::ColorScheme, xterm::Theme};
+/// # use std::convert::TryFrom;
+/// let scheme: ColorScheme = serde_json::from_str(r#"{
+///     "Ansi 1 Color": {
+///         "Red Component": 1.0,
+///         "Green Component": 0.0,
+///         "Blue Component": 0.0
+///     },
+///     "Selection Color": {
+///         "Red Component": 0.0,
+///         "Green Component": 0.0,
+///         "Blue Component": 1.0,
+///         "Alpha Component": 0.5
+///     }
+/// }"#).unwrap();
+///
+/// let theme = Theme::try_from(&scheme).unwrap();
+/// assert_eq!(theme.red().as_deref(), Some("#ff0000"));
+/// assert_eq!(theme.selection_background().as_deref(), Some("#0000ff80"));
+///
+/// let exported = ColorScheme::try_from(&theme).unwrap();
+/// assert_eq!(exported.0["Ansi 1 Color"].red, 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ColorScheme(pub BTreeMap<Str, Color>);
+
+/// The keys of the colors that aren't ANSI colors, and how to get and set
+/// them on a [`Theme`].
+#[allow(clippy::type_complexity)]
+const KEYS: [(
+    &str,
+    &str,
+    fn(&Theme) -> Option<Str>,
+    fn(&mut Theme, Option<Str>),
+); 6] = [
+    (
+        "Background Color",
+        "background",
+        Theme::background,
+        Theme::set_background,
+    ),
+    (
+        "Foreground Color",
+        "foreground",
+        Theme::foreground,
+        Theme::set_foreground,
+    ),
+    ("Cursor Color", "cursor", Theme::cursor, Theme::set_cursor),
+    (
+        "Cursor Text Color",
+        "cursorAccent",
+        Theme::cursor_accent,
+        Theme::set_cursor_accent,
+    ),
+    (
+        "Selection Color",
+        "selectionBackground",
+        Theme::selection_background,
+        Theme::set_selection_background,
+    ),
+    (
+        "Selected Text Color",
+        "selectionForeground",
+        Theme::selection_foreground,
+        Theme::set_selection_foreground,
+    ),
+];
+
+impl TryFrom<&ColorScheme> for Theme {
+    type Error = ThemeError;
+
+    /// Imports an iTerm2 color scheme; fails if any of its colors have
+    /// components that are out of range.
+    fn try_from(scheme: &ColorScheme) -> Result<Theme, ThemeError> {
+        let mut fields = Fields::default();
+        let mut theme = Theme::default();
+
+        for (key, _, _, set) in KEYS {
+            if let Some(color) = scheme.0.get(key) {
+                set(&mut theme, color.import(&mut fields, key));
+            }
+        }
+
+        for index in 0..16 {
+            let key = format!("Ansi {index} Color");
+            if let Some(color) = scheme.0.get(&*key) {
+                theme.set_ansi_color(index, color.import(&mut fields, &key));
+            }
+        }
+
+        fields.finish(theme)
+    }
+}
+
+impl TryFrom<&Theme> for ColorScheme {
+    type Error = ThemeError;
+
+    /// Exports a [`Theme`]; fails if any of its colors are malformed.
+    ///
+    /// iTerm2 only has the 16 named ANSI colors so
+    /// [`extended_ansi`](Theme::extended_ansi) isn't exported.
+    fn try_from(theme: &Theme) -> Result<ColorScheme, ThemeError> {
+        let mut fields = Fields::default();
+        let mut scheme = BTreeMap::new();
+
+        for (key, name, get, _) in KEYS {
+            if let Some(color) = fields.export(name, get(theme).as_deref()) {
+                let _ = scheme.insert(key.into(), Color::export(color));
+            }
+        }
+
+        for index in 0..16 {
+            if let Some(color) = fields.export_ansi(theme, index) {
+                let _ = scheme.insert(
+                    format!("Ansi {index} Color"),
+                    Color::export(color),
+                );
+            }
+        }
+
+        fields.finish(ColorScheme(scheme))
+    }
+}
@@ -0,0 +1,240 @@
+//! Generated colors for [`Theme`]s: 256-color palettes for
+//! [`extended_ansi`](Theme::extended_ansi) and light or dark variants of a
+//! theme.
+//!
+//! Colors a theme doesn't set are taken to be xterm.js's defaults.
+
+use super::{
+    xterm_color, ThemeError, DEFAULT_ANSI, DEFAULT_BACKGROUND, DEFAULT_CURSOR,
+    DEFAULT_CURSOR_ACCENT, DEFAULT_FOREGROUND, DEFAULT_SELECTION_BACKGROUND,
+};
+use crate::ext::color::{Color, ParseColorError};
+use crate::xterm::{Str, Theme};
+
+/// Where the 240 extended ANSI colors (16-255) come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ExtendedPalette {
+    /// xterm's: a 6x6x6 color cube and a 24 step grayscale ramp (what
+    /// xterm.js uses when [`extended_ansi`](Theme::extended_ansi) isn't
+    /// set).
+    #[default]
+    Xterm,
+    /// Made from the theme's own colors, so 256-color output fits in: a
+    /// 6x6x6 cube that's interpolated between the background, the 6 normal
+    /// colors and the foreground (one at each corner) and a grayscale ramp
+    /// from the background to the foreground.
+    FromTheme,
+}
+
+impl ExtendedPalette {
+    /// Generates colors 16-255 for `theme` ([`ExtendedPalette::Xterm`]
+    /// ignores the theme).
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are malformed.
+    pub fn generate(self, theme: &Theme) -> Result<Vec<Color>, ThemeError> {
+        Ok(match self {
+            Self::Xterm => (16..=255).map(xterm_color).collect(),
+            Self::FromTheme => {
+                let colors = Colors::new(theme)?;
+                let bg = colors.background.opaque();
+                let fg = colors.foreground.opaque();
+                let ansi = colors.ansi.map(Color::opaque);
+
+                // Indexed by the red, green and blue coordinates.
+                let corners = [
+                    [[bg, ansi[4]], [ansi[2], ansi[6]]],
+                    [[ansi[1], ansi[5]], [ansi[3], fg]],
+                ];
+
+                let mut palette = Vec::with_capacity(240);
+                for index in 0..216_u8 {
+                    let [r, g, b] = [index / 36, index / 6 % 6, index % 6]
+                        .map(|v| f64::from(v) / 5.0);
+                    let face = |c: [[Color; 2]; 2]| {
+                        let edge = |e: [Color; 2]| e[0].mix(e[1], b);
+                        edge(c[0]).mix(edge(c[1]), g)
+                    };
+
+                    palette.push(face(corners[0]).mix(face(corners[1]), r));
+                }
+
+                for step in 1..=24 {
+                    palette.push(bg.mix(fg, f64::from(step) / 25.0));
+                }
+
+                palette
+            }
+        })
+    }
+}
+
+impl Theme {
+    /// Sets [`extended_ansi`](Theme::extended_ansi) to a generated palette.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::{ext::theme::palette::ExtendedPalette, xterm::Theme};
+    /// let mut theme = Theme::nord();
+    /// theme.fill_extended_ansi(ExtendedPalette::FromTheme).unwrap();
+    ///
+    /// // The corners of the cube are the theme's colors.
+    /// let ext = theme.extended_ansi_colors().unwrap().unwrap();
+    /// assert_eq!(Some(ext[0]), theme.background_color().unwrap());
+    /// assert_eq!(Some(ext[196 - 16]), theme.red_color().unwrap());
+    /// assert_eq!(Some(ext[231 - 16]), theme.foreground_color().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are malformed.
+    pub fn fill_extended_ansi(
+        &mut self,
+        palette: ExtendedPalette,
+    ) -> Result<(), ThemeError> {
+        let colors = palette.generate(self)?;
+        self.set_extended_ansi_colors(Some(colors));
+
+        Ok(())
+    }
+
+    /// Whether the theme's background is dark (i.e. white text has more
+    /// contrast on it than black text).
+    ///
+    /// # Errors
+    ///
+    /// Fails if the background color is malformed.
+    pub fn is_dark(&self) -> Result<bool, ParseColorError> {
+        let background = self
+            .background_color()?
+            .unwrap_or(DEFAULT_BACKGROUND)
+            .opaque();
+
+        Ok(background.contrast_ratio(Color::rgb(0xFF, 0xFF, 0xFF))
+            > background.contrast_ratio(Color::rgb(0, 0, 0)))
+    }
+
+    /// A light variant of a dark theme (or a copy of the theme, if it's
+    /// already light).
+    ///
+    /// Every color has its lightness flipped, keeping its hue and
+    /// saturation, and is then adjusted to have (at least) the contrast with
+    /// the new background that it had with the old one.
+    ///
+    /// ```rust
+    /// # use xterm_js_sys::xterm::Theme;
+    /// let light = Theme::nord().to_light().unwrap();
+    /// assert!(!light.is_dark().unwrap());
+    /// assert_eq!(light.to_light().unwrap(), light);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are malformed.
+    pub fn to_light(&self) -> Result<Theme, ThemeError> {
+        if self.is_dark().unwrap_or(true) {
+            flip(self)
+        } else {
+            Ok(self.clone())
+        }
+    }
+
+    /// A dark variant of a light theme (or a copy of the theme, if it's
+    /// already dark); see [`Theme::to_light`].
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are malformed.
+    pub fn to_dark(&self) -> Result<Theme, ThemeError> {
+        if self.is_dark().unwrap_or(false) {
+            Ok(self.clone())
+        } else {
+            flip(self)
+        }
+    }
+}
+
+/// The colors of a theme, with xterm.js's defaults filled in.
+struct Colors {
+    /// The background.
+    background: Color,
+    /// The foreground.
+    foreground: Color,
+    /// ANSI colors 0-15.
+    ansi: [Color; 16],
+}
+
+impl Colors {
+    /// Parses `theme`'s colors.
+    fn new(theme: &Theme) -> Result<Self, ThemeError> {
+        theme.validate()?;
+
+        // Everything parses now that the theme's been validated.
+        let parse = |color: Option<Str>, default: Color| {
+            color.and_then(|c| c.parse().ok()).unwrap_or(default)
+        };
+
+        let mut ansi = DEFAULT_ANSI;
+        for (index, color) in (0..).zip(&mut ansi) {
+            *color = parse(theme.ansi_color(index), *color);
+        }
+
+        Ok(Self {
+            background: parse(theme.background(), DEFAULT_BACKGROUND),
+            foreground: parse(theme.foreground(), DEFAULT_FOREGROUND),
+            ansi,
+        })
+    }
+}
+
+/// Flips the lightness of every color in `theme`.
+fn flip(theme: &Theme) -> Result<Theme, ThemeError> {
+    let colors = Colors::new(theme)?;
+    let invert = |color: Color| {
+        let (h, s, l) = color.to_hsl();
+        Color::from_hsl(h, s, 1.0 - l, color.a)
+    };
+
+    let old_bg = colors.background.opaque();
+    let new_bg = invert(old_bg);
+    let flip = |color: Color| {
+        let ratio = color.over(old_bg).contrast_ratio(old_bg);
+        invert(color).with_minimum_contrast(new_bg, ratio).to_hex()
+    };
+    let flip_str = |color: Option<Str>, default: Option<Color>| {
+        color
+            .and_then(|c| c.parse().ok())
+            .or(default)
+            .map(|c: Color| flip(c))
+    };
+
+    let mut flipped = theme.clone();
+    flipped.set_background(Some(new_bg.to_hex()));
+    flipped.set_foreground(Some(flip(colors.foreground)));
+    for (index, color) in (0..).zip(colors.ansi) {
+        flipped.set_ansi_color(index, Some(flip(color)));
+    }
+    flipped.set_extended_ansi(theme.extended_ansi().map(|ext| {
+        ext.into_iter()
+            .filter_map(|c| flip_str(Some(c), None))
+            .collect()
+    }));
+
+    flipped.set_cursor(flip_str(theme.cursor(), Some(DEFAULT_CURSOR)));
+    flipped.set_cursor_accent(flip_str(
+        theme.cursor_accent(),
+        Some(DEFAULT_CURSOR_ACCENT),
+    ));
+    flipped.set_selection_background(flip_str(
+        theme.selection_background(),
+        Some(DEFAULT_SELECTION_BACKGROUND),
+    ));
+    flipped
+        .set_selection_foreground(flip_str(theme.selection_foreground(), None));
+    flipped.set_selection_inactive_background(flip_str(
+        theme.selection_inactive_background(),
+        None,
+    ));
+
+    Ok(flipped)
+}
@@ -0,0 +1,214 @@
+//! [Windows Terminal] color schemes.
+//!
+//! [Windows Terminal]: https://learn.microsoft.com/en-us/windows/terminal/customize-settings/color-schemes
+
+use super::{Fields, ThemeError};
+use crate::ext::color::Color;
+use crate::xterm::{Str, Theme};
+
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// A Windows Terminal color scheme (an entry in the `schemes` list in its
+/// `settings.json`).
+///
+/// ```rust
+/// # use xterm_js_sys::{ext::theme::windows_terminal::Scheme, xterm::Theme};
+/// # use std::convert::TryFrom;
+/// let scheme: Scheme = serde_json::from_str(r##"{
+///     "name": "Campbell",
+///     "background": "#0C0C0C",
+///     "foreground": "#CCCCCC",
+///     "purple": "#881798"
+/// }"##).unwrap();
+///
+/// let theme = Theme::try_from(&scheme).unwrap();
+/// assert_eq!(theme.magenta().as_deref(), Some("#881798"));
+///
+/// let exported = Scheme::from_theme("Campbell", &theme).unwrap();
+/// assert_eq!(exported.background.as_deref(), Some("#0c0c0c"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+#[allow(missing_docs)]
+pub struct Scheme {
+    pub name: Str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreground: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor_color: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selection_background: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub black: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub red: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub green: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yellow: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blue: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purple: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cyan: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub white: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_black: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_red: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_green: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_yellow: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_blue: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_purple: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_cyan: Option<Str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bright_white: Option<Str>,
+}
+
+impl Scheme {
+    /// The names of the 16 ANSI colors, in order.
+    const ANSI: [&'static str; 16] = [
+        "black",
+        "red",
+        "green",
+        "yellow",
+        "blue",
+        "purple",
+        "cyan",
+        "white",
+        "brightBlack",
+        "brightRed",
+        "brightGreen",
+        "brightYellow",
+        "brightBlue",
+        "brightPurple",
+        "brightCyan",
+        "brightWhite",
+    ];
+
+    /// The 16 ANSI colors, in order.
+    fn ansi(&self) -> [&Option<Str>; 16] {
+        [
+            &self.black,
+            &self.red,
+            &self.green,
+            &self.yellow,
+            &self.blue,
+            &self.purple,
+            &self.cyan,
+            &self.white,
+            &self.bright_black,
+            &self.bright_red,
+            &self.bright_green,
+            &self.bright_yellow,
+            &self.bright_blue,
+            &self.bright_purple,
+            &self.bright_cyan,
+            &self.bright_white,
+        ]
+    }
+
+    /// The 16 ANSI colors, in order.
+    fn ansi_mut(&mut self) -> [&mut Option<Str>; 16] {
+        [
+            &mut self.black,
+            &mut self.red,
+            &mut self.green,
+            &mut self.yellow,
+            &mut self.blue,
+            &mut self.purple,
+            &mut self.cyan,
+            &mut self.white,
+            &mut self.bright_black,
+            &mut self.bright_red,
+            &mut self.bright_green,
+            &mut self.bright_yellow,
+            &mut self.bright_blue,
+            &mut self.bright_purple,
+            &mut self.bright_cyan,
+            &mut self.bright_white,
+        ]
+    }
+
+    /// Exports a [`Theme`] as a scheme called `name`.
+    ///
+    /// Windows Terminal doesn't do transparency so any alpha is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Fails if any of the theme's colors are malformed.
+    pub fn from_theme(
+        name: impl Into<Str>,
+        theme: &Theme,
+    ) -> Result<Self, ThemeError> {
+        let mut fields = Fields::default();
+        let hex = |c: Color| c.opaque().to_hex();
+
+        let mut scheme = Self {
+            name: name.into(),
+            background: fields
+                .export("background", theme.background().as_deref())
+                .map(hex),
+            foreground: fields
+                .export("foreground", theme.foreground().as_deref())
+                .map(hex),
+            cursor_color: fields
+                .export("cursor", theme.cursor().as_deref())
+                .map(hex),
+            selection_background: fields
+                .export(
+                    "selectionBackground",
+                    theme.selection_background().as_deref(),
+                )
+                .map(hex),
+            ..Self::default()
+        };
+        for (index, color) in (0..).zip(scheme.ansi_mut()) {
+            *color = fields.export_ansi(theme, index).map(hex);
+        }
+
+        fields.finish(scheme)
+    }
+}
+
+impl TryFrom<&Scheme> for Theme {
+    type Error = ThemeError;
+
+    /// Imports a Windows Terminal scheme; fails if any of its colors are
+    /// malformed.
+    fn try_from(scheme: &Scheme) -> Result<Theme, ThemeError> {
+        let mut fields = Fields::default();
+        let mut theme = Theme::default();
+
+        theme.set_background(
+            fields.import("background", scheme.background.as_deref()),
+        );
+        theme.set_foreground(
+            fields.import("foreground", scheme.foreground.as_deref()),
+        );
+        theme.set_cursor(
+            fields.import("cursorColor", scheme.cursor_color.as_deref()),
+        );
+        theme.set_selection_background(fields.import(
+            "selectionBackground",
+            scheme.selection_background.as_deref(),
+        ));
+
+        for ((index, name), color) in (0..).zip(Scheme::ANSI).zip(scheme.ansi())
+        {
+            theme.set_ansi_color(index, fields.import(name, color.as_deref()));
+        }
+
+        fields.finish(theme)
+    }
+}
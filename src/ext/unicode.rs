@@ -1,4 +1,7 @@
-//! Helpers and duals for [`UnicodeVersionProvider`] and [`UnicodeHandling`].
+//! Helpers and duals for [`UnicodeVersionProvider`] and [`UnicodeHandling`],
+//! along with a [Unicode 15.1 provider](UnicodeV15) implemented in Rust and a
+//! [wrapper](AmbiguousWidth) for picking the width of ambiguous width
+//! characters.
 //!
 //! [`UnicodeHandling`]: crate::xterm::UnicodeHandling
 //! [`UnicodeVersionProvider`]: crate::xterm::UnicodeVersionProvider
@@ -8,6 +11,20 @@ use crate::xterm::{
     Str, UnicodeHandling, UnicodeVersionProvider, WideCharacterWidth,
 };
 
+use js_sys::{Function, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+
+mod tables;
+
+mod ambiguous;
+pub use ambiguous::{is_ambiguous, AmbiguousWidth};
+
+pub mod graphemes;
+pub use graphemes::{CharProperties, GraphemeBreak};
+
+mod v15;
+pub use v15::UnicodeV15;
+
 interface! {
     pub trait XtermUnicodeVersionProvider mirrors UnicodeVersionProvider {
         /// Gets a string indicating the Unicode version provided.
@@ -15,6 +32,15 @@ interface! {
 
         /// Unicode version dependent `wcwidth` implementation.
         fn wcwidth(&self, codepoint: u32) -> WideCharacterWidth;
+
+        /// Unicode version dependent character properties implementation.
+        ///
+        /// `preceding` is what this returned for the previous codepoint (or
+        /// 0 if there wasn't one); both are packed as described in
+        /// [`CharProperties`]. Providers that don't care about grapheme
+        /// clusters can use [`CharProperties::without_graphemes`], providers
+        /// that do can use [`CharProperties::with_graphemes`].
+        fn char_properties(&self, codepoint: u32, preceding: u32) -> u32;
     }
 }
 
@@ -30,6 +56,36 @@ impl UnicodeHandling {
     where
         P: IntoJsInterface<UnicodeVersionProvider>,
     {
-        self.register(provider.by_ref())
+        let provider = provider.by_ref();
+
+        // xterm.js reads `version` as a property but Rust providers have it as
+        // a method; swap in its value so the provider is registered under the
+        // right name.
+        let key = JsValue::from_str("version");
+        if let Ok(version) = Reflect::get(&provider, &key) {
+            if let Some(version) = version.dyn_ref::<Function>() {
+                if let Ok(version) = version.call0(&provider) {
+                    let _ = Reflect::set(&provider, &key, &version);
+                }
+            }
+        }
+
+        self.register(provider);
     }
 }
+
+/// Whether `codepoint` is in one of the ranges in `table` (which must be
+/// sorted).
+fn in_table(table: &[(u32, u32)], codepoint: u32) -> bool {
+    table
+        .binary_search_by(|&(low, high)| {
+            if high < codepoint {
+                core::cmp::Ordering::Less
+            } else if low > codepoint {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
@@ -0,0 +1,164 @@
+//! A [version provider](XtermUnicodeVersionProvider) wrapper that picks the
+//! width of East Asian ambiguous width characters.
+
+use super::{in_table, tables, CharProperties, XtermUnicodeVersionProvider};
+use crate::xterm::{Str, WideCharacterWidth};
+
+use std::collections::HashMap;
+
+/// Wraps another [version provider](XtermUnicodeVersionProvider), overriding
+/// the width of East Asian Width class `A` ("ambiguous") characters and of
+/// any individual codepoints you want.
+///
+/// Ambiguous width characters (box drawing, Greek, Cyrillic, some symbols)
+/// are two columns wide in traditional CJK fonts and one column wide
+/// everywhere else; most providers (including xterm.js's) go with one.
+///
+/// The wrapper registers under its own version name so it can sit alongside
+/// its base provider; switch between them at runtime with
+/// [`UnicodeHandling::set_active_version`]:
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "proposed-api")] {
+/// # use xterm_js_sys::ext::unicode::{AmbiguousWidth, UnicodeV15};
+/// # use xterm_js_sys::xterm::{ProposedTerminal, WideCharacterWidth};
+/// # let terminal = ProposedTerminal::new(None);
+/// let cjk = AmbiguousWidth::new(UnicodeV15, WideCharacterWidth::_2)
+///     .with_override(0x2026, WideCharacterWidth::_1); // …
+///
+/// let unicode = terminal.unicode();
+/// unicode.register_version_provider(&UnicodeV15);
+/// unicode.register_version_provider(&cjk);
+///
+/// unicode.set_active_version(cjk.version_name().into());
+/// // and later, to go back:
+/// unicode.set_active_version(UnicodeV15::VERSION.into());
+/// # }
+/// ```
+///
+/// [`UnicodeHandling::set_active_version`]: crate::xterm::UnicodeHandling::set_active_version
+#[derive(Debug, Clone)]
+pub struct AmbiguousWidth<P> {
+    /// The provider being wrapped.
+    base: P,
+    /// The version name to register under.
+    version: Str,
+    /// Width for characters in the `A` class.
+    ambiguous: WideCharacterWidth,
+    /// Per codepoint widths; these win over everything else.
+    overrides: HashMap<u32, WideCharacterWidth>,
+}
+
+impl<P: XtermUnicodeVersionProvider> AmbiguousWidth<P> {
+    /// Wraps `base`, making ambiguous width characters `ambiguous` columns
+    /// wide.
+    ///
+    /// The version name defaults to the base provider's, suffixed with
+    /// `-wide-ambiguous` or `-narrow-ambiguous` (see
+    /// [`with_version_name`](AmbiguousWidth::with_version_name)).
+    pub fn new(base: P, ambiguous: WideCharacterWidth) -> Self {
+        let suffix = match ambiguous {
+            WideCharacterWidth::_2 => "wide-ambiguous",
+            _ => "narrow-ambiguous",
+        };
+        let version = format!("{}-{suffix}", base.version());
+
+        Self {
+            base,
+            version,
+            ambiguous,
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// Sets the version name the provider is registered under.
+    #[must_use]
+    pub fn with_version_name(mut self, version: impl Into<Str>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Gives a single codepoint a particular width, regardless of its class
+    /// or what the base provider says.
+    #[must_use]
+    pub fn with_override(
+        mut self,
+        codepoint: u32,
+        width: WideCharacterWidth,
+    ) -> Self {
+        let _ = self.overrides.insert(codepoint, width);
+        self
+    }
+
+    /// Gives each codepoint in `overrides` a particular width (see
+    /// [`with_override`](AmbiguousWidth::with_override)).
+    #[must_use]
+    pub fn with_overrides(
+        mut self,
+        overrides: impl IntoIterator<Item = (u32, WideCharacterWidth)>,
+    ) -> Self {
+        self.overrides.extend(overrides);
+        self
+    }
+
+    /// The version name the provider is registered under.
+    #[must_use]
+    pub fn version_name(&self) -> &str {
+        &self.version
+    }
+
+    /// The provider being wrapped.
+    pub fn base(&self) -> &P {
+        &self.base
+    }
+
+    /// The width this provider forces on `codepoint`, if any.
+    fn override_for(&self, codepoint: u32) -> Option<WideCharacterWidth> {
+        self.overrides
+            .get(&codepoint)
+            .copied()
+            .or_else(|| is_ambiguous(codepoint).then_some(self.ambiguous))
+    }
+}
+
+/// Whether a codepoint has an East Asian Width of `A` (and isn't zero
+/// width).
+#[must_use]
+pub fn is_ambiguous(codepoint: u32) -> bool {
+    in_table(tables::AMBIGUOUS, codepoint)
+}
+
+impl<P: XtermUnicodeVersionProvider> XtermUnicodeVersionProvider
+    for AmbiguousWidth<P>
+{
+    fn version(&self) -> Str {
+        self.version.clone()
+    }
+
+    fn wcwidth(&self, codepoint: u32) -> WideCharacterWidth {
+        self.override_for(codepoint)
+            .unwrap_or_else(|| self.base.wcwidth(codepoint))
+    }
+
+    /// Defers to the base provider, swapping in the overridden width.
+    ///
+    /// When an overridden codepoint joins the preceding cluster the cluster
+    /// can only get wider.
+    fn char_properties(&self, codepoint: u32, preceding: u32) -> u32 {
+        let props =
+            CharProperties(self.base.char_properties(codepoint, preceding));
+
+        match self.override_for(codepoint) {
+            None => props.0,
+            Some(width) => {
+                let width = if props.should_join() {
+                    props.width().max(width as u8)
+                } else {
+                    width as u8
+                };
+
+                CharProperties::new(props.state(), width, props.should_join()).0
+            }
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Grapheme cluster breaking ([UAX #29]) in the terms of xterm.js's
+//! [`charProperties`].
+//!
+//! xterm.js asks the active Unicode version provider about each character it
+//! prints, passing along what the provider said about the previous one; the
+//! answers are packed into a `u32` (see [`CharProperties`]). Providers use
+//! this to tell xterm.js which characters belong in the same cell as the
+//! character before them (so that, for example, an emoji ZWJ sequence or a
+//! letter with combining marks occupies a single cluster of cells).
+//!
+//! The rules implemented are those of Unicode 15.0's UAX #29, applied to
+//! Unicode 15.1's `Grapheme_Cluster_Break` values; `GB9c` (Unicode 15.1's rule
+//! for Indic conjuncts, which needs the `Indic_Conjunct_Break` property) isn't
+//! implemented.
+//!
+//! [UAX #29]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules
+//! [`charProperties`]: super::XtermUnicodeVersionProvider::char_properties
+
+use super::{in_table, tables};
+use crate::xterm::WideCharacterWidth;
+
+/// A codepoint's `Grapheme_Cluster_Break` property.
+///
+/// `ExtendedPictographic` isn't actually a value of the property (these
+/// codepoints are `Other`) but the rules treat them specially so it's
+/// convenient to have it here.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GraphemeBreak {
+    #[default]
+    Other = 0,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+impl GraphemeBreak {
+    /// All the values, in discriminant order.
+    const ALL: [Self; 15] = [
+        Self::Other,
+        Self::Cr,
+        Self::Lf,
+        Self::Control,
+        Self::Extend,
+        Self::Zwj,
+        Self::RegionalIndicator,
+        Self::Prepend,
+        Self::SpacingMark,
+        Self::L,
+        Self::V,
+        Self::T,
+        Self::Lv,
+        Self::Lvt,
+        Self::ExtendedPictographic,
+    ];
+
+    /// Looks up the property for a codepoint.
+    #[must_use]
+    pub fn of(codepoint: u32) -> Self {
+        let found =
+            tables::GRAPHEME_BREAK.binary_search_by(|&(low, high, _)| {
+                if high < codepoint {
+                    core::cmp::Ordering::Less
+                } else if low > codepoint {
+                    core::cmp::Ordering::Greater
+                } else {
+                    core::cmp::Ordering::Equal
+                }
+            });
+
+        match found {
+            Ok(idx) => tables::GRAPHEME_BREAK[idx].2,
+            Err(_) if in_table(tables::EXTENDED_PICTOGRAPHIC, codepoint) => {
+                Self::ExtendedPictographic
+            }
+            Err(_) => Self::Other,
+        }
+    }
+
+    /// Inverse of `self as u32`.
+    fn from_bits(bits: u32) -> Self {
+        Self::ALL.get(bits as usize).copied().unwrap_or(Self::Other)
+    }
+}
+
+/// xterm.js's packed character properties (`UnicodeCharProperties`).
+///
+/// Bit 0 says whether the character joins the preceding one, bits 1 and 2
+/// are the width of the character (or of the whole cluster, when joining) and
+/// the remaining 24 bits are state that's private to the provider.
+///
+/// A value of 0 means there's no preceding character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CharProperties(pub u32);
+
+impl CharProperties {
+    /// Packs up some character properties.
+    ///
+    /// Only the low 24 bits of `state` are kept.
+    #[must_use]
+    pub const fn new(state: u32, width: u8, should_join: bool) -> Self {
+        Self(
+            ((state & 0xFF_FFFF) << 3)
+                | ((width as u32 & 0b11) << 1)
+                | should_join as u32,
+        )
+    }
+
+    /// Whether the character joins the preceding one.
+    #[must_use]
+    pub const fn should_join(self) -> bool {
+        self.0 & 1 != 0
+    }
+
+    /// The width of the character (or the cluster it joins).
+    #[must_use]
+    pub const fn width(self) -> u8 {
+        ((self.0 >> 1) & 0b11) as u8
+    }
+
+    /// The provider's state.
+    #[must_use]
+    pub const fn state(self) -> u32 {
+        self.0 >> 3
+    }
+
+    /// What xterm.js does for providers that don't know about graphemes:
+    /// zero width characters join the preceding character, everything else
+    /// starts a new cluster.
+    #[must_use]
+    pub fn without_graphemes(
+        width: WideCharacterWidth,
+        preceding: CharProperties,
+    ) -> Self {
+        let mut width = width as u8;
+        let mut should_join = width == 0 && preceding.0 != 0;
+
+        if should_join {
+            let old_width = preceding.width();
+            if old_width == 0 {
+                should_join = false;
+            } else if old_width > width {
+                width = old_width;
+            }
+        }
+
+        Self::new(0, width, should_join)
+    }
+
+    /// Grapheme cluster aware properties for a codepoint with the given
+    /// width, following the extended grapheme cluster rules of [UAX #29] as
+    /// of Unicode 15.0 (so without `GB9c`; see the [module docs](self)).
+    ///
+    /// Joined clusters take the width of their widest codepoint, except that
+    /// emoji followed by U+FE0F (emoji presentation) and pairs of regional
+    /// indicators (flags) are always two columns wide.
+    ///
+    /// [UAX #29]: https://www.unicode.org/reports/tr29/#Grapheme_Cluster_Boundary_Rules
+    #[must_use]
+    pub fn with_graphemes(
+        codepoint: u32,
+        width: WideCharacterWidth,
+        preceding: CharProperties,
+    ) -> Self {
+        let class = GraphemeBreak::of(codepoint);
+        let mut width = width as u8;
+
+        let prev = (preceding.0 != 0).then(|| State::unpack(preceding.state()));
+        let should_join = prev.is_some_and(|prev| !prev.breaks_before(class));
+
+        // Only the state of the codepoint we're joining matters.
+        let joined = prev.filter(|_| should_join).unwrap_or_default();
+        let state = State {
+            class,
+            emoji: match class {
+                GraphemeBreak::ExtendedPictographic => true,
+                GraphemeBreak::Extend | GraphemeBreak::Zwj => {
+                    joined.emoji && joined.class != GraphemeBreak::Zwj
+                }
+                _ => false,
+            },
+            odd_regional_indicator: class == GraphemeBreak::RegionalIndicator
+                && joined.class != GraphemeBreak::RegionalIndicator,
+        };
+
+        if should_join {
+            width = width.max(preceding.width());
+
+            let emoji_presentation = codepoint == 0xFE0F && joined.emoji;
+            let flag = class == GraphemeBreak::RegionalIndicator;
+            if emoji_presentation || flag {
+                width = 2;
+            }
+        }
+
+        Self::new(state.pack(), width, should_join)
+    }
+}
+
+/// The state we keep between codepoints (in [`CharProperties::state`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct State {
+    /// The previous codepoint's class.
+    class: GraphemeBreak,
+    /// Whether the previous codepoint ended an `ExtendedPictographic Extend*`
+    /// or `ExtendedPictographic Extend* ZWJ` sequence (GB11).
+    emoji: bool,
+    /// Whether the previous codepoint was a regional indicator that doesn't
+    /// already have a partner (GB12 and GB13).
+    odd_regional_indicator: bool,
+}
+
+impl State {
+    /// Packs the state into 6 bits.
+    fn pack(self) -> u32 {
+        (self.class as u32)
+            | (u32::from(self.emoji) << 4)
+            | (u32::from(self.odd_regional_indicator) << 5)
+    }
+
+    /// Inverse of [`State::pack`].
+    fn unpack(bits: u32) -> Self {
+        Self {
+            class: GraphemeBreak::from_bits(bits & 0b1111),
+            emoji: bits & (1 << 4) != 0,
+            odd_regional_indicator: bits & (1 << 5) != 0,
+        }
+    }
+
+    /// Whether there's a grapheme cluster boundary between the previous
+    /// codepoint and one of class `next` (by Unicode 15.0's rules, which
+    /// don't have `GB9c`).
+    fn breaks_before(self, next: GraphemeBreak) -> bool {
+        use GraphemeBreak::{
+            Control, Cr, Extend, ExtendedPictographic, Lf, Lv, Lvt, Prepend,
+            RegionalIndicator, SpacingMark, Zwj, L, T, V,
+        };
+
+        match (self.class, next) {
+            // GB3
+            (Cr, Lf) => false,
+            // GB4, GB5
+            (Control | Cr | Lf, _) | (_, Control | Cr | Lf) => true,
+            // GB6, GB7, GB8, GB9, GB9a, GB9b
+            (L, L | V | Lv | Lvt)
+            | (Lv | V, V | T)
+            | (Lvt | T, T)
+            | (_, Extend | Zwj | SpacingMark)
+            | (Prepend, _) => false,
+            // GB11
+            (Zwj, ExtendedPictographic) => !self.emoji,
+            // GB12, GB13
+            (RegionalIndicator, RegionalIndicator) => {
+                !self.odd_regional_indicator
+            }
+            // GB999
+            _ => true,
+        }
+    }
+}
@@ -0,0 +1,2136 @@
+// Generated by `scripts/unicode.py`; do not edit by hand!
+
+//! Unicode property tables.
+//!
+//! Each table is a sorted list of inclusive codepoint ranges.
+
+use super::graphemes::GraphemeBreak;
+
+/// The version of Unicode these tables were generated from.
+pub(super) const UNICODE_VERSION: (u8, u8, u8) = (15, 1, 0);
+
+/// Zero width codepoints.
+pub(super) const ZERO_WIDTH: &[(u32, u32)] = &[
+    (0x0000, 0x001F),
+    (0x007F, 0x009F),
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x0600, 0x0605),
+    (0x0610, 0x061A),
+    (0x061C, 0x061C),
+    (0x064B, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DD),
+    (0x06DF, 0x06E4),
+    (0x06E7, 0x06E8),
+    (0x06EA, 0x06ED),
+    (0x070F, 0x070F),
+    (0x0711, 0x0711),
+    (0x0730, 0x074A),
+    (0x07A6, 0x07B0),
+    (0x07EB, 0x07F3),
+    (0x07FD, 0x07FD),
+    (0x0816, 0x0819),
+    (0x081B, 0x0823),
+    (0x0825, 0x0827),
+    (0x0829, 0x082D),
+    (0x0859, 0x085B),
+    (0x0890, 0x0891),
+    (0x0898, 0x089F),
+    (0x08CA, 0x0902),
+    (0x093A, 0x093A),
+    (0x093C, 0x093C),
+    (0x0941, 0x0948),
+    (0x094D, 0x094D),
+    (0x0951, 0x0957),
+    (0x0962, 0x0963),
+    (0x0981, 0x0981),
+    (0x09BC, 0x09BC),
+    (0x09C1, 0x09C4),
+    (0x09CD, 0x09CD),
+    (0x09E2, 0x09E3),
+    (0x09FE, 0x09FE),
+    (0x0A01, 0x0A02),
+    (0x0A3C, 0x0A3C),
+    (0x0A41, 0x0A42),
+    (0x0A47, 0x0A48),
+    (0x0A4B, 0x0A4D),
+    (0x0A51, 0x0A51),
+    (0x0A70, 0x0A71),
+    (0x0A75, 0x0A75),
+    (0x0A81, 0x0A82),
+    (0x0ABC, 0x0ABC),
+    (0x0AC1, 0x0AC5),
+    (0x0AC7, 0x0AC8),
+    (0x0ACD, 0x0ACD),
+    (0x0AE2, 0x0AE3),
+    (0x0AFA, 0x0AFF),
+    (0x0B01, 0x0B01),
+    (0x0B3C, 0x0B3C),
+    (0x0B3F, 0x0B3F),
+    (0x0B41, 0x0B44),
+    (0x0B4D, 0x0B4D),
+    (0x0B55, 0x0B56),
+    (0x0B62, 0x0B63),
+    (0x0B82, 0x0B82),
+    (0x0BC0, 0x0BC0),
+    (0x0BCD, 0x0BCD),
+    (0x0C00, 0x0C00),
+    (0x0C04, 0x0C04),
+    (0x0C3C, 0x0C3C),
+    (0x0C3E, 0x0C40),
+    (0x0C46, 0x0C48),
+    (0x0C4A, 0x0C4D),
+    (0x0C55, 0x0C56),
+    (0x0C62, 0x0C63),
+    (0x0C81, 0x0C81),
+    (0x0CBC, 0x0CBC),
+    (0x0CBF, 0x0CBF),
+    (0x0CC6, 0x0CC6),
+    (0x0CCC, 0x0CCD),
+    (0x0CE2, 0x0CE3),
+    (0x0D00, 0x0D01),
+    (0x0D3B, 0x0D3C),
+    (0x0D41, 0x0D44),
+    (0x0D4D, 0x0D4D),
+    (0x0D62, 0x0D63),
+    (0x0D81, 0x0D81),
+    (0x0DCA, 0x0DCA),
+    (0x0DD2, 0x0DD4),
+    (0x0DD6, 0x0DD6),
+    (0x0E31, 0x0E31),
+    (0x0E34, 0x0E3A),
+    (0x0E47, 0x0E4E),
+    (0x0EB1, 0x0EB1),
+    (0x0EB4, 0x0EBC),
+    (0x0EC8, 0x0ECE),
+    (0x0F18, 0x0F19),
+    (0x0F35, 0x0F35),
+    (0x0F37, 0x0F37),
+    (0x0F39, 0x0F39),
+    (0x0F71, 0x0F7E),
+    (0x0F80, 0x0F84),
+    (0x0F86, 0x0F87),
+    (0x0F8D, 0x0F97),
+    (0x0F99, 0x0FBC),
+    (0x0FC6, 0x0FC6),
+    (0x102D, 0x1030),
+    (0x1032, 0x1037),
+    (0x1039, 0x103A),
+    (0x103D, 0x103E),
+    (0x1058, 0x1059),
+    (0x105E, 0x1060),
+    (0x1071, 0x1074),
+    (0x1082, 0x1082),
+    (0x1085, 0x1086),
+    (0x108D, 0x108D),
+    (0x109D, 0x109D),
+    (0x1160, 0x11FF),
+    (0x135D, 0x135F),
+    (0x1712, 0x1714),
+    (0x1732, 0x1733),
+    (0x1752, 0x1753),
+    (0x1772, 0x1773),
+    (0x17B4, 0x17B5),
+    (0x17B7, 0x17BD),
+    (0x17C6, 0x17C6),
+    (0x17C9, 0x17D3),
+    (0x17DD, 0x17DD),
+    (0x180B, 0x180F),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1920, 0x1922),
+    (0x1927, 0x1928),
+    (0x1932, 0x1932),
+    (0x1939, 0x193B),
+    (0x1A17, 0x1A18),
+    (0x1A1B, 0x1A1B),
+    (0x1A56, 0x1A56),
+    (0x1A58, 0x1A5E),
+    (0x1A60, 0x1A60),
+    (0x1A62, 0x1A62),
+    (0x1A65, 0x1A6C),
+    (0x1A73, 0x1A7C),
+    (0x1A7F, 0x1A7F),
+    (0x1AB0, 0x1ACE),
+    (0x1B00, 0x1B03),
+    (0x1B34, 0x1B34),
+    (0x1B36, 0x1B3A),
+    (0x1B3C, 0x1B3C),
+    (0x1B42, 0x1B42),
+    (0x1B6B, 0x1B73),
+    (0x1B80, 0x1B81),
+    (0x1BA2, 0x1BA5),
+    (0x1BA8, 0x1BA9),
+    (0x1BAB, 0x1BAD),
+    (0x1BE6, 0x1BE6),
+    (0x1BE8, 0x1BE9),
+    (0x1BED, 0x1BED),
+    (0x1BEF, 0x1BF1),
+    (0x1C2C, 0x1C33),
+    (0x1C36, 0x1C37),
+    (0x1CD0, 0x1CD2),
+    (0x1CD4, 0x1CE0),
+    (0x1CE2, 0x1CE8),
+    (0x1CED, 0x1CED),
+    (0x1CF4, 0x1CF4),
+    (0x1CF8, 0x1CF9),
+    (0x1DC0, 0x1DFF),
+    (0x200B, 0x200F),
+    (0x202A, 0x202E),
+    (0x2060, 0x2064),
+    (0x2066, 0x206F),
+    (0x20D0, 0x20F0),
+    (0x2CEF, 0x2CF1),
+    (0x2D7F, 0x2D7F),
+    (0x2DE0, 0x2DFF),
+    (0x302A, 0x302D),
+    (0x3099, 0x309A),
+    (0xA66F, 0xA672),
+    (0xA674, 0xA67D),
+    (0xA69E, 0xA69F),
+    (0xA6F0, 0xA6F1),
+    (0xA802, 0xA802),
+    (0xA806, 0xA806),
+    (0xA80B, 0xA80B),
+    (0xA825, 0xA826),
+    (0xA82C, 0xA82C),
+    (0xA8C4, 0xA8C5),
+    (0xA8E0, 0xA8F1),
+    (0xA8FF, 0xA8FF),
+    (0xA926, 0xA92D),
+    (0xA947, 0xA951),
+    (0xA980, 0xA982),
+    (0xA9B3, 0xA9B3),
+    (0xA9B6, 0xA9B9),
+    (0xA9BC, 0xA9BD),
+    (0xA9E5, 0xA9E5),
+    (0xAA29, 0xAA2E),
+    (0xAA31, 0xAA32),
+    (0xAA35, 0xAA36),
+    (0xAA43, 0xAA43),
+    (0xAA4C, 0xAA4C),
+    (0xAA7C, 0xAA7C),
+    (0xAAB0, 0xAAB0),
+    (0xAAB2, 0xAAB4),
+    (0xAAB7, 0xAAB8),
+    (0xAABE, 0xAABF),
+    (0xAAC1, 0xAAC1),
+    (0xAAEC, 0xAAED),
+    (0xAAF6, 0xAAF6),
+    (0xABE5, 0xABE5),
+    (0xABE8, 0xABE8),
+    (0xABED, 0xABED),
+    (0xD7B0, 0xD7FF),
+    (0xFB1E, 0xFB1E),
+    (0xFE00, 0xFE0F),
+    (0xFE20, 0xFE2F),
+    (0xFEFF, 0xFEFF),
+    (0xFFF9, 0xFFFB),
+    (0x101FD, 0x101FD),
+    (0x102E0, 0x102E0),
+    (0x10376, 0x1037A),
+    (0x10A01, 0x10A03),
+    (0x10A05, 0x10A06),
+    (0x10A0C, 0x10A0F),
+    (0x10A38, 0x10A3A),
+    (0x10A3F, 0x10A3F),
+    (0x10AE5, 0x10AE6),
+    (0x10D24, 0x10D27),
+    (0x10EAB, 0x10EAC),
+    (0x10EFD, 0x10EFF),
+    (0x10F46, 0x10F50),
+    (0x10F82, 0x10F85),
+    (0x11001, 0x11001),
+    (0x11038, 0x11046),
+    (0x11070, 0x11070),
+    (0x11073, 0x11074),
+    (0x1107F, 0x11081),
+    (0x110B3, 0x110B6),
+    (0x110B9, 0x110BA),
+    (0x110BD, 0x110BD),
+    (0x110C2, 0x110C2),
+    (0x110CD, 0x110CD),
+    (0x11100, 0x11102),
+    (0x11127, 0x1112B),
+    (0x1112D, 0x11134),
+    (0x11173, 0x11173),
+    (0x11180, 0x11181),
+    (0x111B6, 0x111BE),
+    (0x111C9, 0x111CC),
+    (0x111CF, 0x111CF),
+    (0x1122F, 0x11231),
+    (0x11234, 0x11234),
+    (0x11236, 0x11237),
+    (0x1123E, 0x1123E),
+    (0x11241, 0x11241),
+    (0x112DF, 0x112DF),
+    (0x112E3, 0x112EA),
+    (0x11300, 0x11301),
+    (0x1133B, 0x1133C),
+    (0x11340, 0x11340),
+    (0x11366, 0x1136C),
+    (0x11370, 0x11374),
+    (0x11438, 0x1143F),
+    (0x11442, 0x11444),
+    (0x11446, 0x11446),
+    (0x1145E, 0x1145E),
+    (0x114B3, 0x114B8),
+    (0x114BA, 0x114BA),
+    (0x114BF, 0x114C0),
+    (0x114C2, 0x114C3),
+    (0x115B2, 0x115B5),
+    (0x115BC, 0x115BD),
+    (0x115BF, 0x115C0),
+    (0x115DC, 0x115DD),
+    (0x11633, 0x1163A),
+    (0x1163D, 0x1163D),
+    (0x1163F, 0x11640),
+    (0x116AB, 0x116AB),
+    (0x116AD, 0x116AD),
+    (0x116B0, 0x116B5),
+    (0x116B7, 0x116B7),
+    (0x1171D, 0x1171F),
+    (0x11722, 0x11725),
+    (0x11727, 0x1172B),
+    (0x1182F, 0x11837),
+    (0x11839, 0x1183A),
+    (0x1193B, 0x1193C),
+    (0x1193E, 0x1193E),
+    (0x11943, 0x11943),
+    (0x119D4, 0x119D7),
+    (0x119DA, 0x119DB),
+    (0x119E0, 0x119E0),
+    (0x11A01, 0x11A0A),
+    (0x11A33, 0x11A38),
+    (0x11A3B, 0x11A3E),
+    (0x11A47, 0x11A47),
+    (0x11A51, 0x11A56),
+    (0x11A59, 0x11A5B),
+    (0x11A8A, 0x11A96),
+    (0x11A98, 0x11A99),
+    (0x11C30, 0x11C36),
+    (0x11C38, 0x11C3D),
+    (0x11C3F, 0x11C3F),
+    (0x11C92, 0x11CA7),
+    (0x11CAA, 0x11CB0),
+    (0x11CB2, 0x11CB3),
+    (0x11CB5, 0x11CB6),
+    (0x11D31, 0x11D36),
+    (0x11D3A, 0x11D3A),
+    (0x11D3C, 0x11D3D),
+    (0x11D3F, 0x11D45),
+    (0x11D47, 0x11D47),
+    (0x11D90, 0x11D91),
+    (0x11D95, 0x11D95),
+    (0x11D97, 0x11D97),
+    (0x11EF3, 0x11EF4),
+    (0x11F00, 0x11F01),
+    (0x11F36, 0x11F3A),
+    (0x11F40, 0x11F40),
+    (0x11F42, 0x11F42),
+    (0x13430, 0x13440),
+    (0x13447, 0x13455),
+    (0x16AF0, 0x16AF4),
+    (0x16B30, 0x16B36),
+    (0x16F4F, 0x16F4F),
+    (0x16F8F, 0x16F92),
+    (0x16FE4, 0x16FE4),
+    (0x1BC9D, 0x1BC9E),
+    (0x1BCA0, 0x1BCA3),
+    (0x1CF00, 0x1CF2D),
+    (0x1CF30, 0x1CF46),
+    (0x1D167, 0x1D169),
+    (0x1D173, 0x1D182),
+    (0x1D185, 0x1D18B),
+    (0x1D1AA, 0x1D1AD),
+    (0x1D242, 0x1D244),
+    (0x1DA00, 0x1DA36),
+    (0x1DA3B, 0x1DA6C),
+    (0x1DA75, 0x1DA75),
+    (0x1DA84, 0x1DA84),
+    (0x1DA9B, 0x1DA9F),
+    (0x1DAA1, 0x1DAAF),
+    (0x1E000, 0x1E006),
+    (0x1E008, 0x1E018),
+    (0x1E01B, 0x1E021),
+    (0x1E023, 0x1E024),
+    (0x1E026, 0x1E02A),
+    (0x1E08F, 0x1E08F),
+    (0x1E130, 0x1E136),
+    (0x1E2AE, 0x1E2AE),
+    (0x1E2EC, 0x1E2EF),
+    (0x1E4EC, 0x1E4EF),
+    (0x1E8D0, 0x1E8D6),
+    (0x1E944, 0x1E94A),
+    (0xE0001, 0xE0001),
+    (0xE0020, 0xE007F),
+    (0xE0100, 0xE01EF),
+];
+
+/// East Asian Width `W` and `F` codepoints.
+pub(super) const WIDE: &[(u32, u32)] = &[
+    (0x1100, 0x115F),
+    (0x231A, 0x231B),
+    (0x2329, 0x232A),
+    (0x23E9, 0x23EC),
+    (0x23F0, 0x23F0),
+    (0x23F3, 0x23F3),
+    (0x25FD, 0x25FE),
+    (0x2614, 0x2615),
+    (0x2648, 0x2653),
+    (0x267F, 0x267F),
+    (0x2693, 0x2693),
+    (0x26A1, 0x26A1),
+    (0x26AA, 0x26AB),
+    (0x26BD, 0x26BE),
+    (0x26C4, 0x26C5),
+    (0x26CE, 0x26CE),
+    (0x26D4, 0x26D4),
+    (0x26EA, 0x26EA),
+    (0x26F2, 0x26F3),
+    (0x26F5, 0x26F5),
+    (0x26FA, 0x26FA),
+    (0x26FD, 0x26FD),
+    (0x2705, 0x2705),
+    (0x270A, 0x270B),
+    (0x2728, 0x2728),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2795, 0x2797),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x2E80, 0x2E99),
+    (0x2E9B, 0x2EF3),
+    (0x2F00, 0x2FD5),
+    (0x2FF0, 0x3029),
+    (0x302E, 0x303E),
+    (0x3041, 0x3096),
+    (0x309B, 0x30FF),
+    (0x3105, 0x312F),
+    (0x3131, 0x318E),
+    (0x3190, 0x31E3),
+    (0x31EF, 0x321E),
+    (0x3220, 0x3247),
+    (0x3250, 0x4DBF),
+    (0x4E00, 0xA48C),
+    (0xA490, 0xA4C6),
+    (0xA960, 0xA97C),
+    (0xAC00, 0xD7A3),
+    (0xF900, 0xFAFF),
+    (0xFE10, 0xFE19),
+    (0xFE30, 0xFE52),
+    (0xFE54, 0xFE66),
+    (0xFE68, 0xFE6B),
+    (0xFF01, 0xFF60),
+    (0xFFE0, 0xFFE6),
+    (0x16FE0, 0x16FE3),
+    (0x16FF0, 0x16FF1),
+    (0x17000, 0x187F7),
+    (0x18800, 0x18CD5),
+    (0x18D00, 0x18D08),
+    (0x1AFF0, 0x1AFF3),
+    (0x1AFF5, 0x1AFFB),
+    (0x1AFFD, 0x1AFFE),
+    (0x1B000, 0x1B122),
+    (0x1B132, 0x1B132),
+    (0x1B150, 0x1B152),
+    (0x1B155, 0x1B155),
+    (0x1B164, 0x1B167),
+    (0x1B170, 0x1B2FB),
+    (0x1F004, 0x1F004),
+    (0x1F0CF, 0x1F0CF),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F200, 0x1F202),
+    (0x1F210, 0x1F23B),
+    (0x1F240, 0x1F248),
+    (0x1F250, 0x1F251),
+    (0x1F260, 0x1F265),
+    (0x1F300, 0x1F320),
+    (0x1F32D, 0x1F335),
+    (0x1F337, 0x1F37C),
+    (0x1F37E, 0x1F393),
+    (0x1F3A0, 0x1F3CA),
+    (0x1F3CF, 0x1F3D3),
+    (0x1F3E0, 0x1F3F0),
+    (0x1F3F4, 0x1F3F4),
+    (0x1F3F8, 0x1F43E),
+    (0x1F440, 0x1F440),
+    (0x1F442, 0x1F4FC),
+    (0x1F4FF, 0x1F53D),
+    (0x1F54B, 0x1F54E),
+    (0x1F550, 0x1F567),
+    (0x1F57A, 0x1F57A),
+    (0x1F595, 0x1F596),
+    (0x1F5A4, 0x1F5A4),
+    (0x1F5FB, 0x1F64F),
+    (0x1F680, 0x1F6C5),
+    (0x1F6CC, 0x1F6CC),
+    (0x1F6D0, 0x1F6D2),
+    (0x1F6D5, 0x1F6D7),
+    (0x1F6DC, 0x1F6DF),
+    (0x1F6EB, 0x1F6EC),
+    (0x1F6F4, 0x1F6FC),
+    (0x1F7E0, 0x1F7EB),
+    (0x1F7F0, 0x1F7F0),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1F9FF),
+    (0x1FA70, 0x1FA7C),
+    (0x1FA80, 0x1FA88),
+    (0x1FA90, 0x1FABD),
+    (0x1FABF, 0x1FAC5),
+    (0x1FACE, 0x1FADB),
+    (0x1FAE0, 0x1FAE8),
+    (0x1FAF0, 0x1FAF8),
+    (0x20000, 0x2FFFD),
+    (0x30000, 0x3FFFD),
+];
+
+/// East Asian Width `A` codepoints.
+pub(super) const AMBIGUOUS: &[(u32, u32)] = &[
+    (0x00A1, 0x00A1),
+    (0x00A4, 0x00A4),
+    (0x00A7, 0x00A8),
+    (0x00AA, 0x00AA),
+    (0x00AE, 0x00AE),
+    (0x00B0, 0x00B4),
+    (0x00B6, 0x00BA),
+    (0x00BC, 0x00BF),
+    (0x00C6, 0x00C6),
+    (0x00D0, 0x00D0),
+    (0x00D7, 0x00D8),
+    (0x00DE, 0x00E1),
+    (0x00E6, 0x00E6),
+    (0x00E8, 0x00EA),
+    (0x00EC, 0x00ED),
+    (0x00F0, 0x00F0),
+    (0x00F2, 0x00F3),
+    (0x00F7, 0x00FA),
+    (0x00FC, 0x00FC),
+    (0x00FE, 0x00FE),
+    (0x0101, 0x0101),
+    (0x0111, 0x0111),
+    (0x0113, 0x0113),
+    (0x011B, 0x011B),
+    (0x0126, 0x0127),
+    (0x012B, 0x012B),
+    (0x0131, 0x0133),
+    (0x0138, 0x0138),
+    (0x013F, 0x0142),
+    (0x0144, 0x0144),
+    (0x0148, 0x014B),
+    (0x014D, 0x014D),
+    (0x0152, 0x0153),
+    (0x0166, 0x0167),
+    (0x016B, 0x016B),
+    (0x01CE, 0x01CE),
+    (0x01D0, 0x01D0),
+    (0x01D2, 0x01D2),
+    (0x01D4, 0x01D4),
+    (0x01D6, 0x01D6),
+    (0x01D8, 0x01D8),
+    (0x01DA, 0x01DA),
+    (0x01DC, 0x01DC),
+    (0x0251, 0x0251),
+    (0x0261, 0x0261),
+    (0x02C4, 0x02C4),
+    (0x02C7, 0x02C7),
+    (0x02C9, 0x02CB),
+    (0x02CD, 0x02CD),
+    (0x02D0, 0x02D0),
+    (0x02D8, 0x02DB),
+    (0x02DD, 0x02DD),
+    (0x02DF, 0x02DF),
+    (0x0391, 0x03A1),
+    (0x03A3, 0x03A9),
+    (0x03B1, 0x03C1),
+    (0x03C3, 0x03C9),
+    (0x0401, 0x0401),
+    (0x0410, 0x044F),
+    (0x0451, 0x0451),
+    (0x2010, 0x2010),
+    (0x2013, 0x2016),
+    (0x2018, 0x2019),
+    (0x201C, 0x201D),
+    (0x2020, 0x2022),
+    (0x2024, 0x2027),
+    (0x2030, 0x2030),
+    (0x2032, 0x2033),
+    (0x2035, 0x2035),
+    (0x203B, 0x203B),
+    (0x203E, 0x203E),
+    (0x2074, 0x2074),
+    (0x207F, 0x207F),
+    (0x2081, 0x2084),
+    (0x20AC, 0x20AC),
+    (0x2103, 0x2103),
+    (0x2105, 0x2105),
+    (0x2109, 0x2109),
+    (0x2113, 0x2113),
+    (0x2116, 0x2116),
+    (0x2121, 0x2122),
+    (0x2126, 0x2126),
+    (0x212B, 0x212B),
+    (0x2153, 0x2154),
+    (0x215B, 0x215E),
+    (0x2160, 0x216B),
+    (0x2170, 0x2179),
+    (0x2189, 0x2189),
+    (0x2190, 0x2199),
+    (0x21B8, 0x21B9),
+    (0x21D2, 0x21D2),
+    (0x21D4, 0x21D4),
+    (0x21E7, 0x21E7),
+    (0x2200, 0x2200),
+    (0x2202, 0x2203),
+    (0x2207, 0x2208),
+    (0x220B, 0x220B),
+    (0x220F, 0x220F),
+    (0x2211, 0x2211),
+    (0x2215, 0x2215),
+    (0x221A, 0x221A),
+    (0x221D, 0x2220),
+    (0x2223, 0x2223),
+    (0x2225, 0x2225),
+    (0x2227, 0x222C),
+    (0x222E, 0x222E),
+    (0x2234, 0x2237),
+    (0x223C, 0x223D),
+    (0x2248, 0x2248),
+    (0x224C, 0x224C),
+    (0x2252, 0x2252),
+    (0x2260, 0x2261),
+    (0x2264, 0x2267),
+    (0x226A, 0x226B),
+    (0x226E, 0x226F),
+    (0x2282, 0x2283),
+    (0x2286, 0x2287),
+    (0x2295, 0x2295),
+    (0x2299, 0x2299),
+    (0x22A5, 0x22A5),
+    (0x22BF, 0x22BF),
+    (0x2312, 0x2312),
+    (0x2460, 0x24E9),
+    (0x24EB, 0x254B),
+    (0x2550, 0x2573),
+    (0x2580, 0x258F),
+    (0x2592, 0x2595),
+    (0x25A0, 0x25A1),
+    (0x25A3, 0x25A9),
+    (0x25B2, 0x25B3),
+    (0x25B6, 0x25B7),
+    (0x25BC, 0x25BD),
+    (0x25C0, 0x25C1),
+    (0x25C6, 0x25C8),
+    (0x25CB, 0x25CB),
+    (0x25CE, 0x25D1),
+    (0x25E2, 0x25E5),
+    (0x25EF, 0x25EF),
+    (0x2605, 0x2606),
+    (0x2609, 0x2609),
+    (0x260E, 0x260F),
+    (0x261C, 0x261C),
+    (0x261E, 0x261E),
+    (0x2640, 0x2640),
+    (0x2642, 0x2642),
+    (0x2660, 0x2661),
+    (0x2663, 0x2665),
+    (0x2667, 0x266A),
+    (0x266C, 0x266D),
+    (0x266F, 0x266F),
+    (0x269E, 0x269F),
+    (0x26BF, 0x26BF),
+    (0x26C6, 0x26CD),
+    (0x26CF, 0x26D3),
+    (0x26D5, 0x26E1),
+    (0x26E3, 0x26E3),
+    (0x26E8, 0x26E9),
+    (0x26EB, 0x26F1),
+    (0x26F4, 0x26F4),
+    (0x26F6, 0x26F9),
+    (0x26FB, 0x26FC),
+    (0x26FE, 0x26FF),
+    (0x273D, 0x273D),
+    (0x2776, 0x277F),
+    (0x2B56, 0x2B59),
+    (0x3248, 0x324F),
+    (0xE000, 0xF8FF),
+    (0xFFFD, 0xFFFD),
+    (0x1F100, 0x1F10A),
+    (0x1F110, 0x1F12D),
+    (0x1F130, 0x1F169),
+    (0x1F170, 0x1F18D),
+    (0x1F18F, 0x1F190),
+    (0x1F19B, 0x1F1AC),
+    (0xF0000, 0xFFFFD),
+    (0x0010_0000, 0x0010_FFFD),
+];
+
+/// `Extended_Pictographic` codepoints (emoji and friends).
+pub(super) const EXTENDED_PICTOGRAPHIC: &[(u32, u32)] = &[
+    (0x00A9, 0x00A9),
+    (0x00AE, 0x00AE),
+    (0x203C, 0x203C),
+    (0x2049, 0x2049),
+    (0x2122, 0x2122),
+    (0x2139, 0x2139),
+    (0x2194, 0x2199),
+    (0x21A9, 0x21AA),
+    (0x231A, 0x231B),
+    (0x2328, 0x2328),
+    (0x2388, 0x2388),
+    (0x23CF, 0x23CF),
+    (0x23E9, 0x23F3),
+    (0x23F8, 0x23FA),
+    (0x24C2, 0x24C2),
+    (0x25AA, 0x25AB),
+    (0x25B6, 0x25B6),
+    (0x25C0, 0x25C0),
+    (0x25FB, 0x25FE),
+    (0x2600, 0x2605),
+    (0x2607, 0x2612),
+    (0x2614, 0x2685),
+    (0x2690, 0x2705),
+    (0x2708, 0x2712),
+    (0x2714, 0x2714),
+    (0x2716, 0x2716),
+    (0x271D, 0x271D),
+    (0x2721, 0x2721),
+    (0x2728, 0x2728),
+    (0x2733, 0x2734),
+    (0x2744, 0x2744),
+    (0x2747, 0x2747),
+    (0x274C, 0x274C),
+    (0x274E, 0x274E),
+    (0x2753, 0x2755),
+    (0x2757, 0x2757),
+    (0x2763, 0x2767),
+    (0x2795, 0x2797),
+    (0x27A1, 0x27A1),
+    (0x27B0, 0x27B0),
+    (0x27BF, 0x27BF),
+    (0x2934, 0x2935),
+    (0x2B05, 0x2B07),
+    (0x2B1B, 0x2B1C),
+    (0x2B50, 0x2B50),
+    (0x2B55, 0x2B55),
+    (0x3030, 0x3030),
+    (0x303D, 0x303D),
+    (0x3297, 0x3297),
+    (0x3299, 0x3299),
+    (0x1F000, 0x1F0FF),
+    (0x1F10D, 0x1F10F),
+    (0x1F12F, 0x1F12F),
+    (0x1F16C, 0x1F171),
+    (0x1F17E, 0x1F17F),
+    (0x1F18E, 0x1F18E),
+    (0x1F191, 0x1F19A),
+    (0x1F1AD, 0x1F1E5),
+    (0x1F201, 0x1F20F),
+    (0x1F21A, 0x1F21A),
+    (0x1F22F, 0x1F22F),
+    (0x1F232, 0x1F23A),
+    (0x1F23C, 0x1F23F),
+    (0x1F249, 0x1F3FA),
+    (0x1F400, 0x1F53D),
+    (0x1F546, 0x1F64F),
+    (0x1F680, 0x1F6FF),
+    (0x1F774, 0x1F77F),
+    (0x1F7D5, 0x1F7FF),
+    (0x1F80C, 0x1F80F),
+    (0x1F848, 0x1F84F),
+    (0x1F85A, 0x1F85F),
+    (0x1F888, 0x1F88F),
+    (0x1F8AE, 0x1F8FF),
+    (0x1F90C, 0x1F93A),
+    (0x1F93C, 0x1F945),
+    (0x1F947, 0x1FAFF),
+    (0x1FC00, 0x1FFFD),
+];
+
+/// `Grapheme_Cluster_Break` values, other than `Other`.
+pub(super) const GRAPHEME_BREAK: &[(u32, u32, GraphemeBreak)] = &[
+    (0x0000, 0x0009, GraphemeBreak::Control),
+    (0x000A, 0x000A, GraphemeBreak::Lf),
+    (0x000B, 0x000C, GraphemeBreak::Control),
+    (0x000D, 0x000D, GraphemeBreak::Cr),
+    (0x000E, 0x001F, GraphemeBreak::Control),
+    (0x007F, 0x009F, GraphemeBreak::Control),
+    (0x00AD, 0x00AD, GraphemeBreak::Control),
+    (0x0300, 0x036F, GraphemeBreak::Extend),
+    (0x0483, 0x0489, GraphemeBreak::Extend),
+    (0x0591, 0x05BD, GraphemeBreak::Extend),
+    (0x05BF, 0x05BF, GraphemeBreak::Extend),
+    (0x05C1, 0x05C2, GraphemeBreak::Extend),
+    (0x05C4, 0x05C5, GraphemeBreak::Extend),
+    (0x05C7, 0x05C7, GraphemeBreak::Extend),
+    (0x0600, 0x0605, GraphemeBreak::Prepend),
+    (0x0610, 0x061A, GraphemeBreak::Extend),
+    (0x061C, 0x061C, GraphemeBreak::Control),
+    (0x064B, 0x065F, GraphemeBreak::Extend),
+    (0x0670, 0x0670, GraphemeBreak::Extend),
+    (0x06D6, 0x06DC, GraphemeBreak::Extend),
+    (0x06DD, 0x06DD, GraphemeBreak::Prepend),
+    (0x06DF, 0x06E4, GraphemeBreak::Extend),
+    (0x06E7, 0x06E8, GraphemeBreak::Extend),
+    (0x06EA, 0x06ED, GraphemeBreak::Extend),
+    (0x070F, 0x070F, GraphemeBreak::Prepend),
+    (0x0711, 0x0711, GraphemeBreak::Extend),
+    (0x0730, 0x074A, GraphemeBreak::Extend),
+    (0x07A6, 0x07B0, GraphemeBreak::Extend),
+    (0x07EB, 0x07F3, GraphemeBreak::Extend),
+    (0x07FD, 0x07FD, GraphemeBreak::Extend),
+    (0x0816, 0x0819, GraphemeBreak::Extend),
+    (0x081B, 0x0823, GraphemeBreak::Extend),
+    (0x0825, 0x0827, GraphemeBreak::Extend),
+    (0x0829, 0x082D, GraphemeBreak::Extend),
+    (0x0859, 0x085B, GraphemeBreak::Extend),
+    (0x0890, 0x0891, GraphemeBreak::Prepend),
+    (0x0898, 0x089F, GraphemeBreak::Extend),
+    (0x08CA, 0x08E1, GraphemeBreak::Extend),
+    (0x08E2, 0x08E2, GraphemeBreak::Prepend),
+    (0x08E3, 0x0902, GraphemeBreak::Extend),
+    (0x0903, 0x0903, GraphemeBreak::SpacingMark),
+    (0x093A, 0x093A, GraphemeBreak::Extend),
+    (0x093B, 0x093B, GraphemeBreak::SpacingMark),
+    (0x093C, 0x093C, GraphemeBreak::Extend),
+    (0x093E, 0x0940, GraphemeBreak::SpacingMark),
+    (0x0941, 0x0948, GraphemeBreak::Extend),
+    (0x0949, 0x094C, GraphemeBreak::SpacingMark),
+    (0x094D, 0x094D, GraphemeBreak::Extend),
+    (0x094E, 0x094F, GraphemeBreak::SpacingMark),
+    (0x0951, 0x0957, GraphemeBreak::Extend),
+    (0x0962, 0x0963, GraphemeBreak::Extend),
+    (0x0981, 0x0981, GraphemeBreak::Extend),
+    (0x0982, 0x0983, GraphemeBreak::SpacingMark),
+    (0x09BC, 0x09BC, GraphemeBreak::Extend),
+    (0x09BE, 0x09BE, GraphemeBreak::Extend),
+    (0x09BF, 0x09C0, GraphemeBreak::SpacingMark),
+    (0x09C1, 0x09C4, GraphemeBreak::Extend),
+    (0x09C7, 0x09C8, GraphemeBreak::SpacingMark),
+    (0x09CB, 0x09CC, GraphemeBreak::SpacingMark),
+    (0x09CD, 0x09CD, GraphemeBreak::Extend),
+    (0x09D7, 0x09D7, GraphemeBreak::Extend),
+    (0x09E2, 0x09E3, GraphemeBreak::Extend),
+    (0x09FE, 0x09FE, GraphemeBreak::Extend),
+    (0x0A01, 0x0A02, GraphemeBreak::Extend),
+    (0x0A03, 0x0A03, GraphemeBreak::SpacingMark),
+    (0x0A3C, 0x0A3C, GraphemeBreak::Extend),
+    (0x0A3E, 0x0A40, GraphemeBreak::SpacingMark),
+    (0x0A41, 0x0A42, GraphemeBreak::Extend),
+    (0x0A47, 0x0A48, GraphemeBreak::Extend),
+    (0x0A4B, 0x0A4D, GraphemeBreak::Extend),
+    (0x0A51, 0x0A51, GraphemeBreak::Extend),
+    (0x0A70, 0x0A71, GraphemeBreak::Extend),
+    (0x0A75, 0x0A75, GraphemeBreak::Extend),
+    (0x0A81, 0x0A82, GraphemeBreak::Extend),
+    (0x0A83, 0x0A83, GraphemeBreak::SpacingMark),
+    (0x0ABC, 0x0ABC, GraphemeBreak::Extend),
+    (0x0ABE, 0x0AC0, GraphemeBreak::SpacingMark),
+    (0x0AC1, 0x0AC5, GraphemeBreak::Extend),
+    (0x0AC7, 0x0AC8, GraphemeBreak::Extend),
+    (0x0AC9, 0x0AC9, GraphemeBreak::SpacingMark),
+    (0x0ACB, 0x0ACC, GraphemeBreak::SpacingMark),
+    (0x0ACD, 0x0ACD, GraphemeBreak::Extend),
+    (0x0AE2, 0x0AE3, GraphemeBreak::Extend),
+    (0x0AFA, 0x0AFF, GraphemeBreak::Extend),
+    (0x0B01, 0x0B01, GraphemeBreak::Extend),
+    (0x0B02, 0x0B03, GraphemeBreak::SpacingMark),
+    (0x0B3C, 0x0B3C, GraphemeBreak::Extend),
+    (0x0B3E, 0x0B3F, GraphemeBreak::Extend),
+    (0x0B40, 0x0B40, GraphemeBreak::SpacingMark),
+    (0x0B41, 0x0B44, GraphemeBreak::Extend),
+    (0x0B47, 0x0B48, GraphemeBreak::SpacingMark),
+    (0x0B4B, 0x0B4C, GraphemeBreak::SpacingMark),
+    (0x0B4D, 0x0B4D, GraphemeBreak::Extend),
+    (0x0B55, 0x0B57, GraphemeBreak::Extend),
+    (0x0B62, 0x0B63, GraphemeBreak::Extend),
+    (0x0B82, 0x0B82, GraphemeBreak::Extend),
+    (0x0BBE, 0x0BBE, GraphemeBreak::Extend),
+    (0x0BBF, 0x0BBF, GraphemeBreak::SpacingMark),
+    (0x0BC0, 0x0BC0, GraphemeBreak::Extend),
+    (0x0BC1, 0x0BC2, GraphemeBreak::SpacingMark),
+    (0x0BC6, 0x0BC8, GraphemeBreak::SpacingMark),
+    (0x0BCA, 0x0BCC, GraphemeBreak::SpacingMark),
+    (0x0BCD, 0x0BCD, GraphemeBreak::Extend),
+    (0x0BD7, 0x0BD7, GraphemeBreak::Extend),
+    (0x0C00, 0x0C00, GraphemeBreak::Extend),
+    (0x0C01, 0x0C03, GraphemeBreak::SpacingMark),
+    (0x0C04, 0x0C04, GraphemeBreak::Extend),
+    (0x0C3C, 0x0C3C, GraphemeBreak::Extend),
+    (0x0C3E, 0x0C40, GraphemeBreak::Extend),
+    (0x0C41, 0x0C44, GraphemeBreak::SpacingMark),
+    (0x0C46, 0x0C48, GraphemeBreak::Extend),
+    (0x0C4A, 0x0C4D, GraphemeBreak::Extend),
+    (0x0C55, 0x0C56, GraphemeBreak::Extend),
+    (0x0C62, 0x0C63, GraphemeBreak::Extend),
+    (0x0C81, 0x0C81, GraphemeBreak::Extend),
+    (0x0C82, 0x0C83, GraphemeBreak::SpacingMark),
+    (0x0CBC, 0x0CBC, GraphemeBreak::Extend),
+    (0x0CBE, 0x0CBE, GraphemeBreak::SpacingMark),
+    (0x0CBF, 0x0CBF, GraphemeBreak::Extend),
+    (0x0CC0, 0x0CC1, GraphemeBreak::SpacingMark),
+    (0x0CC2, 0x0CC2, GraphemeBreak::Extend),
+    (0x0CC3, 0x0CC4, GraphemeBreak::SpacingMark),
+    (0x0CC6, 0x0CC6, GraphemeBreak::Extend),
+    (0x0CC7, 0x0CC8, GraphemeBreak::SpacingMark),
+    (0x0CCA, 0x0CCB, GraphemeBreak::SpacingMark),
+    (0x0CCC, 0x0CCD, GraphemeBreak::Extend),
+    (0x0CD5, 0x0CD6, GraphemeBreak::Extend),
+    (0x0CE2, 0x0CE3, GraphemeBreak::Extend),
+    (0x0CF3, 0x0CF3, GraphemeBreak::SpacingMark),
+    (0x0D00, 0x0D01, GraphemeBreak::Extend),
+    (0x0D02, 0x0D03, GraphemeBreak::SpacingMark),
+    (0x0D3B, 0x0D3C, GraphemeBreak::Extend),
+    (0x0D3E, 0x0D3E, GraphemeBreak::Extend),
+    (0x0D3F, 0x0D40, GraphemeBreak::SpacingMark),
+    (0x0D41, 0x0D44, GraphemeBreak::Extend),
+    (0x0D46, 0x0D48, GraphemeBreak::SpacingMark),
+    (0x0D4A, 0x0D4C, GraphemeBreak::SpacingMark),
+    (0x0D4D, 0x0D4D, GraphemeBreak::Extend),
+    (0x0D4E, 0x0D4E, GraphemeBreak::Prepend),
+    (0x0D57, 0x0D57, GraphemeBreak::Extend),
+    (0x0D62, 0x0D63, GraphemeBreak::Extend),
+    (0x0D81, 0x0D81, GraphemeBreak::Extend),
+    (0x0D82, 0x0D83, GraphemeBreak::SpacingMark),
+    (0x0DCA, 0x0DCA, GraphemeBreak::Extend),
+    (0x0DCF, 0x0DCF, GraphemeBreak::Extend),
+    (0x0DD0, 0x0DD1, GraphemeBreak::SpacingMark),
+    (0x0DD2, 0x0DD4, GraphemeBreak::Extend),
+    (0x0DD6, 0x0DD6, GraphemeBreak::Extend),
+    (0x0DD8, 0x0DDE, GraphemeBreak::SpacingMark),
+    (0x0DDF, 0x0DDF, GraphemeBreak::Extend),
+    (0x0DF2, 0x0DF3, GraphemeBreak::SpacingMark),
+    (0x0E31, 0x0E31, GraphemeBreak::Extend),
+    (0x0E33, 0x0E33, GraphemeBreak::SpacingMark),
+    (0x0E34, 0x0E3A, GraphemeBreak::Extend),
+    (0x0E47, 0x0E4E, GraphemeBreak::Extend),
+    (0x0EB1, 0x0EB1, GraphemeBreak::Extend),
+    (0x0EB3, 0x0EB3, GraphemeBreak::SpacingMark),
+    (0x0EB4, 0x0EBC, GraphemeBreak::Extend),
+    (0x0EC8, 0x0ECE, GraphemeBreak::Extend),
+    (0x0F18, 0x0F19, GraphemeBreak::Extend),
+    (0x0F35, 0x0F35, GraphemeBreak::Extend),
+    (0x0F37, 0x0F37, GraphemeBreak::Extend),
+    (0x0F39, 0x0F39, GraphemeBreak::Extend),
+    (0x0F3E, 0x0F3F, GraphemeBreak::SpacingMark),
+    (0x0F71, 0x0F7E, GraphemeBreak::Extend),
+    (0x0F7F, 0x0F7F, GraphemeBreak::SpacingMark),
+    (0x0F80, 0x0F84, GraphemeBreak::Extend),
+    (0x0F86, 0x0F87, GraphemeBreak::Extend),
+    (0x0F8D, 0x0F97, GraphemeBreak::Extend),
+    (0x0F99, 0x0FBC, GraphemeBreak::Extend),
+    (0x0FC6, 0x0FC6, GraphemeBreak::Extend),
+    (0x102D, 0x1030, GraphemeBreak::Extend),
+    (0x1031, 0x1031, GraphemeBreak::SpacingMark),
+    (0x1032, 0x1037, GraphemeBreak::Extend),
+    (0x1039, 0x103A, GraphemeBreak::Extend),
+    (0x103B, 0x103C, GraphemeBreak::SpacingMark),
+    (0x103D, 0x103E, GraphemeBreak::Extend),
+    (0x1056, 0x1057, GraphemeBreak::SpacingMark),
+    (0x1058, 0x1059, GraphemeBreak::Extend),
+    (0x105E, 0x1060, GraphemeBreak::Extend),
+    (0x1071, 0x1074, GraphemeBreak::Extend),
+    (0x1082, 0x1082, GraphemeBreak::Extend),
+    (0x1084, 0x1084, GraphemeBreak::SpacingMark),
+    (0x1085, 0x1086, GraphemeBreak::Extend),
+    (0x108D, 0x108D, GraphemeBreak::Extend),
+    (0x109D, 0x109D, GraphemeBreak::Extend),
+    (0x1100, 0x115F, GraphemeBreak::L),
+    (0x1160, 0x11A7, GraphemeBreak::V),
+    (0x11A8, 0x11FF, GraphemeBreak::T),
+    (0x135D, 0x135F, GraphemeBreak::Extend),
+    (0x1712, 0x1714, GraphemeBreak::Extend),
+    (0x1715, 0x1715, GraphemeBreak::SpacingMark),
+    (0x1732, 0x1733, GraphemeBreak::Extend),
+    (0x1734, 0x1734, GraphemeBreak::SpacingMark),
+    (0x1752, 0x1753, GraphemeBreak::Extend),
+    (0x1772, 0x1773, GraphemeBreak::Extend),
+    (0x17B4, 0x17B5, GraphemeBreak::Extend),
+    (0x17B6, 0x17B6, GraphemeBreak::SpacingMark),
+    (0x17B7, 0x17BD, GraphemeBreak::Extend),
+    (0x17BE, 0x17C5, GraphemeBreak::SpacingMark),
+    (0x17C6, 0x17C6, GraphemeBreak::Extend),
+    (0x17C7, 0x17C8, GraphemeBreak::SpacingMark),
+    (0x17C9, 0x17D3, GraphemeBreak::Extend),
+    (0x17DD, 0x17DD, GraphemeBreak::Extend),
+    (0x180B, 0x180D, GraphemeBreak::Extend),
+    (0x180E, 0x180E, GraphemeBreak::Control),
+    (0x180F, 0x180F, GraphemeBreak::Extend),
+    (0x1885, 0x1886, GraphemeBreak::Extend),
+    (0x18A9, 0x18A9, GraphemeBreak::Extend),
+    (0x1920, 0x1922, GraphemeBreak::Extend),
+    (0x1923, 0x1926, GraphemeBreak::SpacingMark),
+    (0x1927, 0x1928, GraphemeBreak::Extend),
+    (0x1929, 0x192B, GraphemeBreak::SpacingMark),
+    (0x1930, 0x1931, GraphemeBreak::SpacingMark),
+    (0x1932, 0x1932, GraphemeBreak::Extend),
+    (0x1933, 0x1938, GraphemeBreak::SpacingMark),
+    (0x1939, 0x193B, GraphemeBreak::Extend),
+    (0x1A17, 0x1A18, GraphemeBreak::Extend),
+    (0x1A19, 0x1A1A, GraphemeBreak::SpacingMark),
+    (0x1A1B, 0x1A1B, GraphemeBreak::Extend),
+    (0x1A55, 0x1A55, GraphemeBreak::SpacingMark),
+    (0x1A56, 0x1A56, GraphemeBreak::Extend),
+    (0x1A57, 0x1A57, GraphemeBreak::SpacingMark),
+    (0x1A58, 0x1A5E, GraphemeBreak::Extend),
+    (0x1A60, 0x1A60, GraphemeBreak::Extend),
+    (0x1A62, 0x1A62, GraphemeBreak::Extend),
+    (0x1A65, 0x1A6C, GraphemeBreak::Extend),
+    (0x1A6D, 0x1A72, GraphemeBreak::SpacingMark),
+    (0x1A73, 0x1A7C, GraphemeBreak::Extend),
+    (0x1A7F, 0x1A7F, GraphemeBreak::Extend),
+    (0x1AB0, 0x1ACE, GraphemeBreak::Extend),
+    (0x1B00, 0x1B03, GraphemeBreak::Extend),
+    (0x1B04, 0x1B04, GraphemeBreak::SpacingMark),
+    (0x1B34, 0x1B3A, GraphemeBreak::Extend),
+    (0x1B3B, 0x1B3B, GraphemeBreak::SpacingMark),
+    (0x1B3C, 0x1B3C, GraphemeBreak::Extend),
+    (0x1B3D, 0x1B41, GraphemeBreak::SpacingMark),
+    (0x1B42, 0x1B42, GraphemeBreak::Extend),
+    (0x1B43, 0x1B44, GraphemeBreak::SpacingMark),
+    (0x1B6B, 0x1B73, GraphemeBreak::Extend),
+    (0x1B80, 0x1B81, GraphemeBreak::Extend),
+    (0x1B82, 0x1B82, GraphemeBreak::SpacingMark),
+    (0x1BA1, 0x1BA1, GraphemeBreak::SpacingMark),
+    (0x1BA2, 0x1BA5, GraphemeBreak::Extend),
+    (0x1BA6, 0x1BA7, GraphemeBreak::SpacingMark),
+    (0x1BA8, 0x1BA9, GraphemeBreak::Extend),
+    (0x1BAA, 0x1BAA, GraphemeBreak::SpacingMark),
+    (0x1BAB, 0x1BAD, GraphemeBreak::Extend),
+    (0x1BE6, 0x1BE6, GraphemeBreak::Extend),
+    (0x1BE7, 0x1BE7, GraphemeBreak::SpacingMark),
+    (0x1BE8, 0x1BE9, GraphemeBreak::Extend),
+    (0x1BEA, 0x1BEC, GraphemeBreak::SpacingMark),
+    (0x1BED, 0x1BED, GraphemeBreak::Extend),
+    (0x1BEE, 0x1BEE, GraphemeBreak::SpacingMark),
+    (0x1BEF, 0x1BF1, GraphemeBreak::Extend),
+    (0x1BF2, 0x1BF3, GraphemeBreak::SpacingMark),
+    (0x1C24, 0x1C2B, GraphemeBreak::SpacingMark),
+    (0x1C2C, 0x1C33, GraphemeBreak::Extend),
+    (0x1C34, 0x1C35, GraphemeBreak::SpacingMark),
+    (0x1C36, 0x1C37, GraphemeBreak::Extend),
+    (0x1CD0, 0x1CD2, GraphemeBreak::Extend),
+    (0x1CD4, 0x1CE0, GraphemeBreak::Extend),
+    (0x1CE1, 0x1CE1, GraphemeBreak::SpacingMark),
+    (0x1CE2, 0x1CE8, GraphemeBreak::Extend),
+    (0x1CED, 0x1CED, GraphemeBreak::Extend),
+    (0x1CF4, 0x1CF4, GraphemeBreak::Extend),
+    (0x1CF7, 0x1CF7, GraphemeBreak::SpacingMark),
+    (0x1CF8, 0x1CF9, GraphemeBreak::Extend),
+    (0x1DC0, 0x1DFF, GraphemeBreak::Extend),
+    (0x200B, 0x200B, GraphemeBreak::Control),
+    (0x200C, 0x200C, GraphemeBreak::Extend),
+    (0x200D, 0x200D, GraphemeBreak::Zwj),
+    (0x200E, 0x200F, GraphemeBreak::Control),
+    (0x2028, 0x202E, GraphemeBreak::Control),
+    (0x2060, 0x206F, GraphemeBreak::Control),
+    (0x20D0, 0x20F0, GraphemeBreak::Extend),
+    (0x2CEF, 0x2CF1, GraphemeBreak::Extend),
+    (0x2D7F, 0x2D7F, GraphemeBreak::Extend),
+    (0x2DE0, 0x2DFF, GraphemeBreak::Extend),
+    (0x302A, 0x302F, GraphemeBreak::Extend),
+    (0x3099, 0x309A, GraphemeBreak::Extend),
+    (0xA66F, 0xA672, GraphemeBreak::Extend),
+    (0xA674, 0xA67D, GraphemeBreak::Extend),
+    (0xA69E, 0xA69F, GraphemeBreak::Extend),
+    (0xA6F0, 0xA6F1, GraphemeBreak::Extend),
+    (0xA802, 0xA802, GraphemeBreak::Extend),
+    (0xA806, 0xA806, GraphemeBreak::Extend),
+    (0xA80B, 0xA80B, GraphemeBreak::Extend),
+    (0xA823, 0xA824, GraphemeBreak::SpacingMark),
+    (0xA825, 0xA826, GraphemeBreak::Extend),
+    (0xA827, 0xA827, GraphemeBreak::SpacingMark),
+    (0xA82C, 0xA82C, GraphemeBreak::Extend),
+    (0xA880, 0xA881, GraphemeBreak::SpacingMark),
+    (0xA8B4, 0xA8C3, GraphemeBreak::SpacingMark),
+    (0xA8C4, 0xA8C5, GraphemeBreak::Extend),
+    (0xA8E0, 0xA8F1, GraphemeBreak::Extend),
+    (0xA8FF, 0xA8FF, GraphemeBreak::Extend),
+    (0xA926, 0xA92D, GraphemeBreak::Extend),
+    (0xA947, 0xA951, GraphemeBreak::Extend),
+    (0xA952, 0xA953, GraphemeBreak::SpacingMark),
+    (0xA960, 0xA97C, GraphemeBreak::L),
+    (0xA980, 0xA982, GraphemeBreak::Extend),
+    (0xA983, 0xA983, GraphemeBreak::SpacingMark),
+    (0xA9B3, 0xA9B3, GraphemeBreak::Extend),
+    (0xA9B4, 0xA9B5, GraphemeBreak::SpacingMark),
+    (0xA9B6, 0xA9B9, GraphemeBreak::Extend),
+    (0xA9BA, 0xA9BB, GraphemeBreak::SpacingMark),
+    (0xA9BC, 0xA9BD, GraphemeBreak::Extend),
+    (0xA9BE, 0xA9C0, GraphemeBreak::SpacingMark),
+    (0xA9E5, 0xA9E5, GraphemeBreak::Extend),
+    (0xAA29, 0xAA2E, GraphemeBreak::Extend),
+    (0xAA2F, 0xAA30, GraphemeBreak::SpacingMark),
+    (0xAA31, 0xAA32, GraphemeBreak::Extend),
+    (0xAA33, 0xAA34, GraphemeBreak::SpacingMark),
+    (0xAA35, 0xAA36, GraphemeBreak::Extend),
+    (0xAA43, 0xAA43, GraphemeBreak::Extend),
+    (0xAA4C, 0xAA4C, GraphemeBreak::Extend),
+    (0xAA4D, 0xAA4D, GraphemeBreak::SpacingMark),
+    (0xAA7C, 0xAA7C, GraphemeBreak::Extend),
+    (0xAAB0, 0xAAB0, GraphemeBreak::Extend),
+    (0xAAB2, 0xAAB4, GraphemeBreak::Extend),
+    (0xAAB7, 0xAAB8, GraphemeBreak::Extend),
+    (0xAABE, 0xAABF, GraphemeBreak::Extend),
+    (0xAAC1, 0xAAC1, GraphemeBreak::Extend),
+    (0xAAEB, 0xAAEB, GraphemeBreak::SpacingMark),
+    (0xAAEC, 0xAAED, GraphemeBreak::Extend),
+    (0xAAEE, 0xAAEF, GraphemeBreak::SpacingMark),
+    (0xAAF5, 0xAAF5, GraphemeBreak::SpacingMark),
+    (0xAAF6, 0xAAF6, GraphemeBreak::Extend),
+    (0xABE3, 0xABE4, GraphemeBreak::SpacingMark),
+    (0xABE5, 0xABE5, GraphemeBreak::Extend),
+    (0xABE6, 0xABE7, GraphemeBreak::SpacingMark),
+    (0xABE8, 0xABE8, GraphemeBreak::Extend),
+    (0xABE9, 0xABEA, GraphemeBreak::SpacingMark),
+    (0xABEC, 0xABEC, GraphemeBreak::SpacingMark),
+    (0xABED, 0xABED, GraphemeBreak::Extend),
+    (0xAC00, 0xAC00, GraphemeBreak::Lv),
+    (0xAC01, 0xAC1B, GraphemeBreak::Lvt),
+    (0xAC1C, 0xAC1C, GraphemeBreak::Lv),
+    (0xAC1D, 0xAC37, GraphemeBreak::Lvt),
+    (0xAC38, 0xAC38, GraphemeBreak::Lv),
+    (0xAC39, 0xAC53, GraphemeBreak::Lvt),
+    (0xAC54, 0xAC54, GraphemeBreak::Lv),
+    (0xAC55, 0xAC6F, GraphemeBreak::Lvt),
+    (0xAC70, 0xAC70, GraphemeBreak::Lv),
+    (0xAC71, 0xAC8B, GraphemeBreak::Lvt),
+    (0xAC8C, 0xAC8C, GraphemeBreak::Lv),
+    (0xAC8D, 0xACA7, GraphemeBreak::Lvt),
+    (0xACA8, 0xACA8, GraphemeBreak::Lv),
+    (0xACA9, 0xACC3, GraphemeBreak::Lvt),
+    (0xACC4, 0xACC4, GraphemeBreak::Lv),
+    (0xACC5, 0xACDF, GraphemeBreak::Lvt),
+    (0xACE0, 0xACE0, GraphemeBreak::Lv),
+    (0xACE1, 0xACFB, GraphemeBreak::Lvt),
+    (0xACFC, 0xACFC, GraphemeBreak::Lv),
+    (0xACFD, 0xAD17, GraphemeBreak::Lvt),
+    (0xAD18, 0xAD18, GraphemeBreak::Lv),
+    (0xAD19, 0xAD33, GraphemeBreak::Lvt),
+    (0xAD34, 0xAD34, GraphemeBreak::Lv),
+    (0xAD35, 0xAD4F, GraphemeBreak::Lvt),
+    (0xAD50, 0xAD50, GraphemeBreak::Lv),
+    (0xAD51, 0xAD6B, GraphemeBreak::Lvt),
+    (0xAD6C, 0xAD6C, GraphemeBreak::Lv),
+    (0xAD6D, 0xAD87, GraphemeBreak::Lvt),
+    (0xAD88, 0xAD88, GraphemeBreak::Lv),
+    (0xAD89, 0xADA3, GraphemeBreak::Lvt),
+    (0xADA4, 0xADA4, GraphemeBreak::Lv),
+    (0xADA5, 0xADBF, GraphemeBreak::Lvt),
+    (0xADC0, 0xADC0, GraphemeBreak::Lv),
+    (0xADC1, 0xADDB, GraphemeBreak::Lvt),
+    (0xADDC, 0xADDC, GraphemeBreak::Lv),
+    (0xADDD, 0xADF7, GraphemeBreak::Lvt),
+    (0xADF8, 0xADF8, GraphemeBreak::Lv),
+    (0xADF9, 0xAE13, GraphemeBreak::Lvt),
+    (0xAE14, 0xAE14, GraphemeBreak::Lv),
+    (0xAE15, 0xAE2F, GraphemeBreak::Lvt),
+    (0xAE30, 0xAE30, GraphemeBreak::Lv),
+    (0xAE31, 0xAE4B, GraphemeBreak::Lvt),
+    (0xAE4C, 0xAE4C, GraphemeBreak::Lv),
+    (0xAE4D, 0xAE67, GraphemeBreak::Lvt),
+    (0xAE68, 0xAE68, GraphemeBreak::Lv),
+    (0xAE69, 0xAE83, GraphemeBreak::Lvt),
+    (0xAE84, 0xAE84, GraphemeBreak::Lv),
+    (0xAE85, 0xAE9F, GraphemeBreak::Lvt),
+    (0xAEA0, 0xAEA0, GraphemeBreak::Lv),
+    (0xAEA1, 0xAEBB, GraphemeBreak::Lvt),
+    (0xAEBC, 0xAEBC, GraphemeBreak::Lv),
+    (0xAEBD, 0xAED7, GraphemeBreak::Lvt),
+    (0xAED8, 0xAED8, GraphemeBreak::Lv),
+    (0xAED9, 0xAEF3, GraphemeBreak::Lvt),
+    (0xAEF4, 0xAEF4, GraphemeBreak::Lv),
+    (0xAEF5, 0xAF0F, GraphemeBreak::Lvt),
+    (0xAF10, 0xAF10, GraphemeBreak::Lv),
+    (0xAF11, 0xAF2B, GraphemeBreak::Lvt),
+    (0xAF2C, 0xAF2C, GraphemeBreak::Lv),
+    (0xAF2D, 0xAF47, GraphemeBreak::Lvt),
+    (0xAF48, 0xAF48, GraphemeBreak::Lv),
+    (0xAF49, 0xAF63, GraphemeBreak::Lvt),
+    (0xAF64, 0xAF64, GraphemeBreak::Lv),
+    (0xAF65, 0xAF7F, GraphemeBreak::Lvt),
+    (0xAF80, 0xAF80, GraphemeBreak::Lv),
+    (0xAF81, 0xAF9B, GraphemeBreak::Lvt),
+    (0xAF9C, 0xAF9C, GraphemeBreak::Lv),
+    (0xAF9D, 0xAFB7, GraphemeBreak::Lvt),
+    (0xAFB8, 0xAFB8, GraphemeBreak::Lv),
+    (0xAFB9, 0xAFD3, GraphemeBreak::Lvt),
+    (0xAFD4, 0xAFD4, GraphemeBreak::Lv),
+    (0xAFD5, 0xAFEF, GraphemeBreak::Lvt),
+    (0xAFF0, 0xAFF0, GraphemeBreak::Lv),
+    (0xAFF1, 0xB00B, GraphemeBreak::Lvt),
+    (0xB00C, 0xB00C, GraphemeBreak::Lv),
+    (0xB00D, 0xB027, GraphemeBreak::Lvt),
+    (0xB028, 0xB028, GraphemeBreak::Lv),
+    (0xB029, 0xB043, GraphemeBreak::Lvt),
+    (0xB044, 0xB044, GraphemeBreak::Lv),
+    (0xB045, 0xB05F, GraphemeBreak::Lvt),
+    (0xB060, 0xB060, GraphemeBreak::Lv),
+    (0xB061, 0xB07B, GraphemeBreak::Lvt),
+    (0xB07C, 0xB07C, GraphemeBreak::Lv),
+    (0xB07D, 0xB097, GraphemeBreak::Lvt),
+    (0xB098, 0xB098, GraphemeBreak::Lv),
+    (0xB099, 0xB0B3, GraphemeBreak::Lvt),
+    (0xB0B4, 0xB0B4, GraphemeBreak::Lv),
+    (0xB0B5, 0xB0CF, GraphemeBreak::Lvt),
+    (0xB0D0, 0xB0D0, GraphemeBreak::Lv),
+    (0xB0D1, 0xB0EB, GraphemeBreak::Lvt),
+    (0xB0EC, 0xB0EC, GraphemeBreak::Lv),
+    (0xB0ED, 0xB107, GraphemeBreak::Lvt),
+    (0xB108, 0xB108, GraphemeBreak::Lv),
+    (0xB109, 0xB123, GraphemeBreak::Lvt),
+    (0xB124, 0xB124, GraphemeBreak::Lv),
+    (0xB125, 0xB13F, GraphemeBreak::Lvt),
+    (0xB140, 0xB140, GraphemeBreak::Lv),
+    (0xB141, 0xB15B, GraphemeBreak::Lvt),
+    (0xB15C, 0xB15C, GraphemeBreak::Lv),
+    (0xB15D, 0xB177, GraphemeBreak::Lvt),
+    (0xB178, 0xB178, GraphemeBreak::Lv),
+    (0xB179, 0xB193, GraphemeBreak::Lvt),
+    (0xB194, 0xB194, GraphemeBreak::Lv),
+    (0xB195, 0xB1AF, GraphemeBreak::Lvt),
+    (0xB1B0, 0xB1B0, GraphemeBreak::Lv),
+    (0xB1B1, 0xB1CB, GraphemeBreak::Lvt),
+    (0xB1CC, 0xB1CC, GraphemeBreak::Lv),
+    (0xB1CD, 0xB1E7, GraphemeBreak::Lvt),
+    (0xB1E8, 0xB1E8, GraphemeBreak::Lv),
+    (0xB1E9, 0xB203, GraphemeBreak::Lvt),
+    (0xB204, 0xB204, GraphemeBreak::Lv),
+    (0xB205, 0xB21F, GraphemeBreak::Lvt),
+    (0xB220, 0xB220, GraphemeBreak::Lv),
+    (0xB221, 0xB23B, GraphemeBreak::Lvt),
+    (0xB23C, 0xB23C, GraphemeBreak::Lv),
+    (0xB23D, 0xB257, GraphemeBreak::Lvt),
+    (0xB258, 0xB258, GraphemeBreak::Lv),
+    (0xB259, 0xB273, GraphemeBreak::Lvt),
+    (0xB274, 0xB274, GraphemeBreak::Lv),
+    (0xB275, 0xB28F, GraphemeBreak::Lvt),
+    (0xB290, 0xB290, GraphemeBreak::Lv),
+    (0xB291, 0xB2AB, GraphemeBreak::Lvt),
+    (0xB2AC, 0xB2AC, GraphemeBreak::Lv),
+    (0xB2AD, 0xB2C7, GraphemeBreak::Lvt),
+    (0xB2C8, 0xB2C8, GraphemeBreak::Lv),
+    (0xB2C9, 0xB2E3, GraphemeBreak::Lvt),
+    (0xB2E4, 0xB2E4, GraphemeBreak::Lv),
+    (0xB2E5, 0xB2FF, GraphemeBreak::Lvt),
+    (0xB300, 0xB300, GraphemeBreak::Lv),
+    (0xB301, 0xB31B, GraphemeBreak::Lvt),
+    (0xB31C, 0xB31C, GraphemeBreak::Lv),
+    (0xB31D, 0xB337, GraphemeBreak::Lvt),
+    (0xB338, 0xB338, GraphemeBreak::Lv),
+    (0xB339, 0xB353, GraphemeBreak::Lvt),
+    (0xB354, 0xB354, GraphemeBreak::Lv),
+    (0xB355, 0xB36F, GraphemeBreak::Lvt),
+    (0xB370, 0xB370, GraphemeBreak::Lv),
+    (0xB371, 0xB38B, GraphemeBreak::Lvt),
+    (0xB38C, 0xB38C, GraphemeBreak::Lv),
+    (0xB38D, 0xB3A7, GraphemeBreak::Lvt),
+    (0xB3A8, 0xB3A8, GraphemeBreak::Lv),
+    (0xB3A9, 0xB3C3, GraphemeBreak::Lvt),
+    (0xB3C4, 0xB3C4, GraphemeBreak::Lv),
+    (0xB3C5, 0xB3DF, GraphemeBreak::Lvt),
+    (0xB3E0, 0xB3E0, GraphemeBreak::Lv),
+    (0xB3E1, 0xB3FB, GraphemeBreak::Lvt),
+    (0xB3FC, 0xB3FC, GraphemeBreak::Lv),
+    (0xB3FD, 0xB417, GraphemeBreak::Lvt),
+    (0xB418, 0xB418, GraphemeBreak::Lv),
+    (0xB419, 0xB433, GraphemeBreak::Lvt),
+    (0xB434, 0xB434, GraphemeBreak::Lv),
+    (0xB435, 0xB44F, GraphemeBreak::Lvt),
+    (0xB450, 0xB450, GraphemeBreak::Lv),
+    (0xB451, 0xB46B, GraphemeBreak::Lvt),
+    (0xB46C, 0xB46C, GraphemeBreak::Lv),
+    (0xB46D, 0xB487, GraphemeBreak::Lvt),
+    (0xB488, 0xB488, GraphemeBreak::Lv),
+    (0xB489, 0xB4A3, GraphemeBreak::Lvt),
+    (0xB4A4, 0xB4A4, GraphemeBreak::Lv),
+    (0xB4A5, 0xB4BF, GraphemeBreak::Lvt),
+    (0xB4C0, 0xB4C0, GraphemeBreak::Lv),
+    (0xB4C1, 0xB4DB, GraphemeBreak::Lvt),
+    (0xB4DC, 0xB4DC, GraphemeBreak::Lv),
+    (0xB4DD, 0xB4F7, GraphemeBreak::Lvt),
+    (0xB4F8, 0xB4F8, GraphemeBreak::Lv),
+    (0xB4F9, 0xB513, GraphemeBreak::Lvt),
+    (0xB514, 0xB514, GraphemeBreak::Lv),
+    (0xB515, 0xB52F, GraphemeBreak::Lvt),
+    (0xB530, 0xB530, GraphemeBreak::Lv),
+    (0xB531, 0xB54B, GraphemeBreak::Lvt),
+    (0xB54C, 0xB54C, GraphemeBreak::Lv),
+    (0xB54D, 0xB567, GraphemeBreak::Lvt),
+    (0xB568, 0xB568, GraphemeBreak::Lv),
+    (0xB569, 0xB583, GraphemeBreak::Lvt),
+    (0xB584, 0xB584, GraphemeBreak::Lv),
+    (0xB585, 0xB59F, GraphemeBreak::Lvt),
+    (0xB5A0, 0xB5A0, GraphemeBreak::Lv),
+    (0xB5A1, 0xB5BB, GraphemeBreak::Lvt),
+    (0xB5BC, 0xB5BC, GraphemeBreak::Lv),
+    (0xB5BD, 0xB5D7, GraphemeBreak::Lvt),
+    (0xB5D8, 0xB5D8, GraphemeBreak::Lv),
+    (0xB5D9, 0xB5F3, GraphemeBreak::Lvt),
+    (0xB5F4, 0xB5F4, GraphemeBreak::Lv),
+    (0xB5F5, 0xB60F, GraphemeBreak::Lvt),
+    (0xB610, 0xB610, GraphemeBreak::Lv),
+    (0xB611, 0xB62B, GraphemeBreak::Lvt),
+    (0xB62C, 0xB62C, GraphemeBreak::Lv),
+    (0xB62D, 0xB647, GraphemeBreak::Lvt),
+    (0xB648, 0xB648, GraphemeBreak::Lv),
+    (0xB649, 0xB663, GraphemeBreak::Lvt),
+    (0xB664, 0xB664, GraphemeBreak::Lv),
+    (0xB665, 0xB67F, GraphemeBreak::Lvt),
+    (0xB680, 0xB680, GraphemeBreak::Lv),
+    (0xB681, 0xB69B, GraphemeBreak::Lvt),
+    (0xB69C, 0xB69C, GraphemeBreak::Lv),
+    (0xB69D, 0xB6B7, GraphemeBreak::Lvt),
+    (0xB6B8, 0xB6B8, GraphemeBreak::Lv),
+    (0xB6B9, 0xB6D3, GraphemeBreak::Lvt),
+    (0xB6D4, 0xB6D4, GraphemeBreak::Lv),
+    (0xB6D5, 0xB6EF, GraphemeBreak::Lvt),
+    (0xB6F0, 0xB6F0, GraphemeBreak::Lv),
+    (0xB6F1, 0xB70B, GraphemeBreak::Lvt),
+    (0xB70C, 0xB70C, GraphemeBreak::Lv),
+    (0xB70D, 0xB727, GraphemeBreak::Lvt),
+    (0xB728, 0xB728, GraphemeBreak::Lv),
+    (0xB729, 0xB743, GraphemeBreak::Lvt),
+    (0xB744, 0xB744, GraphemeBreak::Lv),
+    (0xB745, 0xB75F, GraphemeBreak::Lvt),
+    (0xB760, 0xB760, GraphemeBreak::Lv),
+    (0xB761, 0xB77B, GraphemeBreak::Lvt),
+    (0xB77C, 0xB77C, GraphemeBreak::Lv),
+    (0xB77D, 0xB797, GraphemeBreak::Lvt),
+    (0xB798, 0xB798, GraphemeBreak::Lv),
+    (0xB799, 0xB7B3, GraphemeBreak::Lvt),
+    (0xB7B4, 0xB7B4, GraphemeBreak::Lv),
+    (0xB7B5, 0xB7CF, GraphemeBreak::Lvt),
+    (0xB7D0, 0xB7D0, GraphemeBreak::Lv),
+    (0xB7D1, 0xB7EB, GraphemeBreak::Lvt),
+    (0xB7EC, 0xB7EC, GraphemeBreak::Lv),
+    (0xB7ED, 0xB807, GraphemeBreak::Lvt),
+    (0xB808, 0xB808, GraphemeBreak::Lv),
+    (0xB809, 0xB823, GraphemeBreak::Lvt),
+    (0xB824, 0xB824, GraphemeBreak::Lv),
+    (0xB825, 0xB83F, GraphemeBreak::Lvt),
+    (0xB840, 0xB840, GraphemeBreak::Lv),
+    (0xB841, 0xB85B, GraphemeBreak::Lvt),
+    (0xB85C, 0xB85C, GraphemeBreak::Lv),
+    (0xB85D, 0xB877, GraphemeBreak::Lvt),
+    (0xB878, 0xB878, GraphemeBreak::Lv),
+    (0xB879, 0xB893, GraphemeBreak::Lvt),
+    (0xB894, 0xB894, GraphemeBreak::Lv),
+    (0xB895, 0xB8AF, GraphemeBreak::Lvt),
+    (0xB8B0, 0xB8B0, GraphemeBreak::Lv),
+    (0xB8B1, 0xB8CB, GraphemeBreak::Lvt),
+    (0xB8CC, 0xB8CC, GraphemeBreak::Lv),
+    (0xB8CD, 0xB8E7, GraphemeBreak::Lvt),
+    (0xB8E8, 0xB8E8, GraphemeBreak::Lv),
+    (0xB8E9, 0xB903, GraphemeBreak::Lvt),
+    (0xB904, 0xB904, GraphemeBreak::Lv),
+    (0xB905, 0xB91F, GraphemeBreak::Lvt),
+    (0xB920, 0xB920, GraphemeBreak::Lv),
+    (0xB921, 0xB93B, GraphemeBreak::Lvt),
+    (0xB93C, 0xB93C, GraphemeBreak::Lv),
+    (0xB93D, 0xB957, GraphemeBreak::Lvt),
+    (0xB958, 0xB958, GraphemeBreak::Lv),
+    (0xB959, 0xB973, GraphemeBreak::Lvt),
+    (0xB974, 0xB974, GraphemeBreak::Lv),
+    (0xB975, 0xB98F, GraphemeBreak::Lvt),
+    (0xB990, 0xB990, GraphemeBreak::Lv),
+    (0xB991, 0xB9AB, GraphemeBreak::Lvt),
+    (0xB9AC, 0xB9AC, GraphemeBreak::Lv),
+    (0xB9AD, 0xB9C7, GraphemeBreak::Lvt),
+    (0xB9C8, 0xB9C8, GraphemeBreak::Lv),
+    (0xB9C9, 0xB9E3, GraphemeBreak::Lvt),
+    (0xB9E4, 0xB9E4, GraphemeBreak::Lv),
+    (0xB9E5, 0xB9FF, GraphemeBreak::Lvt),
+    (0xBA00, 0xBA00, GraphemeBreak::Lv),
+    (0xBA01, 0xBA1B, GraphemeBreak::Lvt),
+    (0xBA1C, 0xBA1C, GraphemeBreak::Lv),
+    (0xBA1D, 0xBA37, GraphemeBreak::Lvt),
+    (0xBA38, 0xBA38, GraphemeBreak::Lv),
+    (0xBA39, 0xBA53, GraphemeBreak::Lvt),
+    (0xBA54, 0xBA54, GraphemeBreak::Lv),
+    (0xBA55, 0xBA6F, GraphemeBreak::Lvt),
+    (0xBA70, 0xBA70, GraphemeBreak::Lv),
+    (0xBA71, 0xBA8B, GraphemeBreak::Lvt),
+    (0xBA8C, 0xBA8C, GraphemeBreak::Lv),
+    (0xBA8D, 0xBAA7, GraphemeBreak::Lvt),
+    (0xBAA8, 0xBAA8, GraphemeBreak::Lv),
+    (0xBAA9, 0xBAC3, GraphemeBreak::Lvt),
+    (0xBAC4, 0xBAC4, GraphemeBreak::Lv),
+    (0xBAC5, 0xBADF, GraphemeBreak::Lvt),
+    (0xBAE0, 0xBAE0, GraphemeBreak::Lv),
+    (0xBAE1, 0xBAFB, GraphemeBreak::Lvt),
+    (0xBAFC, 0xBAFC, GraphemeBreak::Lv),
+    (0xBAFD, 0xBB17, GraphemeBreak::Lvt),
+    (0xBB18, 0xBB18, GraphemeBreak::Lv),
+    (0xBB19, 0xBB33, GraphemeBreak::Lvt),
+    (0xBB34, 0xBB34, GraphemeBreak::Lv),
+    (0xBB35, 0xBB4F, GraphemeBreak::Lvt),
+    (0xBB50, 0xBB50, GraphemeBreak::Lv),
+    (0xBB51, 0xBB6B, GraphemeBreak::Lvt),
+    (0xBB6C, 0xBB6C, GraphemeBreak::Lv),
+    (0xBB6D, 0xBB87, GraphemeBreak::Lvt),
+    (0xBB88, 0xBB88, GraphemeBreak::Lv),
+    (0xBB89, 0xBBA3, GraphemeBreak::Lvt),
+    (0xBBA4, 0xBBA4, GraphemeBreak::Lv),
+    (0xBBA5, 0xBBBF, GraphemeBreak::Lvt),
+    (0xBBC0, 0xBBC0, GraphemeBreak::Lv),
+    (0xBBC1, 0xBBDB, GraphemeBreak::Lvt),
+    (0xBBDC, 0xBBDC, GraphemeBreak::Lv),
+    (0xBBDD, 0xBBF7, GraphemeBreak::Lvt),
+    (0xBBF8, 0xBBF8, GraphemeBreak::Lv),
+    (0xBBF9, 0xBC13, GraphemeBreak::Lvt),
+    (0xBC14, 0xBC14, GraphemeBreak::Lv),
+    (0xBC15, 0xBC2F, GraphemeBreak::Lvt),
+    (0xBC30, 0xBC30, GraphemeBreak::Lv),
+    (0xBC31, 0xBC4B, GraphemeBreak::Lvt),
+    (0xBC4C, 0xBC4C, GraphemeBreak::Lv),
+    (0xBC4D, 0xBC67, GraphemeBreak::Lvt),
+    (0xBC68, 0xBC68, GraphemeBreak::Lv),
+    (0xBC69, 0xBC83, GraphemeBreak::Lvt),
+    (0xBC84, 0xBC84, GraphemeBreak::Lv),
+    (0xBC85, 0xBC9F, GraphemeBreak::Lvt),
+    (0xBCA0, 0xBCA0, GraphemeBreak::Lv),
+    (0xBCA1, 0xBCBB, GraphemeBreak::Lvt),
+    (0xBCBC, 0xBCBC, GraphemeBreak::Lv),
+    (0xBCBD, 0xBCD7, GraphemeBreak::Lvt),
+    (0xBCD8, 0xBCD8, GraphemeBreak::Lv),
+    (0xBCD9, 0xBCF3, GraphemeBreak::Lvt),
+    (0xBCF4, 0xBCF4, GraphemeBreak::Lv),
+    (0xBCF5, 0xBD0F, GraphemeBreak::Lvt),
+    (0xBD10, 0xBD10, GraphemeBreak::Lv),
+    (0xBD11, 0xBD2B, GraphemeBreak::Lvt),
+    (0xBD2C, 0xBD2C, GraphemeBreak::Lv),
+    (0xBD2D, 0xBD47, GraphemeBreak::Lvt),
+    (0xBD48, 0xBD48, GraphemeBreak::Lv),
+    (0xBD49, 0xBD63, GraphemeBreak::Lvt),
+    (0xBD64, 0xBD64, GraphemeBreak::Lv),
+    (0xBD65, 0xBD7F, GraphemeBreak::Lvt),
+    (0xBD80, 0xBD80, GraphemeBreak::Lv),
+    (0xBD81, 0xBD9B, GraphemeBreak::Lvt),
+    (0xBD9C, 0xBD9C, GraphemeBreak::Lv),
+    (0xBD9D, 0xBDB7, GraphemeBreak::Lvt),
+    (0xBDB8, 0xBDB8, GraphemeBreak::Lv),
+    (0xBDB9, 0xBDD3, GraphemeBreak::Lvt),
+    (0xBDD4, 0xBDD4, GraphemeBreak::Lv),
+    (0xBDD5, 0xBDEF, GraphemeBreak::Lvt),
+    (0xBDF0, 0xBDF0, GraphemeBreak::Lv),
+    (0xBDF1, 0xBE0B, GraphemeBreak::Lvt),
+    (0xBE0C, 0xBE0C, GraphemeBreak::Lv),
+    (0xBE0D, 0xBE27, GraphemeBreak::Lvt),
+    (0xBE28, 0xBE28, GraphemeBreak::Lv),
+    (0xBE29, 0xBE43, GraphemeBreak::Lvt),
+    (0xBE44, 0xBE44, GraphemeBreak::Lv),
+    (0xBE45, 0xBE5F, GraphemeBreak::Lvt),
+    (0xBE60, 0xBE60, GraphemeBreak::Lv),
+    (0xBE61, 0xBE7B, GraphemeBreak::Lvt),
+    (0xBE7C, 0xBE7C, GraphemeBreak::Lv),
+    (0xBE7D, 0xBE97, GraphemeBreak::Lvt),
+    (0xBE98, 0xBE98, GraphemeBreak::Lv),
+    (0xBE99, 0xBEB3, GraphemeBreak::Lvt),
+    (0xBEB4, 0xBEB4, GraphemeBreak::Lv),
+    (0xBEB5, 0xBECF, GraphemeBreak::Lvt),
+    (0xBED0, 0xBED0, GraphemeBreak::Lv),
+    (0xBED1, 0xBEEB, GraphemeBreak::Lvt),
+    (0xBEEC, 0xBEEC, GraphemeBreak::Lv),
+    (0xBEED, 0xBF07, GraphemeBreak::Lvt),
+    (0xBF08, 0xBF08, GraphemeBreak::Lv),
+    (0xBF09, 0xBF23, GraphemeBreak::Lvt),
+    (0xBF24, 0xBF24, GraphemeBreak::Lv),
+    (0xBF25, 0xBF3F, GraphemeBreak::Lvt),
+    (0xBF40, 0xBF40, GraphemeBreak::Lv),
+    (0xBF41, 0xBF5B, GraphemeBreak::Lvt),
+    (0xBF5C, 0xBF5C, GraphemeBreak::Lv),
+    (0xBF5D, 0xBF77, GraphemeBreak::Lvt),
+    (0xBF78, 0xBF78, GraphemeBreak::Lv),
+    (0xBF79, 0xBF93, GraphemeBreak::Lvt),
+    (0xBF94, 0xBF94, GraphemeBreak::Lv),
+    (0xBF95, 0xBFAF, GraphemeBreak::Lvt),
+    (0xBFB0, 0xBFB0, GraphemeBreak::Lv),
+    (0xBFB1, 0xBFCB, GraphemeBreak::Lvt),
+    (0xBFCC, 0xBFCC, GraphemeBreak::Lv),
+    (0xBFCD, 0xBFE7, GraphemeBreak::Lvt),
+    (0xBFE8, 0xBFE8, GraphemeBreak::Lv),
+    (0xBFE9, 0xC003, GraphemeBreak::Lvt),
+    (0xC004, 0xC004, GraphemeBreak::Lv),
+    (0xC005, 0xC01F, GraphemeBreak::Lvt),
+    (0xC020, 0xC020, GraphemeBreak::Lv),
+    (0xC021, 0xC03B, GraphemeBreak::Lvt),
+    (0xC03C, 0xC03C, GraphemeBreak::Lv),
+    (0xC03D, 0xC057, GraphemeBreak::Lvt),
+    (0xC058, 0xC058, GraphemeBreak::Lv),
+    (0xC059, 0xC073, GraphemeBreak::Lvt),
+    (0xC074, 0xC074, GraphemeBreak::Lv),
+    (0xC075, 0xC08F, GraphemeBreak::Lvt),
+    (0xC090, 0xC090, GraphemeBreak::Lv),
+    (0xC091, 0xC0AB, GraphemeBreak::Lvt),
+    (0xC0AC, 0xC0AC, GraphemeBreak::Lv),
+    (0xC0AD, 0xC0C7, GraphemeBreak::Lvt),
+    (0xC0C8, 0xC0C8, GraphemeBreak::Lv),
+    (0xC0C9, 0xC0E3, GraphemeBreak::Lvt),
+    (0xC0E4, 0xC0E4, GraphemeBreak::Lv),
+    (0xC0E5, 0xC0FF, GraphemeBreak::Lvt),
+    (0xC100, 0xC100, GraphemeBreak::Lv),
+    (0xC101, 0xC11B, GraphemeBreak::Lvt),
+    (0xC11C, 0xC11C, GraphemeBreak::Lv),
+    (0xC11D, 0xC137, GraphemeBreak::Lvt),
+    (0xC138, 0xC138, GraphemeBreak::Lv),
+    (0xC139, 0xC153, GraphemeBreak::Lvt),
+    (0xC154, 0xC154, GraphemeBreak::Lv),
+    (0xC155, 0xC16F, GraphemeBreak::Lvt),
+    (0xC170, 0xC170, GraphemeBreak::Lv),
+    (0xC171, 0xC18B, GraphemeBreak::Lvt),
+    (0xC18C, 0xC18C, GraphemeBreak::Lv),
+    (0xC18D, 0xC1A7, GraphemeBreak::Lvt),
+    (0xC1A8, 0xC1A8, GraphemeBreak::Lv),
+    (0xC1A9, 0xC1C3, GraphemeBreak::Lvt),
+    (0xC1C4, 0xC1C4, GraphemeBreak::Lv),
+    (0xC1C5, 0xC1DF, GraphemeBreak::Lvt),
+    (0xC1E0, 0xC1E0, GraphemeBreak::Lv),
+    (0xC1E1, 0xC1FB, GraphemeBreak::Lvt),
+    (0xC1FC, 0xC1FC, GraphemeBreak::Lv),
+    (0xC1FD, 0xC217, GraphemeBreak::Lvt),
+    (0xC218, 0xC218, GraphemeBreak::Lv),
+    (0xC219, 0xC233, GraphemeBreak::Lvt),
+    (0xC234, 0xC234, GraphemeBreak::Lv),
+    (0xC235, 0xC24F, GraphemeBreak::Lvt),
+    (0xC250, 0xC250, GraphemeBreak::Lv),
+    (0xC251, 0xC26B, GraphemeBreak::Lvt),
+    (0xC26C, 0xC26C, GraphemeBreak::Lv),
+    (0xC26D, 0xC287, GraphemeBreak::Lvt),
+    (0xC288, 0xC288, GraphemeBreak::Lv),
+    (0xC289, 0xC2A3, GraphemeBreak::Lvt),
+    (0xC2A4, 0xC2A4, GraphemeBreak::Lv),
+    (0xC2A5, 0xC2BF, GraphemeBreak::Lvt),
+    (0xC2C0, 0xC2C0, GraphemeBreak::Lv),
+    (0xC2C1, 0xC2DB, GraphemeBreak::Lvt),
+    (0xC2DC, 0xC2DC, GraphemeBreak::Lv),
+    (0xC2DD, 0xC2F7, GraphemeBreak::Lvt),
+    (0xC2F8, 0xC2F8, GraphemeBreak::Lv),
+    (0xC2F9, 0xC313, GraphemeBreak::Lvt),
+    (0xC314, 0xC314, GraphemeBreak::Lv),
+    (0xC315, 0xC32F, GraphemeBreak::Lvt),
+    (0xC330, 0xC330, GraphemeBreak::Lv),
+    (0xC331, 0xC34B, GraphemeBreak::Lvt),
+    (0xC34C, 0xC34C, GraphemeBreak::Lv),
+    (0xC34D, 0xC367, GraphemeBreak::Lvt),
+    (0xC368, 0xC368, GraphemeBreak::Lv),
+    (0xC369, 0xC383, GraphemeBreak::Lvt),
+    (0xC384, 0xC384, GraphemeBreak::Lv),
+    (0xC385, 0xC39F, GraphemeBreak::Lvt),
+    (0xC3A0, 0xC3A0, GraphemeBreak::Lv),
+    (0xC3A1, 0xC3BB, GraphemeBreak::Lvt),
+    (0xC3BC, 0xC3BC, GraphemeBreak::Lv),
+    (0xC3BD, 0xC3D7, GraphemeBreak::Lvt),
+    (0xC3D8, 0xC3D8, GraphemeBreak::Lv),
+    (0xC3D9, 0xC3F3, GraphemeBreak::Lvt),
+    (0xC3F4, 0xC3F4, GraphemeBreak::Lv),
+    (0xC3F5, 0xC40F, GraphemeBreak::Lvt),
+    (0xC410, 0xC410, GraphemeBreak::Lv),
+    (0xC411, 0xC42B, GraphemeBreak::Lvt),
+    (0xC42C, 0xC42C, GraphemeBreak::Lv),
+    (0xC42D, 0xC447, GraphemeBreak::Lvt),
+    (0xC448, 0xC448, GraphemeBreak::Lv),
+    (0xC449, 0xC463, GraphemeBreak::Lvt),
+    (0xC464, 0xC464, GraphemeBreak::Lv),
+    (0xC465, 0xC47F, GraphemeBreak::Lvt),
+    (0xC480, 0xC480, GraphemeBreak::Lv),
+    (0xC481, 0xC49B, GraphemeBreak::Lvt),
+    (0xC49C, 0xC49C, GraphemeBreak::Lv),
+    (0xC49D, 0xC4B7, GraphemeBreak::Lvt),
+    (0xC4B8, 0xC4B8, GraphemeBreak::Lv),
+    (0xC4B9, 0xC4D3, GraphemeBreak::Lvt),
+    (0xC4D4, 0xC4D4, GraphemeBreak::Lv),
+    (0xC4D5, 0xC4EF, GraphemeBreak::Lvt),
+    (0xC4F0, 0xC4F0, GraphemeBreak::Lv),
+    (0xC4F1, 0xC50B, GraphemeBreak::Lvt),
+    (0xC50C, 0xC50C, GraphemeBreak::Lv),
+    (0xC50D, 0xC527, GraphemeBreak::Lvt),
+    (0xC528, 0xC528, GraphemeBreak::Lv),
+    (0xC529, 0xC543, GraphemeBreak::Lvt),
+    (0xC544, 0xC544, GraphemeBreak::Lv),
+    (0xC545, 0xC55F, GraphemeBreak::Lvt),
+    (0xC560, 0xC560, GraphemeBreak::Lv),
+    (0xC561, 0xC57B, GraphemeBreak::Lvt),
+    (0xC57C, 0xC57C, GraphemeBreak::Lv),
+    (0xC57D, 0xC597, GraphemeBreak::Lvt),
+    (0xC598, 0xC598, GraphemeBreak::Lv),
+    (0xC599, 0xC5B3, GraphemeBreak::Lvt),
+    (0xC5B4, 0xC5B4, GraphemeBreak::Lv),
+    (0xC5B5, 0xC5CF, GraphemeBreak::Lvt),
+    (0xC5D0, 0xC5D0, GraphemeBreak::Lv),
+    (0xC5D1, 0xC5EB, GraphemeBreak::Lvt),
+    (0xC5EC, 0xC5EC, GraphemeBreak::Lv),
+    (0xC5ED, 0xC607, GraphemeBreak::Lvt),
+    (0xC608, 0xC608, GraphemeBreak::Lv),
+    (0xC609, 0xC623, GraphemeBreak::Lvt),
+    (0xC624, 0xC624, GraphemeBreak::Lv),
+    (0xC625, 0xC63F, GraphemeBreak::Lvt),
+    (0xC640, 0xC640, GraphemeBreak::Lv),
+    (0xC641, 0xC65B, GraphemeBreak::Lvt),
+    (0xC65C, 0xC65C, GraphemeBreak::Lv),
+    (0xC65D, 0xC677, GraphemeBreak::Lvt),
+    (0xC678, 0xC678, GraphemeBreak::Lv),
+    (0xC679, 0xC693, GraphemeBreak::Lvt),
+    (0xC694, 0xC694, GraphemeBreak::Lv),
+    (0xC695, 0xC6AF, GraphemeBreak::Lvt),
+    (0xC6B0, 0xC6B0, GraphemeBreak::Lv),
+    (0xC6B1, 0xC6CB, GraphemeBreak::Lvt),
+    (0xC6CC, 0xC6CC, GraphemeBreak::Lv),
+    (0xC6CD, 0xC6E7, GraphemeBreak::Lvt),
+    (0xC6E8, 0xC6E8, GraphemeBreak::Lv),
+    (0xC6E9, 0xC703, GraphemeBreak::Lvt),
+    (0xC704, 0xC704, GraphemeBreak::Lv),
+    (0xC705, 0xC71F, GraphemeBreak::Lvt),
+    (0xC720, 0xC720, GraphemeBreak::Lv),
+    (0xC721, 0xC73B, GraphemeBreak::Lvt),
+    (0xC73C, 0xC73C, GraphemeBreak::Lv),
+    (0xC73D, 0xC757, GraphemeBreak::Lvt),
+    (0xC758, 0xC758, GraphemeBreak::Lv),
+    (0xC759, 0xC773, GraphemeBreak::Lvt),
+    (0xC774, 0xC774, GraphemeBreak::Lv),
+    (0xC775, 0xC78F, GraphemeBreak::Lvt),
+    (0xC790, 0xC790, GraphemeBreak::Lv),
+    (0xC791, 0xC7AB, GraphemeBreak::Lvt),
+    (0xC7AC, 0xC7AC, GraphemeBreak::Lv),
+    (0xC7AD, 0xC7C7, GraphemeBreak::Lvt),
+    (0xC7C8, 0xC7C8, GraphemeBreak::Lv),
+    (0xC7C9, 0xC7E3, GraphemeBreak::Lvt),
+    (0xC7E4, 0xC7E4, GraphemeBreak::Lv),
+    (0xC7E5, 0xC7FF, GraphemeBreak::Lvt),
+    (0xC800, 0xC800, GraphemeBreak::Lv),
+    (0xC801, 0xC81B, GraphemeBreak::Lvt),
+    (0xC81C, 0xC81C, GraphemeBreak::Lv),
+    (0xC81D, 0xC837, GraphemeBreak::Lvt),
+    (0xC838, 0xC838, GraphemeBreak::Lv),
+    (0xC839, 0xC853, GraphemeBreak::Lvt),
+    (0xC854, 0xC854, GraphemeBreak::Lv),
+    (0xC855, 0xC86F, GraphemeBreak::Lvt),
+    (0xC870, 0xC870, GraphemeBreak::Lv),
+    (0xC871, 0xC88B, GraphemeBreak::Lvt),
+    (0xC88C, 0xC88C, GraphemeBreak::Lv),
+    (0xC88D, 0xC8A7, GraphemeBreak::Lvt),
+    (0xC8A8, 0xC8A8, GraphemeBreak::Lv),
+    (0xC8A9, 0xC8C3, GraphemeBreak::Lvt),
+    (0xC8C4, 0xC8C4, GraphemeBreak::Lv),
+    (0xC8C5, 0xC8DF, GraphemeBreak::Lvt),
+    (0xC8E0, 0xC8E0, GraphemeBreak::Lv),
+    (0xC8E1, 0xC8FB, GraphemeBreak::Lvt),
+    (0xC8FC, 0xC8FC, GraphemeBreak::Lv),
+    (0xC8FD, 0xC917, GraphemeBreak::Lvt),
+    (0xC918, 0xC918, GraphemeBreak::Lv),
+    (0xC919, 0xC933, GraphemeBreak::Lvt),
+    (0xC934, 0xC934, GraphemeBreak::Lv),
+    (0xC935, 0xC94F, GraphemeBreak::Lvt),
+    (0xC950, 0xC950, GraphemeBreak::Lv),
+    (0xC951, 0xC96B, GraphemeBreak::Lvt),
+    (0xC96C, 0xC96C, GraphemeBreak::Lv),
+    (0xC96D, 0xC987, GraphemeBreak::Lvt),
+    (0xC988, 0xC988, GraphemeBreak::Lv),
+    (0xC989, 0xC9A3, GraphemeBreak::Lvt),
+    (0xC9A4, 0xC9A4, GraphemeBreak::Lv),
+    (0xC9A5, 0xC9BF, GraphemeBreak::Lvt),
+    (0xC9C0, 0xC9C0, GraphemeBreak::Lv),
+    (0xC9C1, 0xC9DB, GraphemeBreak::Lvt),
+    (0xC9DC, 0xC9DC, GraphemeBreak::Lv),
+    (0xC9DD, 0xC9F7, GraphemeBreak::Lvt),
+    (0xC9F8, 0xC9F8, GraphemeBreak::Lv),
+    (0xC9F9, 0xCA13, GraphemeBreak::Lvt),
+    (0xCA14, 0xCA14, GraphemeBreak::Lv),
+    (0xCA15, 0xCA2F, GraphemeBreak::Lvt),
+    (0xCA30, 0xCA30, GraphemeBreak::Lv),
+    (0xCA31, 0xCA4B, GraphemeBreak::Lvt),
+    (0xCA4C, 0xCA4C, GraphemeBreak::Lv),
+    (0xCA4D, 0xCA67, GraphemeBreak::Lvt),
+    (0xCA68, 0xCA68, GraphemeBreak::Lv),
+    (0xCA69, 0xCA83, GraphemeBreak::Lvt),
+    (0xCA84, 0xCA84, GraphemeBreak::Lv),
+    (0xCA85, 0xCA9F, GraphemeBreak::Lvt),
+    (0xCAA0, 0xCAA0, GraphemeBreak::Lv),
+    (0xCAA1, 0xCABB, GraphemeBreak::Lvt),
+    (0xCABC, 0xCABC, GraphemeBreak::Lv),
+    (0xCABD, 0xCAD7, GraphemeBreak::Lvt),
+    (0xCAD8, 0xCAD8, GraphemeBreak::Lv),
+    (0xCAD9, 0xCAF3, GraphemeBreak::Lvt),
+    (0xCAF4, 0xCAF4, GraphemeBreak::Lv),
+    (0xCAF5, 0xCB0F, GraphemeBreak::Lvt),
+    (0xCB10, 0xCB10, GraphemeBreak::Lv),
+    (0xCB11, 0xCB2B, GraphemeBreak::Lvt),
+    (0xCB2C, 0xCB2C, GraphemeBreak::Lv),
+    (0xCB2D, 0xCB47, GraphemeBreak::Lvt),
+    (0xCB48, 0xCB48, GraphemeBreak::Lv),
+    (0xCB49, 0xCB63, GraphemeBreak::Lvt),
+    (0xCB64, 0xCB64, GraphemeBreak::Lv),
+    (0xCB65, 0xCB7F, GraphemeBreak::Lvt),
+    (0xCB80, 0xCB80, GraphemeBreak::Lv),
+    (0xCB81, 0xCB9B, GraphemeBreak::Lvt),
+    (0xCB9C, 0xCB9C, GraphemeBreak::Lv),
+    (0xCB9D, 0xCBB7, GraphemeBreak::Lvt),
+    (0xCBB8, 0xCBB8, GraphemeBreak::Lv),
+    (0xCBB9, 0xCBD3, GraphemeBreak::Lvt),
+    (0xCBD4, 0xCBD4, GraphemeBreak::Lv),
+    (0xCBD5, 0xCBEF, GraphemeBreak::Lvt),
+    (0xCBF0, 0xCBF0, GraphemeBreak::Lv),
+    (0xCBF1, 0xCC0B, GraphemeBreak::Lvt),
+    (0xCC0C, 0xCC0C, GraphemeBreak::Lv),
+    (0xCC0D, 0xCC27, GraphemeBreak::Lvt),
+    (0xCC28, 0xCC28, GraphemeBreak::Lv),
+    (0xCC29, 0xCC43, GraphemeBreak::Lvt),
+    (0xCC44, 0xCC44, GraphemeBreak::Lv),
+    (0xCC45, 0xCC5F, GraphemeBreak::Lvt),
+    (0xCC60, 0xCC60, GraphemeBreak::Lv),
+    (0xCC61, 0xCC7B, GraphemeBreak::Lvt),
+    (0xCC7C, 0xCC7C, GraphemeBreak::Lv),
+    (0xCC7D, 0xCC97, GraphemeBreak::Lvt),
+    (0xCC98, 0xCC98, GraphemeBreak::Lv),
+    (0xCC99, 0xCCB3, GraphemeBreak::Lvt),
+    (0xCCB4, 0xCCB4, GraphemeBreak::Lv),
+    (0xCCB5, 0xCCCF, GraphemeBreak::Lvt),
+    (0xCCD0, 0xCCD0, GraphemeBreak::Lv),
+    (0xCCD1, 0xCCEB, GraphemeBreak::Lvt),
+    (0xCCEC, 0xCCEC, GraphemeBreak::Lv),
+    (0xCCED, 0xCD07, GraphemeBreak::Lvt),
+    (0xCD08, 0xCD08, GraphemeBreak::Lv),
+    (0xCD09, 0xCD23, GraphemeBreak::Lvt),
+    (0xCD24, 0xCD24, GraphemeBreak::Lv),
+    (0xCD25, 0xCD3F, GraphemeBreak::Lvt),
+    (0xCD40, 0xCD40, GraphemeBreak::Lv),
+    (0xCD41, 0xCD5B, GraphemeBreak::Lvt),
+    (0xCD5C, 0xCD5C, GraphemeBreak::Lv),
+    (0xCD5D, 0xCD77, GraphemeBreak::Lvt),
+    (0xCD78, 0xCD78, GraphemeBreak::Lv),
+    (0xCD79, 0xCD93, GraphemeBreak::Lvt),
+    (0xCD94, 0xCD94, GraphemeBreak::Lv),
+    (0xCD95, 0xCDAF, GraphemeBreak::Lvt),
+    (0xCDB0, 0xCDB0, GraphemeBreak::Lv),
+    (0xCDB1, 0xCDCB, GraphemeBreak::Lvt),
+    (0xCDCC, 0xCDCC, GraphemeBreak::Lv),
+    (0xCDCD, 0xCDE7, GraphemeBreak::Lvt),
+    (0xCDE8, 0xCDE8, GraphemeBreak::Lv),
+    (0xCDE9, 0xCE03, GraphemeBreak::Lvt),
+    (0xCE04, 0xCE04, GraphemeBreak::Lv),
+    (0xCE05, 0xCE1F, GraphemeBreak::Lvt),
+    (0xCE20, 0xCE20, GraphemeBreak::Lv),
+    (0xCE21, 0xCE3B, GraphemeBreak::Lvt),
+    (0xCE3C, 0xCE3C, GraphemeBreak::Lv),
+    (0xCE3D, 0xCE57, GraphemeBreak::Lvt),
+    (0xCE58, 0xCE58, GraphemeBreak::Lv),
+    (0xCE59, 0xCE73, GraphemeBreak::Lvt),
+    (0xCE74, 0xCE74, GraphemeBreak::Lv),
+    (0xCE75, 0xCE8F, GraphemeBreak::Lvt),
+    (0xCE90, 0xCE90, GraphemeBreak::Lv),
+    (0xCE91, 0xCEAB, GraphemeBreak::Lvt),
+    (0xCEAC, 0xCEAC, GraphemeBreak::Lv),
+    (0xCEAD, 0xCEC7, GraphemeBreak::Lvt),
+    (0xCEC8, 0xCEC8, GraphemeBreak::Lv),
+    (0xCEC9, 0xCEE3, GraphemeBreak::Lvt),
+    (0xCEE4, 0xCEE4, GraphemeBreak::Lv),
+    (0xCEE5, 0xCEFF, GraphemeBreak::Lvt),
+    (0xCF00, 0xCF00, GraphemeBreak::Lv),
+    (0xCF01, 0xCF1B, GraphemeBreak::Lvt),
+    (0xCF1C, 0xCF1C, GraphemeBreak::Lv),
+    (0xCF1D, 0xCF37, GraphemeBreak::Lvt),
+    (0xCF38, 0xCF38, GraphemeBreak::Lv),
+    (0xCF39, 0xCF53, GraphemeBreak::Lvt),
+    (0xCF54, 0xCF54, GraphemeBreak::Lv),
+    (0xCF55, 0xCF6F, GraphemeBreak::Lvt),
+    (0xCF70, 0xCF70, GraphemeBreak::Lv),
+    (0xCF71, 0xCF8B, GraphemeBreak::Lvt),
+    (0xCF8C, 0xCF8C, GraphemeBreak::Lv),
+    (0xCF8D, 0xCFA7, GraphemeBreak::Lvt),
+    (0xCFA8, 0xCFA8, GraphemeBreak::Lv),
+    (0xCFA9, 0xCFC3, GraphemeBreak::Lvt),
+    (0xCFC4, 0xCFC4, GraphemeBreak::Lv),
+    (0xCFC5, 0xCFDF, GraphemeBreak::Lvt),
+    (0xCFE0, 0xCFE0, GraphemeBreak::Lv),
+    (0xCFE1, 0xCFFB, GraphemeBreak::Lvt),
+    (0xCFFC, 0xCFFC, GraphemeBreak::Lv),
+    (0xCFFD, 0xD017, GraphemeBreak::Lvt),
+    (0xD018, 0xD018, GraphemeBreak::Lv),
+    (0xD019, 0xD033, GraphemeBreak::Lvt),
+    (0xD034, 0xD034, GraphemeBreak::Lv),
+    (0xD035, 0xD04F, GraphemeBreak::Lvt),
+    (0xD050, 0xD050, GraphemeBreak::Lv),
+    (0xD051, 0xD06B, GraphemeBreak::Lvt),
+    (0xD06C, 0xD06C, GraphemeBreak::Lv),
+    (0xD06D, 0xD087, GraphemeBreak::Lvt),
+    (0xD088, 0xD088, GraphemeBreak::Lv),
+    (0xD089, 0xD0A3, GraphemeBreak::Lvt),
+    (0xD0A4, 0xD0A4, GraphemeBreak::Lv),
+    (0xD0A5, 0xD0BF, GraphemeBreak::Lvt),
+    (0xD0C0, 0xD0C0, GraphemeBreak::Lv),
+    (0xD0C1, 0xD0DB, GraphemeBreak::Lvt),
+    (0xD0DC, 0xD0DC, GraphemeBreak::Lv),
+    (0xD0DD, 0xD0F7, GraphemeBreak::Lvt),
+    (0xD0F8, 0xD0F8, GraphemeBreak::Lv),
+    (0xD0F9, 0xD113, GraphemeBreak::Lvt),
+    (0xD114, 0xD114, GraphemeBreak::Lv),
+    (0xD115, 0xD12F, GraphemeBreak::Lvt),
+    (0xD130, 0xD130, GraphemeBreak::Lv),
+    (0xD131, 0xD14B, GraphemeBreak::Lvt),
+    (0xD14C, 0xD14C, GraphemeBreak::Lv),
+    (0xD14D, 0xD167, GraphemeBreak::Lvt),
+    (0xD168, 0xD168, GraphemeBreak::Lv),
+    (0xD169, 0xD183, GraphemeBreak::Lvt),
+    (0xD184, 0xD184, GraphemeBreak::Lv),
+    (0xD185, 0xD19F, GraphemeBreak::Lvt),
+    (0xD1A0, 0xD1A0, GraphemeBreak::Lv),
+    (0xD1A1, 0xD1BB, GraphemeBreak::Lvt),
+    (0xD1BC, 0xD1BC, GraphemeBreak::Lv),
+    (0xD1BD, 0xD1D7, GraphemeBreak::Lvt),
+    (0xD1D8, 0xD1D8, GraphemeBreak::Lv),
+    (0xD1D9, 0xD1F3, GraphemeBreak::Lvt),
+    (0xD1F4, 0xD1F4, GraphemeBreak::Lv),
+    (0xD1F5, 0xD20F, GraphemeBreak::Lvt),
+    (0xD210, 0xD210, GraphemeBreak::Lv),
+    (0xD211, 0xD22B, GraphemeBreak::Lvt),
+    (0xD22C, 0xD22C, GraphemeBreak::Lv),
+    (0xD22D, 0xD247, GraphemeBreak::Lvt),
+    (0xD248, 0xD248, GraphemeBreak::Lv),
+    (0xD249, 0xD263, GraphemeBreak::Lvt),
+    (0xD264, 0xD264, GraphemeBreak::Lv),
+    (0xD265, 0xD27F, GraphemeBreak::Lvt),
+    (0xD280, 0xD280, GraphemeBreak::Lv),
+    (0xD281, 0xD29B, GraphemeBreak::Lvt),
+    (0xD29C, 0xD29C, GraphemeBreak::Lv),
+    (0xD29D, 0xD2B7, GraphemeBreak::Lvt),
+    (0xD2B8, 0xD2B8, GraphemeBreak::Lv),
+    (0xD2B9, 0xD2D3, GraphemeBreak::Lvt),
+    (0xD2D4, 0xD2D4, GraphemeBreak::Lv),
+    (0xD2D5, 0xD2EF, GraphemeBreak::Lvt),
+    (0xD2F0, 0xD2F0, GraphemeBreak::Lv),
+    (0xD2F1, 0xD30B, GraphemeBreak::Lvt),
+    (0xD30C, 0xD30C, GraphemeBreak::Lv),
+    (0xD30D, 0xD327, GraphemeBreak::Lvt),
+    (0xD328, 0xD328, GraphemeBreak::Lv),
+    (0xD329, 0xD343, GraphemeBreak::Lvt),
+    (0xD344, 0xD344, GraphemeBreak::Lv),
+    (0xD345, 0xD35F, GraphemeBreak::Lvt),
+    (0xD360, 0xD360, GraphemeBreak::Lv),
+    (0xD361, 0xD37B, GraphemeBreak::Lvt),
+    (0xD37C, 0xD37C, GraphemeBreak::Lv),
+    (0xD37D, 0xD397, GraphemeBreak::Lvt),
+    (0xD398, 0xD398, GraphemeBreak::Lv),
+    (0xD399, 0xD3B3, GraphemeBreak::Lvt),
+    (0xD3B4, 0xD3B4, GraphemeBreak::Lv),
+    (0xD3B5, 0xD3CF, GraphemeBreak::Lvt),
+    (0xD3D0, 0xD3D0, GraphemeBreak::Lv),
+    (0xD3D1, 0xD3EB, GraphemeBreak::Lvt),
+    (0xD3EC, 0xD3EC, GraphemeBreak::Lv),
+    (0xD3ED, 0xD407, GraphemeBreak::Lvt),
+    (0xD408, 0xD408, GraphemeBreak::Lv),
+    (0xD409, 0xD423, GraphemeBreak::Lvt),
+    (0xD424, 0xD424, GraphemeBreak::Lv),
+    (0xD425, 0xD43F, GraphemeBreak::Lvt),
+    (0xD440, 0xD440, GraphemeBreak::Lv),
+    (0xD441, 0xD45B, GraphemeBreak::Lvt),
+    (0xD45C, 0xD45C, GraphemeBreak::Lv),
+    (0xD45D, 0xD477, GraphemeBreak::Lvt),
+    (0xD478, 0xD478, GraphemeBreak::Lv),
+    (0xD479, 0xD493, GraphemeBreak::Lvt),
+    (0xD494, 0xD494, GraphemeBreak::Lv),
+    (0xD495, 0xD4AF, GraphemeBreak::Lvt),
+    (0xD4B0, 0xD4B0, GraphemeBreak::Lv),
+    (0xD4B1, 0xD4CB, GraphemeBreak::Lvt),
+    (0xD4CC, 0xD4CC, GraphemeBreak::Lv),
+    (0xD4CD, 0xD4E7, GraphemeBreak::Lvt),
+    (0xD4E8, 0xD4E8, GraphemeBreak::Lv),
+    (0xD4E9, 0xD503, GraphemeBreak::Lvt),
+    (0xD504, 0xD504, GraphemeBreak::Lv),
+    (0xD505, 0xD51F, GraphemeBreak::Lvt),
+    (0xD520, 0xD520, GraphemeBreak::Lv),
+    (0xD521, 0xD53B, GraphemeBreak::Lvt),
+    (0xD53C, 0xD53C, GraphemeBreak::Lv),
+    (0xD53D, 0xD557, GraphemeBreak::Lvt),
+    (0xD558, 0xD558, GraphemeBreak::Lv),
+    (0xD559, 0xD573, GraphemeBreak::Lvt),
+    (0xD574, 0xD574, GraphemeBreak::Lv),
+    (0xD575, 0xD58F, GraphemeBreak::Lvt),
+    (0xD590, 0xD590, GraphemeBreak::Lv),
+    (0xD591, 0xD5AB, GraphemeBreak::Lvt),
+    (0xD5AC, 0xD5AC, GraphemeBreak::Lv),
+    (0xD5AD, 0xD5C7, GraphemeBreak::Lvt),
+    (0xD5C8, 0xD5C8, GraphemeBreak::Lv),
+    (0xD5C9, 0xD5E3, GraphemeBreak::Lvt),
+    (0xD5E4, 0xD5E4, GraphemeBreak::Lv),
+    (0xD5E5, 0xD5FF, GraphemeBreak::Lvt),
+    (0xD600, 0xD600, GraphemeBreak::Lv),
+    (0xD601, 0xD61B, GraphemeBreak::Lvt),
+    (0xD61C, 0xD61C, GraphemeBreak::Lv),
+    (0xD61D, 0xD637, GraphemeBreak::Lvt),
+    (0xD638, 0xD638, GraphemeBreak::Lv),
+    (0xD639, 0xD653, GraphemeBreak::Lvt),
+    (0xD654, 0xD654, GraphemeBreak::Lv),
+    (0xD655, 0xD66F, GraphemeBreak::Lvt),
+    (0xD670, 0xD670, GraphemeBreak::Lv),
+    (0xD671, 0xD68B, GraphemeBreak::Lvt),
+    (0xD68C, 0xD68C, GraphemeBreak::Lv),
+    (0xD68D, 0xD6A7, GraphemeBreak::Lvt),
+    (0xD6A8, 0xD6A8, GraphemeBreak::Lv),
+    (0xD6A9, 0xD6C3, GraphemeBreak::Lvt),
+    (0xD6C4, 0xD6C4, GraphemeBreak::Lv),
+    (0xD6C5, 0xD6DF, GraphemeBreak::Lvt),
+    (0xD6E0, 0xD6E0, GraphemeBreak::Lv),
+    (0xD6E1, 0xD6FB, GraphemeBreak::Lvt),
+    (0xD6FC, 0xD6FC, GraphemeBreak::Lv),
+    (0xD6FD, 0xD717, GraphemeBreak::Lvt),
+    (0xD718, 0xD718, GraphemeBreak::Lv),
+    (0xD719, 0xD733, GraphemeBreak::Lvt),
+    (0xD734, 0xD734, GraphemeBreak::Lv),
+    (0xD735, 0xD74F, GraphemeBreak::Lvt),
+    (0xD750, 0xD750, GraphemeBreak::Lv),
+    (0xD751, 0xD76B, GraphemeBreak::Lvt),
+    (0xD76C, 0xD76C, GraphemeBreak::Lv),
+    (0xD76D, 0xD787, GraphemeBreak::Lvt),
+    (0xD788, 0xD788, GraphemeBreak::Lv),
+    (0xD789, 0xD7A3, GraphemeBreak::Lvt),
+    (0xD7B0, 0xD7C6, GraphemeBreak::V),
+    (0xD7CB, 0xD7FB, GraphemeBreak::T),
+    (0xFB1E, 0xFB1E, GraphemeBreak::Extend),
+    (0xFE00, 0xFE0F, GraphemeBreak::Extend),
+    (0xFE20, 0xFE2F, GraphemeBreak::Extend),
+    (0xFEFF, 0xFEFF, GraphemeBreak::Control),
+    (0xFF9E, 0xFF9F, GraphemeBreak::Extend),
+    (0xFFF0, 0xFFFB, GraphemeBreak::Control),
+    (0x101FD, 0x101FD, GraphemeBreak::Extend),
+    (0x102E0, 0x102E0, GraphemeBreak::Extend),
+    (0x10376, 0x1037A, GraphemeBreak::Extend),
+    (0x10A01, 0x10A03, GraphemeBreak::Extend),
+    (0x10A05, 0x10A06, GraphemeBreak::Extend),
+    (0x10A0C, 0x10A0F, GraphemeBreak::Extend),
+    (0x10A38, 0x10A3A, GraphemeBreak::Extend),
+    (0x10A3F, 0x10A3F, GraphemeBreak::Extend),
+    (0x10AE5, 0x10AE6, GraphemeBreak::Extend),
+    (0x10D24, 0x10D27, GraphemeBreak::Extend),
+    (0x10EAB, 0x10EAC, GraphemeBreak::Extend),
+    (0x10EFD, 0x10EFF, GraphemeBreak::Extend),
+    (0x10F46, 0x10F50, GraphemeBreak::Extend),
+    (0x10F82, 0x10F85, GraphemeBreak::Extend),
+    (0x11000, 0x11000, GraphemeBreak::SpacingMark),
+    (0x11001, 0x11001, GraphemeBreak::Extend),
+    (0x11002, 0x11002, GraphemeBreak::SpacingMark),
+    (0x11038, 0x11046, GraphemeBreak::Extend),
+    (0x11070, 0x11070, GraphemeBreak::Extend),
+    (0x11073, 0x11074, GraphemeBreak::Extend),
+    (0x1107F, 0x11081, GraphemeBreak::Extend),
+    (0x11082, 0x11082, GraphemeBreak::SpacingMark),
+    (0x110B0, 0x110B2, GraphemeBreak::SpacingMark),
+    (0x110B3, 0x110B6, GraphemeBreak::Extend),
+    (0x110B7, 0x110B8, GraphemeBreak::SpacingMark),
+    (0x110B9, 0x110BA, GraphemeBreak::Extend),
+    (0x110BD, 0x110BD, GraphemeBreak::Prepend),
+    (0x110C2, 0x110C2, GraphemeBreak::Extend),
+    (0x110CD, 0x110CD, GraphemeBreak::Prepend),
+    (0x11100, 0x11102, GraphemeBreak::Extend),
+    (0x11127, 0x1112B, GraphemeBreak::Extend),
+    (0x1112C, 0x1112C, GraphemeBreak::SpacingMark),
+    (0x1112D, 0x11134, GraphemeBreak::Extend),
+    (0x11145, 0x11146, GraphemeBreak::SpacingMark),
+    (0x11173, 0x11173, GraphemeBreak::Extend),
+    (0x11180, 0x11181, GraphemeBreak::Extend),
+    (0x11182, 0x11182, GraphemeBreak::SpacingMark),
+    (0x111B3, 0x111B5, GraphemeBreak::SpacingMark),
+    (0x111B6, 0x111BE, GraphemeBreak::Extend),
+    (0x111BF, 0x111C0, GraphemeBreak::SpacingMark),
+    (0x111C2, 0x111C3, GraphemeBreak::Prepend),
+    (0x111C9, 0x111CC, GraphemeBreak::Extend),
+    (0x111CE, 0x111CE, GraphemeBreak::SpacingMark),
+    (0x111CF, 0x111CF, GraphemeBreak::Extend),
+    (0x1122C, 0x1122E, GraphemeBreak::SpacingMark),
+    (0x1122F, 0x11231, GraphemeBreak::Extend),
+    (0x11232, 0x11233, GraphemeBreak::SpacingMark),
+    (0x11234, 0x11234, GraphemeBreak::Extend),
+    (0x11235, 0x11235, GraphemeBreak::SpacingMark),
+    (0x11236, 0x11237, GraphemeBreak::Extend),
+    (0x1123E, 0x1123E, GraphemeBreak::Extend),
+    (0x11241, 0x11241, GraphemeBreak::Extend),
+    (0x112DF, 0x112DF, GraphemeBreak::Extend),
+    (0x112E0, 0x112E2, GraphemeBreak::SpacingMark),
+    (0x112E3, 0x112EA, GraphemeBreak::Extend),
+    (0x11300, 0x11301, GraphemeBreak::Extend),
+    (0x11302, 0x11303, GraphemeBreak::SpacingMark),
+    (0x1133B, 0x1133C, GraphemeBreak::Extend),
+    (0x1133E, 0x1133E, GraphemeBreak::Extend),
+    (0x1133F, 0x1133F, GraphemeBreak::SpacingMark),
+    (0x11340, 0x11340, GraphemeBreak::Extend),
+    (0x11341, 0x11344, GraphemeBreak::SpacingMark),
+    (0x11347, 0x11348, GraphemeBreak::SpacingMark),
+    (0x1134B, 0x1134D, GraphemeBreak::SpacingMark),
+    (0x11357, 0x11357, GraphemeBreak::Extend),
+    (0x11362, 0x11363, GraphemeBreak::SpacingMark),
+    (0x11366, 0x1136C, GraphemeBreak::Extend),
+    (0x11370, 0x11374, GraphemeBreak::Extend),
+    (0x11435, 0x11437, GraphemeBreak::SpacingMark),
+    (0x11438, 0x1143F, GraphemeBreak::Extend),
+    (0x11440, 0x11441, GraphemeBreak::SpacingMark),
+    (0x11442, 0x11444, GraphemeBreak::Extend),
+    (0x11445, 0x11445, GraphemeBreak::SpacingMark),
+    (0x11446, 0x11446, GraphemeBreak::Extend),
+    (0x1145E, 0x1145E, GraphemeBreak::Extend),
+    (0x114B0, 0x114B0, GraphemeBreak::Extend),
+    (0x114B1, 0x114B2, GraphemeBreak::SpacingMark),
+    (0x114B3, 0x114B8, GraphemeBreak::Extend),
+    (0x114B9, 0x114B9, GraphemeBreak::SpacingMark),
+    (0x114BA, 0x114BA, GraphemeBreak::Extend),
+    (0x114BB, 0x114BC, GraphemeBreak::SpacingMark),
+    (0x114BD, 0x114BD, GraphemeBreak::Extend),
+    (0x114BE, 0x114BE, GraphemeBreak::SpacingMark),
+    (0x114BF, 0x114C0, GraphemeBreak::Extend),
+    (0x114C1, 0x114C1, GraphemeBreak::SpacingMark),
+    (0x114C2, 0x114C3, GraphemeBreak::Extend),
+    (0x115AF, 0x115AF, GraphemeBreak::Extend),
+    (0x115B0, 0x115B1, GraphemeBreak::SpacingMark),
+    (0x115B2, 0x115B5, GraphemeBreak::Extend),
+    (0x115B8, 0x115BB, GraphemeBreak::SpacingMark),
+    (0x115BC, 0x115BD, GraphemeBreak::Extend),
+    (0x115BE, 0x115BE, GraphemeBreak::SpacingMark),
+    (0x115BF, 0x115C0, GraphemeBreak::Extend),
+    (0x115DC, 0x115DD, GraphemeBreak::Extend),
+    (0x11630, 0x11632, GraphemeBreak::SpacingMark),
+    (0x11633, 0x1163A, GraphemeBreak::Extend),
+    (0x1163B, 0x1163C, GraphemeBreak::SpacingMark),
+    (0x1163D, 0x1163D, GraphemeBreak::Extend),
+    (0x1163E, 0x1163E, GraphemeBreak::SpacingMark),
+    (0x1163F, 0x11640, GraphemeBreak::Extend),
+    (0x116AB, 0x116AB, GraphemeBreak::Extend),
+    (0x116AC, 0x116AC, GraphemeBreak::SpacingMark),
+    (0x116AD, 0x116AD, GraphemeBreak::Extend),
+    (0x116AE, 0x116AF, GraphemeBreak::SpacingMark),
+    (0x116B0, 0x116B5, GraphemeBreak::Extend),
+    (0x116B6, 0x116B6, GraphemeBreak::SpacingMark),
+    (0x116B7, 0x116B7, GraphemeBreak::Extend),
+    (0x1171D, 0x1171F, GraphemeBreak::Extend),
+    (0x11722, 0x11725, GraphemeBreak::Extend),
+    (0x11726, 0x11726, GraphemeBreak::SpacingMark),
+    (0x11727, 0x1172B, GraphemeBreak::Extend),
+    (0x1182C, 0x1182E, GraphemeBreak::SpacingMark),
+    (0x1182F, 0x11837, GraphemeBreak::Extend),
+    (0x11838, 0x11838, GraphemeBreak::SpacingMark),
+    (0x11839, 0x1183A, GraphemeBreak::Extend),
+    (0x11930, 0x11930, GraphemeBreak::Extend),
+    (0x11931, 0x11935, GraphemeBreak::SpacingMark),
+    (0x11937, 0x11938, GraphemeBreak::SpacingMark),
+    (0x1193B, 0x1193C, GraphemeBreak::Extend),
+    (0x1193D, 0x1193D, GraphemeBreak::SpacingMark),
+    (0x1193E, 0x1193E, GraphemeBreak::Extend),
+    (0x1193F, 0x1193F, GraphemeBreak::Prepend),
+    (0x11940, 0x11940, GraphemeBreak::SpacingMark),
+    (0x11941, 0x11941, GraphemeBreak::Prepend),
+    (0x11942, 0x11942, GraphemeBreak::SpacingMark),
+    (0x11943, 0x11943, GraphemeBreak::Extend),
+    (0x119D1, 0x119D3, GraphemeBreak::SpacingMark),
+    (0x119D4, 0x119D7, GraphemeBreak::Extend),
+    (0x119DA, 0x119DB, GraphemeBreak::Extend),
+    (0x119DC, 0x119DF, GraphemeBreak::SpacingMark),
+    (0x119E0, 0x119E0, GraphemeBreak::Extend),
+    (0x119E4, 0x119E4, GraphemeBreak::SpacingMark),
+    (0x11A01, 0x11A0A, GraphemeBreak::Extend),
+    (0x11A33, 0x11A38, GraphemeBreak::Extend),
+    (0x11A39, 0x11A39, GraphemeBreak::SpacingMark),
+    (0x11A3A, 0x11A3A, GraphemeBreak::Prepend),
+    (0x11A3B, 0x11A3E, GraphemeBreak::Extend),
+    (0x11A47, 0x11A47, GraphemeBreak::Extend),
+    (0x11A51, 0x11A56, GraphemeBreak::Extend),
+    (0x11A57, 0x11A58, GraphemeBreak::SpacingMark),
+    (0x11A59, 0x11A5B, GraphemeBreak::Extend),
+    (0x11A84, 0x11A89, GraphemeBreak::Prepend),
+    (0x11A8A, 0x11A96, GraphemeBreak::Extend),
+    (0x11A97, 0x11A97, GraphemeBreak::SpacingMark),
+    (0x11A98, 0x11A99, GraphemeBreak::Extend),
+    (0x11C2F, 0x11C2F, GraphemeBreak::SpacingMark),
+    (0x11C30, 0x11C36, GraphemeBreak::Extend),
+    (0x11C38, 0x11C3D, GraphemeBreak::Extend),
+    (0x11C3E, 0x11C3E, GraphemeBreak::SpacingMark),
+    (0x11C3F, 0x11C3F, GraphemeBreak::Extend),
+    (0x11C92, 0x11CA7, GraphemeBreak::Extend),
+    (0x11CA9, 0x11CA9, GraphemeBreak::SpacingMark),
+    (0x11CAA, 0x11CB0, GraphemeBreak::Extend),
+    (0x11CB1, 0x11CB1, GraphemeBreak::SpacingMark),
+    (0x11CB2, 0x11CB3, GraphemeBreak::Extend),
+    (0x11CB4, 0x11CB4, GraphemeBreak::SpacingMark),
+    (0x11CB5, 0x11CB6, GraphemeBreak::Extend),
+    (0x11D31, 0x11D36, GraphemeBreak::Extend),
+    (0x11D3A, 0x11D3A, GraphemeBreak::Extend),
+    (0x11D3C, 0x11D3D, GraphemeBreak::Extend),
+    (0x11D3F, 0x11D45, GraphemeBreak::Extend),
+    (0x11D46, 0x11D46, GraphemeBreak::Prepend),
+    (0x11D47, 0x11D47, GraphemeBreak::Extend),
+    (0x11D8A, 0x11D8E, GraphemeBreak::SpacingMark),
+    (0x11D90, 0x11D91, GraphemeBreak::Extend),
+    (0x11D93, 0x11D94, GraphemeBreak::SpacingMark),
+    (0x11D95, 0x11D95, GraphemeBreak::Extend),
+    (0x11D96, 0x11D96, GraphemeBreak::SpacingMark),
+    (0x11D97, 0x11D97, GraphemeBreak::Extend),
+    (0x11EF3, 0x11EF4, GraphemeBreak::Extend),
+    (0x11EF5, 0x11EF6, GraphemeBreak::SpacingMark),
+    (0x11F00, 0x11F01, GraphemeBreak::Extend),
+    (0x11F02, 0x11F02, GraphemeBreak::Prepend),
+    (0x11F03, 0x11F03, GraphemeBreak::SpacingMark),
+    (0x11F34, 0x11F35, GraphemeBreak::SpacingMark),
+    (0x11F36, 0x11F3A, GraphemeBreak::Extend),
+    (0x11F3E, 0x11F3F, GraphemeBreak::SpacingMark),
+    (0x11F40, 0x11F40, GraphemeBreak::Extend),
+    (0x11F41, 0x11F41, GraphemeBreak::SpacingMark),
+    (0x11F42, 0x11F42, GraphemeBreak::Extend),
+    (0x13430, 0x1343F, GraphemeBreak::Control),
+    (0x13440, 0x13440, GraphemeBreak::Extend),
+    (0x13447, 0x13455, GraphemeBreak::Extend),
+    (0x16AF0, 0x16AF4, GraphemeBreak::Extend),
+    (0x16B30, 0x16B36, GraphemeBreak::Extend),
+    (0x16F4F, 0x16F4F, GraphemeBreak::Extend),
+    (0x16F51, 0x16F87, GraphemeBreak::SpacingMark),
+    (0x16F8F, 0x16F92, GraphemeBreak::Extend),
+    (0x16FE4, 0x16FE4, GraphemeBreak::Extend),
+    (0x16FF0, 0x16FF1, GraphemeBreak::SpacingMark),
+    (0x1BC9D, 0x1BC9E, GraphemeBreak::Extend),
+    (0x1BCA0, 0x1BCA3, GraphemeBreak::Control),
+    (0x1CF00, 0x1CF2D, GraphemeBreak::Extend),
+    (0x1CF30, 0x1CF46, GraphemeBreak::Extend),
+    (0x1D165, 0x1D165, GraphemeBreak::Extend),
+    (0x1D166, 0x1D166, GraphemeBreak::SpacingMark),
+    (0x1D167, 0x1D169, GraphemeBreak::Extend),
+    (0x1D16D, 0x1D16D, GraphemeBreak::SpacingMark),
+    (0x1D16E, 0x1D172, GraphemeBreak::Extend),
+    (0x1D173, 0x1D17A, GraphemeBreak::Control),
+    (0x1D17B, 0x1D182, GraphemeBreak::Extend),
+    (0x1D185, 0x1D18B, GraphemeBreak::Extend),
+    (0x1D1AA, 0x1D1AD, GraphemeBreak::Extend),
+    (0x1D242, 0x1D244, GraphemeBreak::Extend),
+    (0x1DA00, 0x1DA36, GraphemeBreak::Extend),
+    (0x1DA3B, 0x1DA6C, GraphemeBreak::Extend),
+    (0x1DA75, 0x1DA75, GraphemeBreak::Extend),
+    (0x1DA84, 0x1DA84, GraphemeBreak::Extend),
+    (0x1DA9B, 0x1DA9F, GraphemeBreak::Extend),
+    (0x1DAA1, 0x1DAAF, GraphemeBreak::Extend),
+    (0x1E000, 0x1E006, GraphemeBreak::Extend),
+    (0x1E008, 0x1E018, GraphemeBreak::Extend),
+    (0x1E01B, 0x1E021, GraphemeBreak::Extend),
+    (0x1E023, 0x1E024, GraphemeBreak::Extend),
+    (0x1E026, 0x1E02A, GraphemeBreak::Extend),
+    (0x1E08F, 0x1E08F, GraphemeBreak::Extend),
+    (0x1E130, 0x1E136, GraphemeBreak::Extend),
+    (0x1E2AE, 0x1E2AE, GraphemeBreak::Extend),
+    (0x1E2EC, 0x1E2EF, GraphemeBreak::Extend),
+    (0x1E4EC, 0x1E4EF, GraphemeBreak::Extend),
+    (0x1E8D0, 0x1E8D6, GraphemeBreak::Extend),
+    (0x1E944, 0x1E94A, GraphemeBreak::Extend),
+    (0x1F1E6, 0x1F1FF, GraphemeBreak::RegionalIndicator),
+    (0x1F3FB, 0x1F3FF, GraphemeBreak::Extend),
+    (0xE0000, 0xE001F, GraphemeBreak::Control),
+    (0xE0020, 0xE007F, GraphemeBreak::Extend),
+    (0xE0080, 0xE00FF, GraphemeBreak::Control),
+    (0xE0100, 0xE01EF, GraphemeBreak::Extend),
+    (0xE01F0, 0xE0FFF, GraphemeBreak::Control),
+];
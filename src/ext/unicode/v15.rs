@@ -0,0 +1,94 @@
+//! A Unicode 15.1 [version provider](XtermUnicodeVersionProvider) implemented
+//! in Rust.
+
+use super::{in_table, tables, CharProperties, XtermUnicodeVersionProvider};
+use crate::xterm::{Str, WideCharacterWidth};
+
+/// Character widths from Unicode 15.1's East Asian Width property, following
+/// Markus Kuhn's [`wcwidth`].
+///
+/// Combining marks, format characters and Hangul Jamo medial vowels and final
+/// consonants (including those in Hangul Jamo Extended-B) are zero width; East
+/// Asian Wide and Fullwidth characters (which includes emoji with emoji
+/// presentation) are two columns wide; everything else (including ambiguous
+/// width characters) is one column wide.
+///
+/// Codepoints are grouped into grapheme clusters (see
+/// [`CharProperties::with_graphemes`]) so that emoji ZWJ sequences, flags and
+/// characters with combining marks each occupy a single cluster of cells.
+/// The tables are Unicode 15.1's but the clustering rules are Unicode 15.0's:
+/// rule `GB9c`, which 15.1 added to keep Indic conjuncts together, isn't
+/// implemented, so conjuncts are split after the virama.
+///
+/// Register this with [`UnicodeHandling::register_version_provider`] and then
+/// switch to it with [`UnicodeHandling::set_active_version`]:
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "proposed-api")] {
+/// # use xterm_js_sys::{ext::unicode::UnicodeV15, xterm::ProposedTerminal};
+/// # let terminal = ProposedTerminal::new(None);
+/// let unicode = terminal.unicode();
+/// unicode.register_version_provider(&UnicodeV15);
+/// unicode.set_active_version(UnicodeV15::VERSION.into());
+/// # }
+/// ```
+///
+/// [`wcwidth`]: http://www.cl.cam.ac.uk/~mgk25/ucs/wcwidth.c
+/// [`UnicodeHandling::register_version_provider`]: crate::xterm::UnicodeHandling::register_version_provider
+/// [`UnicodeHandling::set_active_version`]: crate::xterm::UnicodeHandling::set_active_version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct UnicodeV15;
+
+impl UnicodeV15 {
+    /// The version name this provider registers itself under.
+    pub const VERSION: &'static str = "15.1";
+
+    /// The version of Unicode the width tables were generated from.
+    pub const UNICODE_VERSION: (u8, u8, u8) = tables::UNICODE_VERSION;
+
+    /// Gets the width of a codepoint.
+    ///
+    /// C0 and C1 control characters are zero width (xterm.js handles them
+    /// before asking for a width anyway).
+    #[must_use]
+    pub fn width(codepoint: u32) -> WideCharacterWidth {
+        use WideCharacterWidth::{_0, _1, _2};
+
+        match codepoint {
+            0..=0x1F | 0x7F..=0x9F => _0,
+            0x20..=0x7E => _1,
+            _ if in_table(tables::ZERO_WIDTH, codepoint) => _0,
+            _ if in_table(tables::WIDE, codepoint) => _2,
+            _ => _1,
+        }
+    }
+
+    /// Whether a codepoint is `Extended_Pictographic`: an emoji, something
+    /// emoji-like, or a codepoint reserved for future emoji.
+    ///
+    /// Note that many of these default to text presentation and are one
+    /// column wide unless followed by U+FE0F.
+    #[must_use]
+    pub fn is_emoji(codepoint: u32) -> bool {
+        in_table(tables::EXTENDED_PICTOGRAPHIC, codepoint)
+    }
+}
+
+impl XtermUnicodeVersionProvider for UnicodeV15 {
+    fn version(&self) -> Str {
+        Self::VERSION.into()
+    }
+
+    fn wcwidth(&self, codepoint: u32) -> WideCharacterWidth {
+        Self::width(codepoint)
+    }
+
+    fn char_properties(&self, codepoint: u32, preceding: u32) -> u32 {
+        CharProperties::with_graphemes(
+            codepoint,
+            Self::width(codepoint),
+            CharProperties(preceding),
+        )
+        .0
+    }
+}
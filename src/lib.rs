@@ -73,9 +73,27 @@ fn idx_to_opt(idx: i32) -> Option<u32> {
 mod readonly_array;
 pub use readonly_array::ReadOnlyArray;
 
+#[cfg(all(feature = "xterm-4", feature = "xterm-5"))]
+compile_error!(
+    "the `xterm-4` and `xterm-5` features are mutually exclusive; enable the \
+     one that matches your version of xterm.js"
+);
+
+#[cfg(not(any(feature = "xterm-4", feature = "xterm-5")))]
+compile_error!(
+    "enable either the `xterm-4` or the `xterm-5` feature, to match your \
+     version of xterm.js (with `default-features = false`, add \
+     `features = [\"xterm-5\"]` for xterm.js 5)"
+);
+
+#[cfg(all(feature = "xterm-4", feature = "addon-image"))]
+compile_error!("the image addon needs xterm.js 5 (the `xterm-5` feature)");
+
 pub mod xterm;
 pub use xterm::Terminal;
 
+pub mod addons;
+
 #[cfg(feature = "crossterm-support")]
 #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "crossterm-support")))]
 pub mod crossterm_support;
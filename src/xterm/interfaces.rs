@@ -142,7 +142,8 @@ extern "C" {
 
 #[wasm_bindgen(module = "xterm")]
 extern "C" {
-    /// **[EXPERIMENTAL]** Unicode handling interface.
+    /// **[EXPERIMENTAL]** Unicode handling interface; available from
+    /// `ProposedTerminal::unicode` (with the `proposed-api` feature).
     ///
     /// (This is a [duck-typed interface]).
     ///
@@ -197,9 +198,60 @@ extern "C" {
     pub fn char_properties(this: &UnicodeVersionProvider, codepoint: u32, preceding: u32) -> u32;
 }
 
+#[cfg(feature = "xterm-5")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
 #[wasm_bindgen(module = "xterm")]
 extern "C" {
-    /// **[EXPERIMENTAL]** Parser interface.
+    /// A replacement logger for `console`, set with
+    /// [`TerminalOptions::logger`].
+    ///
+    /// Each method takes a message along with any number of extra arguments
+    /// (like `console.log`).
+    ///
+    /// (This is a [duck-typed interface]; an implementation that forwards to
+    /// the `log` crate is available [here] when the `ext` feature is enabled).
+    ///
+    /// [`TerminalOptions::logger`]: super::options::TerminalOptions::logger
+    /// [duck-typed interface]: https://rustwasm.github.io/docs/wasm-bindgen/reference/working-with-duck-typed-interfaces.html
+    /// [here]: crate::ext::logger::LogForwarder
+    #[derive(Debug, Clone, PartialEq)]
+    pub type Logger;
+
+    /// Logs a trace message; this will only be called if the log level is set
+    /// to trace.
+    #[wasm_bindgen(structural, method, variadic, js_name = trace)]
+    pub fn trace(this: &Logger, message: &str, args: Box<[JsValue]>);
+
+    /// Logs a debug message; this will only be called if the log level is set
+    /// to debug or below.
+    #[wasm_bindgen(structural, method, variadic, js_name = debug)]
+    pub fn debug(this: &Logger, message: &str, args: Box<[JsValue]>);
+
+    /// Logs an info message; this will only be called if the log level is set
+    /// to info or below.
+    #[wasm_bindgen(structural, method, variadic, js_name = info)]
+    pub fn info(this: &Logger, message: &str, args: Box<[JsValue]>);
+
+    /// Logs a warning message; this will only be called if the log level is
+    /// set to warn or below.
+    #[wasm_bindgen(structural, method, variadic, js_name = warn)]
+    pub fn warn(this: &Logger, message: &str, args: Box<[JsValue]>);
+
+    /// Logs an error message; this will only be called if the log level is
+    /// set to error or below.
+    #[wasm_bindgen(structural, method, variadic, js_name = error)]
+    pub fn error(this: &Logger, message: &str, args: Box<[JsValue]>);
+}
+
+#[cfg(any(feature = "xterm-5", feature = "proposed-api"))]
+#[cfg_attr(
+    all(docs, not(doctest)),
+    doc(cfg(any(feature = "xterm-5", feature = "proposed-api")))
+)]
+#[wasm_bindgen(module = "xterm")]
+extern "C" {
+    /// Parser interface; see `Terminal::parser` (a proposed API, on
+    /// `ProposedTerminal`, in xterm.js 4).
     ///
     /// (This is a [duck-typed interface]).
     ///
@@ -9,6 +9,8 @@
 //! - [`buffer`] - Buffer-related interfaces and types
 //! - [`interfaces`] - Core interfaces like Disposable, Marker, etc.
 //! - [`terminal`] - The main Terminal implementation
+//! - `proposed` - Proposed APIs, on a `ProposedTerminal` (with the
+//!   `proposed-api` feature)
 //!
 //! For detailed information about interface mirroring and design patterns,
 //! see the original xterm.rs documentation and individual module documentation.
@@ -18,6 +20,8 @@ pub use buffer::*;
 pub use events::*;
 pub use interfaces::*;
 pub use options::*;
+#[cfg(feature = "proposed-api")]
+pub use proposed::*;
 pub use terminal::*;
 pub use types::*;
 
@@ -25,5 +29,8 @@ pub mod buffer;
 pub mod events;
 pub mod interfaces;
 pub mod options;
+#[cfg(feature = "proposed-api")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "proposed-api")))]
+pub mod proposed;
 pub mod terminal;
 pub mod types;
@@ -1,19 +1,40 @@
 //! Terminal configuration and options for xterm.js bindings.
 
+#[cfg(feature = "xterm-5")]
+use super::interfaces::Logger;
 use super::types::*;
 use wasm_bindgen::prelude::*;
 
+/// Defines an option struct: its fields, a constructor, getters and setters
+/// for the fields that aren't `pub`, `with_*` builders and conversions to
+/// and from plain JS objects.
+///
+/// Structs marked `#[serde::skip_unset]` (those that derive serde's traits)
+/// leave their unset fields out when serialized, like `to_object` does.
+// Only the addons' structs aren't marked, so without them a rule goes unused.
+#[allow(unused_macro_rules)]
 macro_rules! wasm_struct {
+    (#[serde::skip_unset] $($rest:tt)+) => {
+        wasm_struct! {
+            @serde(feature = "serde") $($rest)+
+        }
+    };
+    (# $($rest:tt)+) => {
+        wasm_struct! { @serde(any()) # $($rest)+ }
+    };
     (
+        @serde($serde:meta)
         $(#[constructor::skip = $const_skip_reason:literal])?
         #[wasm_bindgen $(( $($wb_opts:tt)* ))? ]
         $(#[$metas:meta])*
         pub struct $nom:ident {
             $(
                 $(#[doc = $docs_field:literal])*
-                $(#[wasm_bindgen($($wasm_opt:ident$( = $wasm_val:tt)?),+)])?
+                $(#[cfg($($cfg:tt)+)])?
+                $(#[wasm_bindgen(js_name = $js_field:ident)])?
                 // $(#[$metas_field:meta])*
                 $(#[deprecated($($depr:tt)+)])?
+                $(#[serde($($serde_opt:tt)+)])?
                 $(pub $field:ident: $field_ty:ty)?
                 $(|
                     clone(
@@ -32,9 +53,17 @@ macro_rules! wasm_struct {
         pub struct $nom {
             $(
                 $(#[doc = $docs_field])*
-                $(#[wasm_bindgen($($wasm_opt$( = $wasm_val)?),+)])?
+                $(#[cfg($($cfg)+)])?
+                $(#[wasm_bindgen(js_name = $js_field)])?
                 // $(#[$metas_field])*
                 $(#[deprecated($($depr)+)])?
+                $(#[cfg_attr(feature = "serde", serde($($serde_opt)+))])?
+                #[cfg_attr(
+                    $serde,
+                    serde(
+                        skip_serializing_if = "crate::xterm::options::is_unset"
+                    )
+                )]
                 $(pub $field: $field_ty)?
                 $(
                     $(
@@ -57,12 +86,14 @@ macro_rules! wasm_struct {
             #[must_use]
             pub const fn new(
                 $(
+                    $(#[cfg($($cfg)+)])?
                     $($field: $field_ty,)?
                     $($priv_field: $priv_field_ty,)?
                 )+
             ) -> Self {
                 Self {
                     $(
+                        $(#[cfg($($cfg)+)])?
                         $($field,)?
                         $($priv_field,)?
                     )+
@@ -74,9 +105,10 @@ macro_rules! wasm_struct {
         impl $nom {
             $(
                 $(#[doc = $docs_field])*
+                $(#[cfg($($cfg)+)])?
 
-                // Some garbage to swallow the doc comment in case we're not
-                // dealing with a private field:
+                // Some garbage to swallow the doc comment (and `cfg`) in case
+                // we're not dealing with a private field:
                 $(
                     #[allow(unused_doc_comments)]
                     #[cfg(__never__)]
@@ -93,6 +125,7 @@ macro_rules! wasm_struct {
                 )?
 
                 $(#[doc = $docs_field])*
+                $(#[cfg($($cfg)+)])?
 
                 // Again: garbage to swallow the doc comment.
                 $(
@@ -110,12 +143,374 @@ macro_rules! wasm_struct {
                 )?
             )*
         }
+
+        ::paste::paste! {
+            impl $nom {
+                $(
+                    $(#[cfg($($cfg)+)])?
+                    $(#[deprecated($($depr)+)])?
+                    $(
+                        #[doc = concat!(
+                            "Sets [`", stringify!($field), "`](Self::",
+                            stringify!($field), ") and returns `self`.",
+                        )]
+                        #[allow(deprecated)]
+                        #[must_use]
+                        pub fn [<with_ $field>](
+                            mut self,
+                            $field: impl $crate::xterm::FieldValue<$field_ty>,
+                        ) -> Self {
+                            self.$field =
+                                $crate::xterm::FieldValue::into_field($field);
+                            self
+                        }
+                    )?
+                    $(
+                        #[doc = concat!(
+                            "Sets [`", stringify!($priv_field), "`](Self::",
+                            stringify!($priv_field), ") and returns `self`.",
+                        )]
+                        #[must_use]
+                        pub fn [<with_ $priv_field>](
+                            mut self,
+                            $priv_field: impl $crate::xterm::FieldValue<
+                                $priv_field_ty,
+                            >,
+                        ) -> Self {
+                            self.$priv_field =
+                                $crate::xterm::FieldValue::into_field(
+                                    $priv_field,
+                                );
+                            self
+                        }
+                    )?
+                )*
+            }
+        }
+
+        impl $nom {
+            #[doc = concat!(
+                "Reads a `", stringify!($nom), "` off of a plain JS object ",
+                "with the same fields (i.e. one that xterm.js hands back, ",
+                "which wasm-bindgen can't convert).\n\n",
+                "Fields that are missing or have the wrong type are left ",
+                "unset; returns `None` if `object` isn't an object or is ",
+                "missing a required field.",
+            )]
+            #[allow(deprecated)]
+            #[must_use]
+            pub fn from_object(
+                object: &::wasm_bindgen::JsValue,
+            ) -> Option<Self> {
+                if !object.is_object() {
+                    return None;
+                }
+                let get = |name: &str| {
+                    ::js_sys::Reflect::get(object, &name.into())
+                        .unwrap_or(::wasm_bindgen::JsValue::UNDEFINED)
+                };
+
+                Some(Self {
+                    $(
+                        $(#[cfg($($cfg)+)])?
+                        $($field: $crate::xterm::FromJsField::from_js_field(
+                            &get(stringify!($js_field)),
+                        )?,)?
+                        $($priv_field: $crate::xterm::FromJsField::from_js_field(
+                            &get(stringify!($js_name)),
+                        )?,)?
+                    )+
+                })
+            }
+        }
+
+        impl $nom {
+            #[doc = concat!(
+                "Converts to a plain JS object with the fields that are set ",
+                "(under xterm.js's names).\n\n",
+                "Passing a `", stringify!($nom), "` to JS directly makes a ",
+                "class instance whose fields are getters, which xterm.js ",
+                "doesn't see when it copies options with `for..in`.",
+            )]
+            #[allow(deprecated)]
+            #[must_use]
+            pub fn to_object(&self) -> ::js_sys::Object {
+                let object = ::js_sys::Object::new();
+                let set = |name: &str, value| {
+                    if let Some(value) = value {
+                        let _ = ::js_sys::Reflect::set(
+                            &object,
+                            &name.into(),
+                            &value,
+                        );
+                    }
+                };
+
+                $(
+                    $(#[cfg($($cfg)+)])?
+                    $(set(
+                        stringify!($js_field),
+                        $crate::xterm::IntoJsField::to_js_field(&self.$field),
+                    );)?
+                    $(#[cfg($($cfg)+)])?
+                    $(set(
+                        stringify!($js_name),
+                        $crate::xterm::IntoJsField::to_js_field(
+                            &self.$priv_field,
+                        ),
+                    );)?
+                )+
+
+                object
+            }
+        }
+
+        impl $crate::xterm::IntoJsField for $nom {
+            fn to_js_field(&self) -> Option<::wasm_bindgen::JsValue> {
+                Some(self.to_object().into())
+            }
+        }
+
+        impl $crate::xterm::FromJsField for $nom {
+            fn from_js_field(value: &::wasm_bindgen::JsValue) -> Option<Self> {
+                Self::from_object(value)
+            }
+        }
     };
 }
 
+// Addon bindings define their option structs with this too.
+#[cfg(any(
+    feature = "addon-attach",
+    feature = "addon-fit",
+    feature = "addon-image",
+    feature = "addon-search",
+    feature = "addon-serialize",
+    feature = "addon-unicode11",
+))]
+pub(crate) use wasm_struct;
+
+/// Values the `with_*` builders that option structs have for each of their
+/// fields accept, for a field of type `T`.
+///
+/// That's a `T` or, for optional fields, the value by itself (`with_cols(80)`
+/// rather than `with_cols(Some(80))`); string fields also take `&str`s.
+pub trait FieldValue<T> {
+    /// Converts to the field's type.
+    fn into_field(self) -> T;
+}
+
+impl<T> FieldValue<T> for T {
+    fn into_field(self) -> T {
+        self
+    }
+}
+
+impl<T> FieldValue<Option<T>> for T {
+    fn into_field(self) -> Option<T> {
+        Some(self)
+    }
+}
+
+impl FieldValue<Str> for &str {
+    fn into_field(self) -> Str {
+        self.into()
+    }
+}
+
+impl FieldValue<Option<Str>> for &str {
+    fn into_field(self) -> Option<Str> {
+        Some(self.into())
+    }
+}
+
+/// Whether an option struct's field is left out when it's serialized: unset
+/// optional fields are, required fields never are.
+#[cfg(feature = "serde")]
+pub(crate) trait Unset {
+    /// Whether the field is unset.
+    fn is_unset(&self) -> bool;
+}
+
+#[cfg(feature = "serde")]
+impl<T> Unset for Option<T> {
+    fn is_unset(&self) -> bool {
+        self.is_none()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Unset for Str {
+    fn is_unset(&self) -> bool {
+        false
+    }
+}
+
+/// [`Unset::is_unset`] as a function, for `skip_serializing_if`.
+#[cfg(feature = "serde")]
+pub(crate) fn is_unset<T: Unset>(field: &T) -> bool {
+    field.is_unset()
+}
+
+/// Types that the fields of option structs can have, read back from JS; see
+/// the `from_object` method option structs have.
+pub trait FromJsField: Sized {
+    /// Converts from a JS value, if it's of the right type.
+    fn from_js_field(value: &JsValue) -> Option<Self>;
+}
+
+/// Missing (`undefined`) and `null` fields are `None`, as are fields with the
+/// wrong type.
+impl<T: FromJsField> FromJsField for Option<T> {
+    fn from_js_field(value: &JsValue) -> Option<Self> {
+        Some(T::from_js_field(value))
+    }
+}
+
+impl FromJsField for bool {
+    fn from_js_field(value: &JsValue) -> Option<Self> {
+        value.as_bool()
+    }
+}
+
+impl FromJsField for f32 {
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_js_field(value: &JsValue) -> Option<Self> {
+        value.as_f64().map(|value| value as f32)
+    }
+}
+
+impl FromJsField for f64 {
+    fn from_js_field(value: &JsValue) -> Option<Self> {
+        value.as_f64()
+    }
+}
+
+/// Integers must be whole and in range.
+macro_rules! integer_fields {
+    ($($ty:ty),*) => {$(
+        impl FromJsField for $ty {
+            // Range checked (and integral) before it's cast.
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::float_cmp,
+            )]
+            fn from_js_field(value: &JsValue) -> Option<Self> {
+                value
+                    .as_f64()
+                    .filter(|n| n.trunc() == *n)
+                    .filter(|n| (0.0..=f64::from(<$ty>::MAX)).contains(n))
+                    .map(|n| n as $ty)
+            }
+        }
+    )*};
+}
+
+integer_fields!(u16, u32);
+
+impl FromJsField for Str {
+    fn from_js_field(value: &JsValue) -> Option<Self> {
+        value.as_string()
+    }
+}
+
+impl FromJsField for Vec<Str> {
+    fn from_js_field(value: &JsValue) -> Option<Self> {
+        let array = value.dyn_ref::<js_sys::Array>()?;
+        array.iter().map(|value| value.as_string()).collect()
+    }
+}
+
+/// The string enums (and [`FontWeight`]) have `from_js_value`.
+macro_rules! enum_fields {
+    ($($(#[$cfg:meta])? $ty:ty),* $(,)?) => {$(
+        $(#[$cfg])?
+        impl FromJsField for $ty {
+            fn from_js_field(value: &JsValue) -> Option<Self> {
+                Self::from_js_value(value)
+            }
+        }
+    )*};
+}
+
+enum_fields! {
+    #[cfg(feature = "xterm-4")] BellStyle,
+    CursorStyle,
+    #[cfg(feature = "xterm-5")] CursorInactiveStyle,
+    FastScrollModifier,
+    FontWeight,
+    LogLevel,
+    MouseTrackingMode,
+    #[cfg(feature = "xterm-4")] RendererType,
+    #[cfg(feature = "xterm-5")] WindowsPty,
+}
+
+#[cfg(feature = "xterm-5")]
+impl FromJsField for Logger {
+    fn from_js_field(value: &JsValue) -> Option<Self> {
+        value.is_object().then(|| value.clone().unchecked_into())
+    }
+}
+
+/// Types that the fields of option structs can have, converted to JS; see the
+/// `to_object` method option structs have.
+pub trait IntoJsField {
+    /// Converts to a JS value; `None` for fields that aren't set.
+    fn to_js_field(&self) -> Option<JsValue>;
+}
+
+impl<T: IntoJsField> IntoJsField for Option<T> {
+    fn to_js_field(&self) -> Option<JsValue> {
+        self.as_ref().and_then(T::to_js_field)
+    }
+}
+
+/// Types that convert to JS by themselves.
+macro_rules! into_js_fields {
+    ($($(#[$cfg:meta])? $ty:ty),* $(,)?) => {$(
+        $(#[$cfg])?
+        impl IntoJsField for $ty {
+            fn to_js_field(&self) -> Option<JsValue> {
+                Some(JsValue::from(self.clone()))
+            }
+        }
+    )*};
+}
+
+into_js_fields! {
+    bool, f32, f64, u16, u32, Str,
+    #[cfg(feature = "xterm-4")] BellStyle,
+    CursorStyle,
+    #[cfg(feature = "xterm-5")] CursorInactiveStyle,
+    FastScrollModifier,
+    FontWeight,
+    LogLevel,
+    MouseTrackingMode,
+    #[cfg(feature = "xterm-4")] RendererType,
+    #[cfg(feature = "xterm-5")] WindowsPty,
+    #[cfg(feature = "xterm-5")] Logger,
+}
+
+impl IntoJsField for Vec<Str> {
+    fn to_js_field(&self) -> Option<JsValue> {
+        let array: js_sys::Array =
+            self.iter().map(|s| JsValue::from(s.as_str())).collect();
+        Some(array.into())
+    }
+}
+
 wasm_struct! {
+#[serde::skip_unset]
 #[wasm_bindgen(inspectable)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "camelCase", deny_unknown_fields),
+    // The `unsafe` is in wasm-bindgen's generated code.
+    allow(clippy::unsafe_derive_deserialize)
+)]
 /// Data type to register a `CSI`, `DCS`, or `ESC` callback in the parser in the
 /// form:
 ///   - ESC I..I F
@@ -153,13 +548,22 @@ pub struct FunctionIdentifier {
 
     /// Final byte, must be in range \\x40 .. \\x7e for CSI and DCS,
     /// \\x30 .. \\x7e for ESC.
+    #[serde(rename = "final")]
     |clone(set = set_final, js_name = final)
     final_byte: Str,
 }}
 
 wasm_struct! {
+#[serde::skip_unset]
 #[wasm_bindgen(inspectable)]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "camelCase", deny_unknown_fields),
+    // The `unsafe` is in wasm-bindgen's generated code.
+    allow(clippy::unsafe_derive_deserialize)
+)]
 /// Contains colors to theme the terminal with.
 ///
 /// (This is really an interface, but we just go and define our own type that
@@ -263,8 +667,16 @@ pub struct Theme {
 }}
 
 wasm_struct! {
+#[serde::skip_unset]
 #[wasm_bindgen(inspectable)]
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "camelCase", deny_unknown_fields),
+    // The `unsafe` is in wasm-bindgen's generated code.
+    allow(clippy::unsafe_derive_deserialize)
+)]
 /// Enable various window manipulation and report features (`CSI Ps ; Ps ; Ps
 /// t`).
 ///
@@ -444,8 +856,16 @@ pub struct WindowOptions {
 }}
 
 wasm_struct! {
+#[serde::skip_unset]
 #[wasm_bindgen(inspectable)]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "camelCase", deny_unknown_fields),
+    // The `unsafe` is in wasm-bindgen's generated code.
+    allow(clippy::unsafe_derive_deserialize)
+)]
 /// Terminal modes as set by SM/DECSET.
 ///
 /// (This is really an interface, but we just go and define our own type that
@@ -494,8 +914,16 @@ pub struct Modes {
 }}
 
 wasm_struct! {
+#[serde::skip_unset]
 #[wasm_bindgen(inspectable)]
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(rename_all = "camelCase", deny_unknown_fields),
+    // The `unsafe` is in wasm-bindgen's generated code.
+    allow(clippy::unsafe_derive_deserialize)
+)]
 /// An object containing start up options for the terminal.
 ///
 /// (This is really an interface, but we just go and define our own type that
@@ -523,10 +951,16 @@ pub struct TerminalOptions {
 
     /// A data uri of the sound to use for the bell when
     /// [`TerminalOptions.bell_style`] = 'sound'.
+    ///
+    /// xterm.js 4 only.
+    #[cfg(feature = "xterm-4")]
     |clone(set = set_bell_sound, js_name = bellSound)
     bell_sound: Option<Str>,
 
     /// The type of the bell notification the terminal will use.
+    ///
+    /// xterm.js 4 only.
+    #[cfg(feature = "xterm-4")]
     #[wasm_bindgen(js_name = bellStyle)]
     pub bell_style: Option<BellStyle>,
 
@@ -560,6 +994,9 @@ pub struct TerminalOptions {
     pub cursor_width: Option<f32>,
 
     /// The style of the cursor when the terminal is not focused.
+    ///
+    /// xterm.js 5 only.
+    #[cfg(feature = "xterm-5")]
     #[wasm_bindgen(js_name = cursorInactiveStyle)]
     pub cursor_inactive_style: Option<CursorInactiveStyle>,
 
@@ -593,7 +1030,7 @@ pub struct TerminalOptions {
 
     /// The font weight used to render non-bold text.
     #[wasm_bindgen(js_name = fontWeight)]
-    pub font_weight: Option<f32>,
+    pub font_weight: Option<FontWeight>,
 
     /// The font weight used to render bold text.
     #[wasm_bindgen(js_name = fontWeightBold)]
@@ -602,6 +1039,9 @@ pub struct TerminalOptions {
     /// Whether to ignore the bracketed paste mode. When true, this will always
     /// paste without the `\x1b[200~` and `\x1b[201~` sequences, even when the
     /// shell enables bracketed mode.
+    ///
+    /// xterm.js 5 only.
+    #[cfg(feature = "xterm-5")]
     #[wasm_bindgen(js_name = ignoreBracketedPasteMode)]
     pub ignore_bracketed_paste_mode: Option<bool>,
 
@@ -628,6 +1068,16 @@ pub struct TerminalOptions {
     #[wasm_bindgen(js_name = logLevel)]
     pub log_level: Option<LogLevel>,
 
+    /// A logger to use instead of `console`.
+    ///
+    /// This is skipped when (de)serializing.
+    ///
+    /// xterm.js 5 only.
+    #[cfg(feature = "xterm-5")]
+    #[serde(skip)]
+    |clone(set = set_logger, js_name = logger)
+    logger: Option<Logger>,
+
     /// Whether holding a modifier key will force normal selection behavior,
     /// regardless of whether the terminal is in mouse events mode. This will
     /// also prevent mouse events from being emitted by the terminal. For
@@ -656,6 +1106,9 @@ pub struct TerminalOptions {
     ///
     ///   - Letter spacing
     ///   - Cursor blinking
+    ///
+    /// xterm.js 4 only.
+    #[cfg(feature = "xterm-4")]
     #[wasm_bindgen(js_name = rendererType)]
     pub renderer_type: Option<RendererType>,
 
@@ -715,6 +1168,9 @@ pub struct TerminalOptions {
     /// When set to 'auto', the backend will be automatically detected based on the
     /// environment. When set to 'conpty', the ConPTY backend will be used. When set
     /// to 'winpty', the WinPTY backend will be used.
+    ///
+    /// xterm.js 5 only.
+    #[cfg(feature = "xterm-5")]
     #[wasm_bindgen(js_name = windowsPty)]
     pub windows_pty: Option<WindowsPty>,
 
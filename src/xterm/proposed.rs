@@ -0,0 +1,223 @@
+//! xterm.js's proposed (experimental) APIs.
+//!
+//! xterm.js throws when one of these is used on a terminal that doesn't have
+//! [`allow_proposed_api`] on. Here they're only available on a
+//! [`ProposedTerminal`], which can only be made from a terminal that has the
+//! option on, so forgetting it is a compile error rather than a runtime one.
+//!
+//! [`allow_proposed_api`]: super::options::TerminalOptions::allow_proposed_api
+
+#[cfg(feature = "xterm-4")]
+use super::interfaces::Parser;
+use super::interfaces::{Marker, UnicodeHandling};
+use super::options::TerminalOptions;
+use super::terminal::Terminal;
+use super::types::Str;
+use crate::ReadOnlyArray;
+use js_sys::Reflect;
+use wasm_bindgen::prelude::*;
+
+use core::ops::Deref;
+
+#[wasm_bindgen(module = "xterm")]
+extern "C" {
+    /// See [`ProposedTerminal::markers`].
+    #[wasm_bindgen(method, getter = markers)]
+    fn markers(this: &Terminal) -> ReadOnlyArray<Marker>;
+
+    /// See [`ProposedTerminal::parser`].
+    #[cfg(feature = "xterm-4")]
+    #[wasm_bindgen(method, getter = parser)]
+    fn parser(this: &Terminal) -> Parser;
+
+    /// See [`ProposedTerminal::unicode`].
+    #[wasm_bindgen(method, getter = unicode)]
+    fn unicode(this: &Terminal) -> UnicodeHandling;
+
+    /// See [`ProposedTerminal::register_marker`].
+    #[cfg(feature = "xterm-4")]
+    #[wasm_bindgen(method, js_name = registerMarker)]
+    fn register_marker(
+        this: &Terminal,
+        cursor_y_offset: Option<i16>,
+    ) -> Option<Marker>;
+
+    /// See [`ProposedTerminal::register_character_joiner`].
+    #[wasm_bindgen(method, js_name = registerCharacterJoiner)]
+    fn register_character_joiner(
+        this: &Terminal,
+        handler: &Closure<dyn FnMut(Str) -> js_sys::Array>,
+    ) -> u32;
+
+    /// See [`ProposedTerminal::deregister_character_joiner`].
+    #[wasm_bindgen(method, js_name = deregisterCharacterJoiner)]
+    fn deregister_character_joiner(this: &Terminal, joiner_id: u32);
+}
+
+/// A [`Terminal`] with [`allow_proposed_api`] on, which unlocks xterm.js's
+/// proposed APIs.
+///
+/// Make one with [`ProposedTerminal::new`] or, for a terminal that already
+/// exists, [`Terminal::into_proposed`]. It derefs to the [`Terminal`] so
+/// everything else is still available.
+///
+/// Note that turning [`allow_proposed_api`] back off (with
+/// [`Terminal::set_options`]) makes the proposed APIs throw again.
+///
+/// [`allow_proposed_api`]: TerminalOptions::allow_proposed_api
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct ProposedTerminal(Terminal);
+
+impl ProposedTerminal {
+    /// Creates a new terminal with `options`, with [`allow_proposed_api`]
+    /// turned on.
+    ///
+    /// [`allow_proposed_api`]: TerminalOptions::allow_proposed_api
+    #[must_use]
+    pub fn new(options: Option<TerminalOptions>) -> Self {
+        let mut options = options.unwrap_or_default();
+        options.allow_proposed_api = Some(true);
+
+        Self(Terminal::new(Some(options)))
+    }
+
+    /// The underlying [`Terminal`].
+    #[must_use]
+    pub fn into_inner(self) -> Terminal {
+        self.0
+    }
+
+    /// **[EXPERIMENTAL]** Get all markers registered against the buffer. If
+    /// the alt buffer is active this will always return `[]`.
+    #[must_use]
+    pub fn markers(&self) -> ReadOnlyArray<Marker> {
+        self.0.markers()
+    }
+
+    /// **[EXPERIMENTAL]** Get the parser interface to register custom escape
+    /// sequence handlers.
+    ///
+    /// (This is only a proposed API in xterm.js 4; with the `xterm-5` feature
+    /// it's `Terminal::parser`.)
+    #[cfg(feature = "xterm-4")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-4")))]
+    #[must_use]
+    pub fn parser(&self) -> Parser {
+        self.0.parser()
+    }
+
+    /// **[EXPERIMENTAL]** Get the Unicode handling interface.
+    ///
+    /// This can be used to register Unicode versions and switch the active
+    /// Unicode version.
+    #[must_use]
+    pub fn unicode(&self) -> UnicodeHandling {
+        self.0.unicode()
+    }
+
+    /// **[EXPERIMENTAL]** Registers a marker at the current cursor position.
+    ///
+    /// Returns the new marker or undefined if the alt buffer is active.
+    ///
+    /// (This is only a proposed API in xterm.js 4; with the `xterm-5` feature
+    /// it's `Terminal::register_marker`.)
+    #[cfg(feature = "xterm-4")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-4")))]
+    #[must_use]
+    pub fn register_marker(
+        &self,
+        cursor_y_offset: Option<i16>,
+    ) -> Option<Marker> {
+        self.0.register_marker(cursor_y_offset)
+    }
+
+    /// **[EXPERIMENTAL]** Registers a character joiner, allowing custom
+    /// sequences of characters to be rendered as a single unit. This is
+    /// useful in particular for rendering ligatures and graphemes, among
+    /// other things.
+    ///
+    /// Each registered character joiner is called with a string of text
+    /// representing a portion of a line in the terminal that can be rendered
+    /// as a single unit. The joiner must return a sorted array, where each
+    /// entry is itself an array of length two, containing the start
+    /// (inclusive) and end (exclusive) index of a substring of the input that
+    /// should be rendered as a single unit.
+    ///
+    /// NOTE: character joiners are only used by the canvas renderer.
+    ///
+    /// Takes `handler`: The function that determines character joins. It is
+    /// called with a string of text that is eligible for joining and returns
+    /// an array where each entry is an array containing the start (inclusive)
+    /// and end (exclusive) indexes of ranges that should be rendered as a
+    /// single unit.
+    ///
+    /// Returns the ID of the new joiner, this can be used to deregister.
+    #[must_use = "the ID is needed to deregister the joiner"]
+    pub fn register_character_joiner(
+        &self,
+        handler: &Closure<dyn FnMut(Str) -> js_sys::Array>,
+    ) -> u32 {
+        self.0.register_character_joiner(handler)
+    }
+
+    /// **[EXPERIMENTAL]** Deregisters the character joiner if one was
+    /// registered.
+    /// NOTE: character joiners are only used by the canvas renderer.
+    ///
+    /// Takes `joiner_id`: The character joiner's ID (returned after register).
+    pub fn deregister_character_joiner(&self, joiner_id: u32) {
+        self.0.deregister_character_joiner(joiner_id);
+    }
+}
+
+impl Terminal {
+    /// Gets at the terminal's proposed APIs, if it has
+    /// [`allow_proposed_api`] on (otherwise the terminal is handed back).
+    ///
+    /// # Errors
+    ///
+    /// Fails (returning the terminal) if [`allow_proposed_api`] is off.
+    ///
+    /// [`allow_proposed_api`]: TerminalOptions::allow_proposed_api
+    pub fn into_proposed(self) -> Result<ProposedTerminal, Terminal> {
+        let allowed = Reflect::get(self.as_ref(), &"options".into())
+            .and_then(|options| {
+                Reflect::get(&options, &"allowProposedApi".into())
+            })
+            .ok()
+            .and_then(|allowed| allowed.as_bool());
+
+        if allowed == Some(true) {
+            Ok(ProposedTerminal(self))
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Deref for ProposedTerminal {
+    type Target = Terminal;
+
+    fn deref(&self) -> &Terminal {
+        &self.0
+    }
+}
+
+impl AsRef<Terminal> for ProposedTerminal {
+    fn as_ref(&self) -> &Terminal {
+        &self.0
+    }
+}
+
+impl AsRef<JsValue> for ProposedTerminal {
+    fn as_ref(&self) -> &JsValue {
+        self.0.as_ref()
+    }
+}
+
+impl From<ProposedTerminal> for Terminal {
+    fn from(terminal: ProposedTerminal) -> Terminal {
+        terminal.0
+    }
+}
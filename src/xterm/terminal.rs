@@ -5,7 +5,6 @@ use super::events::*;
 use super::interfaces::*;
 use super::options::*;
 use super::types::*;
-use crate::ReadOnlyArray;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen(module = "xterm")]
@@ -21,11 +20,21 @@ extern "C" {
     #[wasm_bindgen(constructor)]
     pub fn new(options: Option<TerminalOptions>) -> Terminal;
 
+    /// Creates a new `Terminal` object from a plain options object (i.e. from
+    /// [`TerminalOptions::to_object`]).
+    ///
+    /// xterm.js copies the options it's constructed with using `for..in`, so
+    /// this is the way to go if the options need to be seen; [`new`] passes a
+    /// [`TerminalOptions`], whose fields are getters that xterm.js skips.
+    ///
+    /// [`new`]: Terminal::new
+    #[wasm_bindgen(constructor)]
+    pub fn new_with_options_object(options: &js_sys::Object) -> Terminal;
+
     /////////////////////////////// Properties ///////////////////////////////
 
-    /// **[EXPERIMENTAL]** The terminal's current buffer, this might be either
-    /// the normal buffer or the alt buffer depending on what's running in the
-    /// terminal.
+    /// The terminal's current buffer, this might be either the normal buffer
+    /// or the alt buffer depending on what's running in the terminal.
     #[wasm_bindgen(method, getter = buffer)]
     pub fn buffer(this: &Terminal) -> BufferNamespace;
 
@@ -43,15 +52,6 @@ extern "C" {
     #[wasm_bindgen(method, getter = element)]
     pub fn element(this: &Terminal) -> Option<web_sys::HtmlElement>;
 
-    /// **[EXPERIMENTAL]** Get all markers registered against the buffer. If the
-    /// alt buffer is active this will always return `[]`.
-    #[wasm_bindgen(method, getter = markers)]
-    pub fn markers(this: &Terminal) -> ReadOnlyArray<Marker>;
-
-    /// Get the parser interface to register custom escape sequence handlers.
-    #[wasm_bindgen(method, getter = parser)]
-    pub fn parser(this: &Terminal) -> Parser;
-
     /// The number of rows in the terminal's viewport. Use
     /// [`TerminalOptions::rows`] to set this in the [constructor] and
     /// [`Terminal::resize`] for when the terminal exists.
@@ -66,13 +66,6 @@ extern "C" {
     #[wasm_bindgen(method, getter = textarea)]
     pub fn textarea(this: &Terminal) -> Option<web_sys::HtmlTextAreaElement>;
 
-    /// **[EXPERIMENTAL]** Get the Unicode handling interface.
-    ///
-    /// This can be used to register Unicode versions and switch the active
-    /// Unicode version.
-    #[wasm_bindgen(method, getter = unicode)]
-    pub fn unicode(this: &Terminal) -> UnicodeHandling;
-
     /// Natural language strings that can be localized.
     #[wasm_bindgen(method, getter = strings)]
     pub fn strings(this: &Terminal) -> LocalizableStrings;
@@ -81,6 +74,14 @@ extern "C" {
     #[wasm_bindgen(method, getter = modes)]
     pub fn modes(this: &Terminal) -> Modes;
 
+    /// Get the parser interface to register custom escape sequence handlers.
+    ///
+    /// (In xterm.js 4 this is a proposed API; see the `proposed-api` feature.)
+    #[cfg(feature = "xterm-5")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
+    #[wasm_bindgen(method, getter = parser)]
+    pub fn parser(this: &Terminal) -> Parser;
+
     /// Gets the terminal options. This supports getting multiple options.
     ///
     /// Example:
@@ -104,6 +105,14 @@ extern "C" {
     #[wasm_bindgen(method, setter = options)]
     pub fn set_options(this: &Terminal, options: TerminalOptions);
 
+    /// Sets the options that are on `options` (a plain object with xterm.js's
+    /// option names), leaving the rest as they are.
+    ///
+    /// As with [`set_options`](Terminal::set_options), options that are
+    /// objects must be new objects to take effect.
+    #[wasm_bindgen(method, setter = options)]
+    pub fn set_options_object(this: &Terminal, options: &js_sys::Object);
+
     /////////////////////////////// Event Listeners ///////////////////////////////
 
     /// Adds an event listener for when the bell is triggered.
@@ -209,6 +218,8 @@ extern "C" {
     /// Returns a [`Disposable`] to stop listening.
     ///
     /// [`write`]: Terminal::write
+    #[cfg(feature = "xterm-5")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
     #[wasm_bindgen(method, js_name = onWriteParsed)]
     pub fn on_write_parsed(this: &Terminal, listener: &Closure<dyn FnMut()>) -> Disposable;
 
@@ -294,10 +305,14 @@ extern "C" {
     ///     selection clearing. Set this to false if the data sent should not
     ///     be treated like user input would, for example passing an escape
     ///     sequence to the application.
+    #[cfg(feature = "xterm-5")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
     #[wasm_bindgen(method, js_name = input)]
     pub fn input(this: &Terminal, data: Str);
 
     /// Input data to application side with user input flag.
+    #[cfg(feature = "xterm-5")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
     #[wasm_bindgen(method, js_name = input)]
     pub fn input_with_user_flag(this: &Terminal, data: Str, was_user_input: bool);
 
@@ -350,6 +365,8 @@ extern "C" {
     /// This is a function that takes a WheelEvent, allowing consumers to stop
     /// propagation and/or prevent the default action. The function returns
     /// whether the event should be processed by xterm.js.
+    #[cfg(feature = "xterm-5")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
     #[wasm_bindgen(method, js_name = attachCustomWheelEventHandler)]
     pub fn attach_custom_wheel_event_handler(
         this: &Terminal,
@@ -378,44 +395,16 @@ extern "C" {
     #[wasm_bindgen(method, js_name = clearTextureAtlas)]
     pub fn clear_texture_atlas(this: &Terminal);
 
-    /// **[EXPERIMENTAL]** Registers a marker at the current cursor position.
+    /// Registers a marker at the current cursor position.
     ///
     /// Returns the new marker or undefined if the alt buffer is active.
+    ///
+    /// (In xterm.js 4 this is a proposed API; see the `proposed-api` feature.)
+    #[cfg(feature = "xterm-5")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
     #[wasm_bindgen(method, js_name = registerMarker)]
     pub fn register_marker(this: &Terminal, cursor_y_offset: Option<i16>) -> Option<Marker>;
 
-    /// **[EXPERIMENTAL]** Registers a character joiner, allowing custom sequences of
-    /// characters to be rendered as a single unit. This is useful in particular
-    /// for rendering ligatures and graphemes, among other things.
-    ///
-    /// Each registered character joiner is called with a string of text
-    /// representing a portion of a line in the terminal that can be rendered as
-    /// a single unit. The joiner must return a sorted array, where each entry is
-    /// itself an array of length two, containing the start (inclusive) and end
-    /// (exclusive) index of a substring of the input that should be rendered as
-    /// a single unit.
-    ///
-    /// NOTE: character joiners are only used by the canvas renderer.
-    ///
-    /// Takes `handler`: The function that determines character joins. It is called
-    /// with a string of text that is eligible for joining and returns an array
-    /// where each entry is an array containing the start (inclusive) and end
-    /// (exclusive) indexes of ranges that should be rendered as a single unit.
-    ///
-    /// Returns the ID of the new joiner, this can be used to deregister.
-    #[wasm_bindgen(method, js_name = registerCharacterJoiner)]
-    pub fn register_character_joiner(
-        this: &Terminal,
-        handler: &Closure<dyn FnMut(Str) -> js_sys::Array>,
-    ) -> u32;
-
-    /// **[EXPERIMENTAL]** Deregisters the character joiner if one was registered.
-    /// NOTE: character joiners are only used by the canvas renderer.
-    ///
-    /// Takes `joiner_id`: The character joiner's ID (returned after register).
-    #[wasm_bindgen(method, js_name = deregisterCharacterJoiner)]
-    pub fn deregister_character_joiner(this: &Terminal, joiner_id: u32);
-
     /// Resizes the terminal. It's best practice to debounce calls to resize,
     /// this will help ensure that the pty can respond to the resize event
     /// before another one occurs.
@@ -426,6 +415,10 @@ extern "C" {
     #[wasm_bindgen(method, js_name = resize)]
     pub fn resize(this: &Terminal, columns: u16, rows: u16);
 
+    /// Perform a full reset (RIS, aka `ESC c`).
+    #[wasm_bindgen(method, js_name = reset)]
+    pub fn reset(this: &Terminal);
+
     /// Scroll the display of the terminal by a number of lines.
     ///
     /// Takes `amount`: The number of lines to scroll down (negative scroll up).
@@ -480,6 +473,15 @@ extern "C" {
     #[wasm_bindgen(method, js_name = write)]
     pub fn write(this: &Terminal, data: Str);
 
+    /// Writes data to the terminal, returning the exception if xterm.js throws
+    /// one (i.e. if the terminal has been disposed).
+    ///
+    /// This is otherwise identical to [`write`].
+    ///
+    /// [`write`]: Terminal::write
+    #[wasm_bindgen(method, catch, js_name = write)]
+    pub fn try_write(this: &Terminal, data: Str) -> Result<(), JsValue>;
+
     /// Writes data to the terminal and takes a callback.
     ///
     /// This identical to [`write`] except it also takes a callback.
@@ -2,12 +2,19 @@
 
 #![allow(missing_docs)] // wasm_bindgen generates additional undocumented items
 
+use core::fmt::{self, Display};
+use wasm_bindgen::convert::{
+    FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi,
+};
+use wasm_bindgen::describe::WasmDescribe;
 use wasm_bindgen::prelude::*;
 
 /// An alias for [`String`].
 pub type Str = String;
 
 /// A string representing the type of a bell notification.
+#[cfg(feature = "xterm-4")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-4")))]
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)] // wasm_bindgen generates additional undocumented items
@@ -45,6 +52,8 @@ pub enum CursorStyle {
 }
 
 /// The style of the cursor when the terminal is not focused.
+#[cfg(feature = "xterm-5")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)] // wasm_bindgen generates additional undocumented items
@@ -74,33 +83,139 @@ pub enum FastScrollModifier {
     Shift = "shift",
 }
 
-/// A string representing text font weight.
-#[wasm_bindgen]
+/// A font weight: `normal`, `bold` or a number from 1 to 1000 (i.e. `400` or
+/// `450`).
+///
+/// This crosses into JS as xterm.js's `FontWeight`: the strings `"normal"` and
+/// `"bold"` or a number.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-#[allow(missing_docs)] // wasm_bindgen generates additional undocumented items
 pub enum FontWeight {
-    /// Normal font weight.
-    Normal = "normal",
-    /// Bold font weight.
-    Bold = "bold",
+    /// Normal font weight (400).
+    Normal,
+    /// Bold font weight (700).
+    Bold,
+    /// A numeric font weight; xterm.js accepts 1 to 1000.
+    Numeric(u16),
+}
+
+impl FontWeight {
     /// 100 font weight.
-    _100 = "100",
+    pub const _100: Self = Self::Numeric(100);
     /// 200 font weight.
-    _200 = "200",
+    pub const _200: Self = Self::Numeric(200);
     /// 300 font weight.
-    _300 = "300",
+    pub const _300: Self = Self::Numeric(300);
     /// 400 font weight.
-    _400 = "400",
+    pub const _400: Self = Self::Numeric(400);
     /// 500 font weight.
-    _500 = "500",
+    pub const _500: Self = Self::Numeric(500);
     /// 600 font weight.
-    _600 = "600",
+    pub const _600: Self = Self::Numeric(600);
     /// 700 font weight.
-    _700 = "700",
+    pub const _700: Self = Self::Numeric(700);
     /// 800 font weight.
-    _800 = "800",
+    pub const _800: Self = Self::Numeric(800);
     /// 900 font weight.
-    _900 = "900",
+    pub const _900: Self = Self::Numeric(900);
+
+    /// The weight as a number (`normal` is 400 and `bold` is 700).
+    #[must_use]
+    pub const fn to_number(self) -> u16 {
+        match self {
+            Self::Normal => 400,
+            Self::Bold => 700,
+            Self::Numeric(weight) => weight,
+        }
+    }
+
+    /// Whether xterm.js accepts this weight (numeric weights must be from 1
+    /// to 1000).
+    #[must_use]
+    pub const fn is_valid(self) -> bool {
+        matches!(self, Self::Normal | Self::Bold | Self::Numeric(1..=1000))
+    }
+
+    /// Parses `normal`, `bold` or a number.
+    #[must_use]
+    #[allow(clippy::should_implement_trait)] // matches the string enums
+    pub fn from_str(weight: &str) -> Option<Self> {
+        match weight {
+            "normal" => Some(Self::Normal),
+            "bold" => Some(Self::Bold),
+            number => number.parse().ok().map(Self::Numeric),
+        }
+    }
+
+    /// Converts from the JS representation (a string or a number).
+    #[must_use]
+    // Numbers are range checked before they're cast.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_js_value(value: &JsValue) -> Option<Self> {
+        match value.as_string() {
+            Some(weight) => Self::from_str(&weight),
+            None => value
+                .as_f64()
+                .filter(|w| (0.0..=f64::from(u16::MAX)).contains(&w.round()))
+                .map(|w| Self::Numeric(w.round() as u16)),
+        }
+    }
+}
+
+impl Display for FontWeight {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Normal => write!(fmt, "normal"),
+            Self::Bold => write!(fmt, "bold"),
+            Self::Numeric(weight) => write!(fmt, "{weight}"),
+        }
+    }
+}
+
+impl From<FontWeight> for JsValue {
+    fn from(weight: FontWeight) -> Self {
+        match weight {
+            FontWeight::Numeric(weight) => weight.into(),
+            keyword => keyword.to_string().into(),
+        }
+    }
+}
+
+impl WasmDescribe for FontWeight {
+    fn describe() {
+        JsValue::describe();
+    }
+}
+
+impl IntoWasmAbi for FontWeight {
+    type Abi = <JsValue as IntoWasmAbi>::Abi;
+
+    fn into_abi(self) -> Self::Abi {
+        JsValue::from(self).into_abi()
+    }
+}
+
+impl FromWasmAbi for FontWeight {
+    type Abi = <JsValue as FromWasmAbi>::Abi;
+
+    /// Values that aren't font weights come out as [`FontWeight::Normal`]
+    /// (xterm.js's default).
+    #[allow(unsafe_code)]
+    unsafe fn from_abi(js: Self::Abi) -> Self {
+        Self::from_js_value(&JsValue::from_abi(js)).unwrap_or(Self::Normal)
+    }
+}
+
+// `undefined` is `0`, as with imported JS types.
+impl OptionIntoWasmAbi for FontWeight {
+    fn none() -> Self::Abi {
+        0
+    }
+}
+
+impl OptionFromWasmAbi for FontWeight {
+    fn is_none(abi: &Self::Abi) -> bool {
+        *abi == 0
+    }
 }
 
 /// A string representing log level.
@@ -108,7 +223,11 @@ pub enum FontWeight {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)] // wasm_bindgen generates additional undocumented items
 pub enum LogLevel {
-    /// Show debug (and above) log level information (all logs).
+    /// Show trace (and above) log level information (all logs).
+    #[cfg(feature = "xterm-5")]
+    #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
+    Trace = "trace",
+    /// Show debug (and above) log level information.
     Debug = "debug",
     /// Show information (and above) log level information.
     Info = "info",
@@ -121,6 +240,8 @@ pub enum LogLevel {
 }
 
 /// A string representing a renderer type.
+#[cfg(feature = "xterm-4")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-4")))]
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)] // wasm_bindgen generates additional undocumented items
@@ -157,6 +278,8 @@ pub enum WideCharacterWidth {
 }
 
 /// Windows PTY backend type.
+#[cfg(feature = "xterm-5")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "xterm-5")))]
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(missing_docs)] // wasm_bindgen generates additional undocumented items
@@ -185,3 +308,159 @@ pub enum MouseTrackingMode {
     /// Use All Motion Mouse Tracking (CSI ? 1003 h).
     Any = "any",
 }
+
+/// (De)serializes string enums as their JS string values.
+#[cfg(feature = "serde")]
+macro_rules! serde_string_enums {
+    ($($nom:ident { $($variant:ident),+ $(,)? })*) => {$(
+        #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+        impl serde::Serialize for $nom {
+            fn serialize<S: serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match self {
+                    $nom::__Invalid => Err(serde::ser::Error::custom(
+                        concat!("invalid ", stringify!($nom)),
+                    )),
+                    valid => serializer.serialize_str(valid.to_str()),
+                }
+            }
+        }
+
+        #[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+        impl<'de> serde::Deserialize<'de> for $nom {
+            fn deserialize<D: serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+
+                $nom::from_str(&value).ok_or_else(|| {
+                    let expected = [$($nom::$variant.to_str()),+].join("`, `");
+                    serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Str(&value),
+                        &format!("one of `{expected}`").as_str(),
+                    )
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "serde")]
+serde_string_enums! {
+    CursorStyle { Block, Underline, Bar }
+    FastScrollModifier { Alt, Ctrl, Shift }
+    BufferType { Normal, Alternate }
+    MouseTrackingMode { None, X10, Vt200, Drag, Any }
+}
+
+#[cfg(all(feature = "serde", feature = "xterm-4"))]
+serde_string_enums! {
+    BellStyle { None, Visual, Sound, Both }
+    LogLevel { Debug, Info, Warn, Error, Off }
+    RendererType { Dom, Canvas }
+}
+
+#[cfg(all(feature = "serde", feature = "xterm-5"))]
+serde_string_enums! {
+    CursorInactiveStyle { Outline, Block, Bar, Underline, None }
+    LogLevel { Trace, Debug, Info, Warn, Error, Off }
+    WindowsPty { Auto, Conpty, Winpty }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+impl serde::Serialize for FontWeight {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Numeric(weight) => serializer.serialize_u16(*weight),
+            keyword => serializer.collect_str(keyword),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(all(docs, not(doctest)), doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for FontWeight {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        /// Accepts `normal`, `bold`, and whole numbers (or numeric strings)
+        /// from 1 to 1000.
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = FontWeight;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "`normal`, `bold` or a number from 1 to 1000")
+            }
+
+            fn visit_u64<E: serde::de::Error>(
+                self,
+                weight: u64,
+            ) -> Result<FontWeight, E> {
+                u16::try_from(weight)
+                    .ok()
+                    .map(FontWeight::Numeric)
+                    .filter(|weight| weight.is_valid())
+                    .ok_or_else(|| {
+                        E::invalid_value(
+                            serde::de::Unexpected::Unsigned(weight),
+                            &self,
+                        )
+                    })
+            }
+
+            fn visit_i64<E: serde::de::Error>(
+                self,
+                weight: i64,
+            ) -> Result<FontWeight, E> {
+                u64::try_from(weight)
+                    .map_err(|_| {
+                        E::invalid_value(
+                            serde::de::Unexpected::Signed(weight),
+                            &self,
+                        )
+                    })
+                    .and_then(|weight| self.visit_u64(weight))
+            }
+
+            // Only whole numbers in range get here, so nothing is lost.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn visit_f64<E: serde::de::Error>(
+                self,
+                weight: f64,
+            ) -> Result<FontWeight, E> {
+                if weight.fract() == 0.0 && (1.0..=1000.0).contains(&weight) {
+                    Ok(FontWeight::Numeric(weight as u16))
+                } else {
+                    Err(E::invalid_value(
+                        serde::de::Unexpected::Float(weight),
+                        &self,
+                    ))
+                }
+            }
+
+            fn visit_str<E: serde::de::Error>(
+                self,
+                weight: &str,
+            ) -> Result<FontWeight, E> {
+                FontWeight::from_str(weight)
+                    .filter(|weight| weight.is_valid())
+                    .ok_or_else(|| {
+                        E::invalid_value(
+                            serde::de::Unexpected::Str(weight),
+                            &self,
+                        )
+                    })
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "ext")]
+
+mod common;
+
+use common::FakeTerminal;
+use js_sys::Object;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::capabilities::{CapabilityError, Kind, XtermVersion};
+use xterm_js_sys::xterm::Terminal;
+
+/// A fake terminal with `options` and the given functions.
+fn terminal(functions: &[&str], options: &[(&str, JsValue)]) -> Terminal {
+    FakeTerminal::new()
+        .with_functions(functions)
+        .with_options(options)
+        .build()
+}
+
+#[wasm_bindgen_test]
+fn detects_members() {
+    let terminal = terminal(
+        &["write", "registerCharacterJoiner", "onBell"],
+        &[("fontSize", 15.into()), ("allowProposedApi", false.into())],
+    );
+
+    let capabilities = terminal.capabilities();
+    assert_eq!(capabilities.version, Some(XtermVersion::V5));
+    assert!(!capabilities.proposed_api);
+    assert!(capabilities.has(Kind::Method, "write"));
+    assert!(capabilities.has(Kind::Event, "onBell"));
+    assert!(capabilities.has(Kind::Option, "fontSize"));
+    assert!(capabilities.has(Kind::Option, "allowProposedApi"));
+    assert!(!capabilities.has(Kind::Method, "registerMarker"));
+    assert!(capabilities
+        .missing()
+        .any(|m| m == (Kind::Method, "clearTextureAtlas")));
+
+    assert_eq!(
+        capabilities.require(Kind::Method, "registerMarker"),
+        Err(CapabilityError::Missing {
+            kind: Kind::Method,
+            name: "registerMarker",
+            version: Some(XtermVersion::V5),
+        })
+    );
+    let err = capabilities
+        .require(Kind::Method, "registerCharacterJoiner")
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "`registerCharacterJoiner` is a proposed API; turn on \
+         `allowProposedApi` to use it"
+    );
+}
+
+#[wasm_bindgen_test]
+fn detects_versions() {
+    let v4 = terminal(&["write", "setOption"], &[]).capabilities();
+    assert_eq!(v4.version, Some(XtermVersion::V4));
+
+    let proposed = terminal(&["write"], &[("allowProposedApi", true.into())]);
+    assert!(proposed.capabilities().proposed_api);
+
+    let other: Terminal = Object::new().unchecked_into();
+    let capabilities = other.capabilities();
+    assert_eq!(capabilities.version, None);
+    assert!(capabilities.options.is_empty());
+    assert_eq!(
+        capabilities
+            .require(Kind::Event, "onBell")
+            .unwrap_err()
+            .to_string(),
+        "the loaded xterm.js has no `onBell` event"
+    );
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "ext")]
+
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::color::{Color, ParseColorError};
+use xterm_js_sys::xterm::Theme;
+
+#[wasm_bindgen_test]
+fn parses_css_colors() {
+    let parse = |c: &str| c.parse::<Color>();
+
+    assert_eq!(parse("#abc"), Ok(Color::rgb(0xAA, 0xBB, 0xCC)));
+    assert_eq!(parse("#abc8"), Ok(Color::rgba(0xAA, 0xBB, 0xCC, 0x88)));
+    assert_eq!(parse("#A0B1C2"), Ok(Color::rgb(0xA0, 0xB1, 0xC2)));
+    assert_eq!(parse("#a0b1c2d3"), Ok(Color::rgba(0xA0, 0xB1, 0xC2, 0xD3)));
+
+    assert_eq!(parse("rgb(1, 2, 3)"), Ok(Color::rgb(1, 2, 3)));
+    assert_eq!(parse("RGBA(1,2,3,0)"), Ok(Color::rgba(1, 2, 3, 0)));
+    assert_eq!(parse("rgb(100% 0% 50%)"), Ok(Color::rgb(0xFF, 0, 0x80)));
+    assert_eq!(parse("rgba(0 0 0 / 25%)"), Ok(Color::rgba(0, 0, 0, 0x40)));
+
+    assert_eq!(parse("Teal"), Ok(Color::rgb(0, 0x80, 0x80)));
+    assert_eq!(parse("transparent"), Ok(Color::rgba(0, 0, 0, 0)));
+}
+
+#[wasm_bindgen_test]
+fn rejects_malformed_colors() {
+    let parse = |c: &str| c.parse::<Color>();
+
+    assert_eq!(parse("#12345"), Err(ParseColorError::InvalidHex));
+    assert_eq!(parse("#12345g"), Err(ParseColorError::InvalidHex));
+    assert_eq!(parse("#"), Err(ParseColorError::InvalidHex));
+
+    assert_eq!(parse("rgb(1, 2)"), Err(ParseColorError::InvalidFunction));
+    assert_eq!(
+        parse("rgb(1, 2, 256)"),
+        Err(ParseColorError::InvalidFunction)
+    );
+    assert_eq!(
+        parse("rgba(1, 2, 3, 2)"),
+        Err(ParseColorError::InvalidFunction)
+    );
+    assert_eq!(parse("rgb(1, 2, 3"), Err(ParseColorError::InvalidFunction));
+
+    assert_eq!(parse("rebeccapurple "), Err(ParseColorError::Unknown));
+    assert_eq!(parse("hsl(0, 0%, 0%)"), Err(ParseColorError::Unknown));
+    assert_eq!(parse(""), Err(ParseColorError::Unknown));
+}
+
+#[wasm_bindgen_test]
+fn typed_theme_colors() {
+    let mut theme = Theme::default();
+    theme.set_cursor_color(Some(Color::rgba(1, 2, 3, 4)));
+    assert_eq!(theme.cursor().as_deref(), Some("#01020304"));
+    assert_eq!(theme.cursor_color(), Ok(Some(Color::rgba(1, 2, 3, 4))));
+    assert_eq!(theme.background_color(), Ok(None));
+
+    theme.set_bright_red(Some("crimson".into()));
+    assert_eq!(
+        theme.bright_red_color(),
+        Ok(Some(Color::rgb(0xDC, 0x14, 0x3C)))
+    );
+
+    theme.set_extended_ansi_colors(Some(vec![Color::rgb(0, 0, 0)]));
+    assert_eq!(theme.extended_ansi(), Some(vec!["#000000".into()]));
+    assert_eq!(theme.validate(), Ok(()));
+
+    theme.set_red(Some("#ff00000".into()));
+    theme.set_extended_ansi(Some(vec!["bleu".into()]));
+    assert_eq!(theme.red_color(), Err(ParseColorError::InvalidHex));
+    assert_eq!(theme.extended_ansi_colors(), Err(ParseColorError::Unknown));
+
+    let err = theme.validate().unwrap_err();
+    let fields: Vec<_> = err.0.iter().map(|e| &*e.field).collect();
+    assert_eq!(fields, ["red", "extendedAnsi[0]"]);
+}
@@ -0,0 +1,3 @@
+// Preloaded (with `node --require`) for the tests that use a real xterm.js:
+// its bundle is built for browsers and expects `self` to be the global object.
+globalThis.self ??= globalThis;
@@ -0,0 +1,133 @@
+//! A fake xterm.js terminal for tests that don't need a real one.
+//!
+//! Everything on it is a plain JS object (as a real terminal's `options` and
+//! `modes` are), so tests go through the same conversions they would with
+//! xterm.js.
+
+// Each test uses some of this.
+#![allow(dead_code)]
+
+use js_sys::{Array, Function, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use xterm_js_sys::xterm::Terminal;
+
+/// Makes a plain JS object with the given properties.
+pub fn object(properties: &[(&str, JsValue)]) -> Object {
+    let object = Object::new();
+    for (name, value) in properties {
+        Reflect::set(&object, &(*name).into(), value).unwrap();
+    }
+
+    object
+}
+
+/// Gets `key` off of `object`.
+pub fn get(object: &JsValue, key: &str) -> JsValue {
+    Reflect::get(object, &key.into()).unwrap()
+}
+
+/// Builds a fake terminal with just the members a test needs.
+#[derive(Debug)]
+pub struct FakeTerminal {
+    /// The terminal object.
+    object: Object,
+}
+
+impl Default for FakeTerminal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeTerminal {
+    /// A terminal with no members at all.
+    pub fn new() -> Self {
+        Self {
+            object: Object::new(),
+        }
+    }
+
+    /// Gives the terminal an `options` object with the given options.
+    pub fn with_options(self, options: &[(&str, JsValue)]) -> Self {
+        self.with(&[("options", object(options).into())])
+    }
+
+    /// Adds functions (that do nothing) with the given names.
+    pub fn with_functions(self, names: &[&str]) -> Self {
+        for name in names {
+            let f = Function::new_no_args("");
+            Reflect::set(&self.object, &(*name).into(), &f).unwrap();
+        }
+
+        self
+    }
+
+    /// Gives the terminal a size and a `resize` that records what it's
+    /// resized to (see [`resized`]).
+    pub fn with_size(self, cols: u16, rows: u16) -> Self {
+        let resize = Function::new_with_args(
+            "cols, rows",
+            "this.resized = [cols, rows];",
+        );
+        self.with(&[
+            ("cols", cols.into()),
+            ("rows", rows.into()),
+            ("resize", resize.into()),
+        ])
+    }
+
+    /// Gives the terminal a `modes` object (with no modes set) and an
+    /// `onWriteParsed` that holds on to its listener (see [`parse`]) and
+    /// records when it's disposed (see [`write_parsed_disposed`]).
+    pub fn with_modes(self) -> Self {
+        let on_write_parsed = Function::new_with_args(
+            "listener",
+            "this.writeParsed = listener;
+             return { dispose: () => { this.writeParsedDisposed = true; } };",
+        );
+        self.with(&[
+            ("modes", Object::new().into()),
+            ("onWriteParsed", on_write_parsed.into()),
+        ])
+    }
+
+    /// Sets arbitrary properties on the terminal.
+    pub fn with(self, properties: &[(&str, JsValue)]) -> Self {
+        for (name, value) in properties {
+            Reflect::set(&self.object, &(*name).into(), value).unwrap();
+        }
+
+        self
+    }
+
+    /// The terminal.
+    pub fn build(self) -> Terminal {
+        self.object.unchecked_into()
+    }
+}
+
+/// What a terminal made [`with_size`](FakeTerminal::with_size) was last
+/// resized to, if it was resized.
+pub fn resized(terminal: &Terminal) -> Option<Vec<JsValue>> {
+    let resized = get(terminal, "resized");
+    (!resized.is_undefined()).then(|| Array::from(&resized).to_vec())
+}
+
+/// Sets the given modes on a terminal made
+/// [`with_modes`](FakeTerminal::with_modes) and then calls its
+/// `onWriteParsed` listener, like writing mode changing sequences would.
+pub fn parse(terminal: &Terminal, modes: &[(&str, JsValue)]) {
+    let current = get(terminal, "modes");
+    for (name, value) in modes {
+        Reflect::set(&current, &(*name).into(), value).unwrap();
+    }
+
+    let listener: Function = get(terminal, "writeParsed").unchecked_into();
+    listener.call0(&JsValue::UNDEFINED).unwrap();
+}
+
+/// Whether the `onWriteParsed` listener on a terminal made
+/// [`with_modes`](FakeTerminal::with_modes) was disposed.
+pub fn write_parsed_disposed(terminal: &Terminal) -> bool {
+    get(terminal, "writeParsedDisposed").is_truthy()
+}
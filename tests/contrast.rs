@@ -0,0 +1,83 @@
+#![cfg(feature = "ext")]
+
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::color::Color;
+use xterm_js_sys::ext::contrast::{AA, AAA};
+use xterm_js_sys::xterm::Theme;
+
+#[wasm_bindgen_test]
+fn wcag_ratios() {
+    let black = Color::rgb(0, 0, 0);
+    let white = Color::rgb(0xFF, 0xFF, 0xFF);
+    assert!((black.contrast_ratio(white) - 21.0).abs() < 1e-9);
+    assert!((white.contrast_ratio(white) - 1.0).abs() < 1e-9);
+
+    // #767676 is the darkest gray that passes AA on white.
+    let gray = Color::rgb(0x76, 0x76, 0x76);
+    assert!(gray.contrast_ratio(white) >= AA);
+    assert!(Color::rgb(0x77, 0x77, 0x77).contrast_ratio(white) < AA);
+
+    // Translucent colors are blended first.
+    assert_eq!(
+        Color::rgba(0xFF, 0xFF, 0xFF, 0x80).over(black),
+        Color::rgb(0x80, 0x80, 0x80)
+    );
+}
+
+#[wasm_bindgen_test]
+fn adjusting_keeps_hue() {
+    let white = Color::rgb(0xFF, 0xFF, 0xFF);
+    let yellow = Color::rgb(0xFC, 0xE9, 0x4F);
+
+    let adjusted = yellow.with_minimum_contrast(white, AA);
+    assert!(adjusted.contrast_ratio(white) >= AA);
+    assert!(adjusted.r > adjusted.g && adjusted.g > adjusted.b);
+
+    // Already fine, so left alone.
+    assert_eq!(adjusted.with_minimum_contrast(white, AA), adjusted);
+
+    // Nothing gets a mid gray to 21:1 so it goes as far as it can (black
+    // has slightly more contrast than white).
+    let gray = Color::rgb(0x77, 0x77, 0x77);
+    assert_eq!(gray.with_minimum_contrast(gray, 21.0), Color::rgb(0, 0, 0));
+}
+
+#[wasm_bindgen_test]
+fn theme_report() {
+    let mut theme = Theme::default();
+    theme.set_background(Some("#ffffff".into()));
+    theme.set_foreground(Some("#000000".into()));
+    theme.set_ansi_color(16, Some("#ffff00".into()));
+
+    let report = theme.contrast_report(AAA).unwrap();
+    assert_eq!(report.background, Color::rgb(0xFF, 0xFF, 0xFF));
+    assert_eq!(report.entries.len(), 18);
+    assert!(report.entries[0].passes());
+    assert!(!report.entries[1].is_default || report.entries[1].passes());
+
+    let failures: Vec<_> = report.failures().map(|e| e.name.as_str()).collect();
+    assert!(failures.contains(&"yellow"));
+    assert!(failures.contains(&"extendedAnsi[0]"));
+    assert!(!failures.contains(&"foreground"));
+
+    let adjusted = theme.with_minimum_contrast(AAA).unwrap();
+    let report = adjusted.contrast_report(AAA).unwrap();
+    assert!(report.passes(), "{report}");
+    assert!(adjusted.yellow().is_some());
+    assert_eq!(adjusted.foreground(), theme.foreground());
+}
+
+#[wasm_bindgen_test]
+fn malformed_theme() {
+    let mut theme = Theme::default();
+    theme.set_red(Some("#ff00000".into()));
+
+    let err = theme.contrast_report(AA).unwrap_err();
+    assert_eq!(err.0[0].field, "red");
+
+    // Only Alacritty imports take `0x` colors; xterm.js doesn't.
+    let mut theme = Theme::default();
+    theme.set_background(Some("0xffffff".into()));
+    let err = theme.contrast_report(AA).unwrap_err();
+    assert_eq!(err.0[0].field, "background");
+}
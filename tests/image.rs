@@ -0,0 +1,56 @@
+#![cfg(all(feature = "ext", feature = "addon-image"))]
+
+mod common;
+
+use common::{get, object, FakeTerminal};
+use wasm_bindgen_test::*;
+use xterm_js_sys::xterm::WindowOptions;
+
+#[wasm_bindgen_test]
+fn enables_size_reports() {
+    // Other window options are kept.
+    let terminal = FakeTerminal::new()
+        .with_options(&[(
+            "windowOptions",
+            object(&[("getWinTitle", true.into())]).into(),
+        )])
+        .build();
+    let old = get(&get(&terminal, "options"), "windowOptions");
+
+    terminal.enable_size_reports();
+    let options = get(&terminal, "options");
+    let window_options = get(&options, "windowOptions");
+    assert_eq!(
+        WindowOptions::from_object(&window_options),
+        Some(
+            WindowOptions::default()
+                .with_get_win_title(true)
+                .with_size_reports()
+        )
+    );
+    // A new object, so xterm.js notices; and nothing else is set.
+    assert_ne!(window_options, old);
+    assert_eq!(js_sys::Object::keys(&options.into()).length(), 1);
+
+    // Already on: nothing's set.
+    let terminal = FakeTerminal::new()
+        .with_options(&[(
+            "windowOptions",
+            WindowOptions::default()
+                .with_size_reports()
+                .to_object()
+                .into(),
+        )])
+        .build();
+    let options = get(&terminal, "options");
+    terminal.enable_size_reports();
+    assert_eq!(get(&terminal, "options"), options);
+
+    // No options at all.
+    let terminal = FakeTerminal::new().build();
+    terminal.enable_size_reports();
+    let window_options = get(&get(&terminal, "options"), "windowOptions");
+    assert!(WindowOptions::from_object(&window_options)
+        .unwrap()
+        .has_size_reports());
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "ext", feature = "proposed-api"))]
+
+use std::borrow::Cow;
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::joiner::{
+    join_utf16, utf16_to_utf8_offset, utf8_to_utf16_offset, CharacterJoiner,
+    LigatureJoiner,
+};
+
+#[wasm_bindgen_test]
+fn offsets() {
+    // 'é' is 2 bytes/1 unit, '😀' is 4 bytes/2 units.
+    let text = "aé😀=>";
+
+    assert_eq!(utf8_to_utf16_offset(text, 0), Some(0));
+    assert_eq!(utf8_to_utf16_offset(text, 1), Some(1));
+    assert_eq!(utf8_to_utf16_offset(text, 2), None);
+    assert_eq!(utf8_to_utf16_offset(text, 3), Some(2));
+    assert_eq!(utf8_to_utf16_offset(text, 7), Some(4));
+    assert_eq!(utf8_to_utf16_offset(text, 9), Some(6));
+    assert_eq!(utf8_to_utf16_offset(text, 10), None);
+
+    assert_eq!(utf16_to_utf8_offset(text, 2), Some(3));
+    assert_eq!(utf16_to_utf8_offset(text, 3), None);
+    assert_eq!(utf16_to_utf8_offset(text, 4), Some(7));
+    assert_eq!(utf16_to_utf8_offset(text, 6), Some(9));
+    assert_eq!(utf16_to_utf8_offset(text, 7), None);
+
+    for (byte, _) in text.char_indices() {
+        let unit = utf8_to_utf16_offset(text, byte).unwrap();
+        assert_eq!(utf16_to_utf8_offset(text, unit), Some(byte));
+    }
+}
+
+#[wasm_bindgen_test]
+fn ligatures() {
+    let joiner = LigatureJoiner::default();
+
+    assert_eq!(joiner.join("a => b != c"), vec![2..4, 7..9]);
+    assert_eq!(joiner.join("x === y"), vec![2..5]);
+    assert_eq!(joiner.join("<==>"), vec![0..3]);
+    assert_eq!(joiner.join("a->b->c"), vec![1..3, 4..6]);
+    assert_eq!(joiner.join("plain text"), vec![]);
+    assert_eq!(joiner.join(""), vec![]);
+
+    // Offsets are converted to UTF-16 for xterm.js.
+    assert_eq!(joiner.join("😀 -> é"), vec![5..7]);
+    assert_eq!(join_utf16(&joiner, "😀 -> é"), vec![3..5]);
+
+    let custom = LigatureJoiner::new([Cow::Borrowed("fi"), Cow::Borrowed("x")])
+        .with_sequences([Cow::Owned("ffi".to_string())]);
+    assert_eq!(custom.sequences(), ["ffi", "fi"]);
+    assert_eq!(custom.join("office fix"), vec![1..4, 7..9]);
+}
+
+#[wasm_bindgen_test]
+fn closures_and_bad_ranges() {
+    // Ranges that split a char or are empty are dropped.
+    let joiner = |_: &str| vec![0..2, 1..5, 2..2, 2..6, 6..7];
+    assert_eq!(join_utf16(&joiner, "é😀a"), vec![0..1, 1..3, 3..4]);
+}
+
+#[wasm_bindgen_test]
+fn unsorted_and_overlapping_ranges() {
+    // Sorted by start; overlaps with a range that's already kept (earlier, or
+    // as early but longer) are dropped. Touching ranges are fine.
+    let joiner = |_: &str| vec![6..8, 2..4, 0..2, 1..3, 2..5, 6..7, 8..9];
+    assert_eq!(
+        join_utf16(&joiner, "a=>b!=c=>"),
+        vec![0..2, 2..5, 6..8, 8..9]
+    );
+}
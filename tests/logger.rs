@@ -0,0 +1,148 @@
+#![cfg(feature = "ext")]
+
+use log::{Level, Log, Record};
+#[cfg(feature = "xterm-5")]
+use log::{LevelFilter, Metadata};
+#[cfg(feature = "xterm-5")]
+use std::sync::Mutex;
+#[cfg(feature = "xterm-5")]
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+#[cfg(feature = "xterm-5")]
+use xterm_js_sys::ext::logger::LogForwarder;
+use xterm_js_sys::ext::logger::TerminalLogger;
+use xterm_js_sys::xterm::LogLevel;
+#[cfg(feature = "xterm-5")]
+use xterm_js_sys::xterm::Logger;
+
+#[cfg(feature = "xterm-5")]
+/// Records everything that's logged.
+struct Capture(Mutex<Vec<(Level, String, String)>>);
+
+#[cfg(feature = "xterm-5")]
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        self.0.lock().unwrap().push((
+            record.level(),
+            record.target().to_owned(),
+            record.args().to_string(),
+        ));
+    }
+
+    fn flush(&self) {}
+}
+
+#[cfg(feature = "xterm-5")]
+static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+#[cfg(feature = "xterm-5")]
+#[wasm_bindgen_test]
+fn forwards_messages_and_args() {
+    log::set_logger(&CAPTURE).unwrap();
+    log::set_max_level(LevelFilter::Info);
+    assert_eq!(LogForwarder::log_level(), LogLevel::Info);
+
+    let logger: Logger = LogForwarder::default().into();
+    logger.warn("parser", vec![JsValue::from(1), "two".into()].into());
+    logger.debug("filtered out", Box::new([]));
+
+    let custom: Logger = LogForwarder::new().with_target("custom").into();
+    custom.error("oops", Box::new([js_sys::Error::new("bad").into()]));
+
+    let logged = CAPTURE.0.lock().unwrap();
+    assert_eq!(
+        *logged,
+        vec![
+            (
+                Level::Warn,
+                "xterm_js".to_owned(),
+                "parser 1 two".to_owned()
+            ),
+            (
+                Level::Error,
+                "custom".to_owned(),
+                "oops Error: bad".to_owned()
+            ),
+        ]
+    );
+}
+
+/// Logs a record straight to `logger`.
+fn log_to(logger: &TerminalLogger, level: Level, message: &str) {
+    logger.log(
+        &Record::builder()
+            .level(level)
+            .target("app")
+            .module_path(Some("app::net"))
+            .args(format_args!("{message}"))
+            .build(),
+    );
+}
+
+#[wasm_bindgen_test]
+fn terminal_logger_backlog() {
+    let logger = TerminalLogger::new(LogLevel::Warn)
+        .with_timestamps(false)
+        .with_colors(false)
+        .with_backlog_capacity(2);
+
+    log_to(&logger, Level::Info, "filtered out");
+    log_to(&logger, Level::Warn, "first");
+    log_to(&logger, Level::Error, "second\nline");
+    log_to(&logger, Level::Warn, "third");
+
+    // No terminal yet, so the last two are buffered.
+    assert_eq!(
+        logger.backlog(),
+        vec![
+            "[ERROR app::net] second\r\nline\r\n".to_owned(),
+            "[WARN  app::net] third\r\n".to_owned(),
+        ]
+    );
+
+    logger.set_level(LogLevel::Off);
+    assert_eq!(logger.level(), LogLevel::Off);
+    log_to(&logger, Level::Error, "dropped");
+    assert_eq!(logger.backlog().len(), 2);
+}
+
+#[wasm_bindgen_test]
+fn terminal_logger_colors() {
+    let logger = TerminalLogger::new(LogLevel::Debug)
+        .with_timestamps(false)
+        .with_module_paths(false);
+
+    log_to(&logger, Level::Debug, "hi");
+    log_to(&logger, Level::Trace, "filtered out");
+
+    assert_eq!(
+        logger.backlog(),
+        vec![
+            "\x1b[2m[\x1b[0m\x1b[1;34mDEBUG\x1b[0m\x1b[2m]\x1b[0m hi\r\n"
+                .to_owned()
+        ]
+    );
+}
+
+#[cfg(feature = "xterm-5")]
+#[wasm_bindgen_test]
+fn terminal_logger_trace() {
+    assert_eq!(LogLevel::from(Level::Trace), LogLevel::Trace);
+    assert_eq!(Level::try_from(LogLevel::Trace).unwrap(), Level::Trace);
+
+    let logger = TerminalLogger::new(LogLevel::Trace)
+        .with_timestamps(false)
+        .with_colors(false)
+        .with_module_paths(false);
+    assert_eq!(logger.level(), LogLevel::Trace);
+
+    log_to(&logger, Level::Trace, "kept");
+    assert_eq!(logger.backlog(), vec!["[TRACE] kept\r\n".to_owned()]);
+
+    logger.set_level(LogLevel::Debug);
+    assert_eq!(logger.level(), LogLevel::Debug);
+}
@@ -0,0 +1,128 @@
+#![cfg(all(feature = "ext", feature = "xterm-5"))]
+
+mod common;
+
+use common::{parse, write_parsed_disposed, FakeTerminal};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::modes::{ModeChanged, ModeTracker};
+use xterm_js_sys::xterm::{Modes, MouseTrackingMode};
+
+#[wasm_bindgen_test]
+fn reports_changes_in_order() {
+    let mut old = Modes::default();
+    old.send_focus_mode = Some(true);
+    old.mouse_tracking_mode = Some(MouseTrackingMode::Vt200);
+
+    let mut new = Modes::default();
+    new.application_cursor_keys_mode = Some(true);
+    new.bracketed_paste_mode = Some(true);
+    new.insert_mode = Some(true);
+    new.mouse_tracking_mode = Some(MouseTrackingMode::Any);
+    new.origin_mode = Some(true);
+    new.send_focus_mode = Some(false);
+
+    assert_eq!(
+        ModeChanged::between(&old, &new),
+        [
+            ModeChanged::ApplicationCursorKeys(true),
+            ModeChanged::BracketedPaste(true),
+            ModeChanged::Insert(true),
+            ModeChanged::MouseTracking(MouseTrackingMode::Any),
+            ModeChanged::Origin(true),
+            ModeChanged::SendFocus(false),
+        ]
+    );
+
+    assert_eq!(
+        ModeChanged::between(&new, &old),
+        [
+            ModeChanged::ApplicationCursorKeys(false),
+            ModeChanged::BracketedPaste(false),
+            ModeChanged::Insert(false),
+            ModeChanged::MouseTracking(MouseTrackingMode::Vt200),
+            ModeChanged::Origin(false),
+            ModeChanged::SendFocus(true),
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+fn unset_modes_are_off() {
+    let unset = Modes::default();
+
+    let mut off = Modes::default();
+    off.bracketed_paste_mode = Some(false);
+    off.mouse_tracking_mode = Some(MouseTrackingMode::None);
+    off.application_keypad_mode = Some(true); // not tracked
+    assert_eq!(ModeChanged::between(&unset, &off), []);
+
+    off.mouse_tracking_mode = Some(MouseTrackingMode::X10);
+    assert_eq!(
+        ModeChanged::between(&unset, &off),
+        [ModeChanged::MouseTracking(MouseTrackingMode::X10)]
+    );
+}
+
+#[wasm_bindgen_test]
+fn tracker_reports_changes_after_writes() {
+    let terminal = FakeTerminal::new().with_options(&[]).with_modes().build();
+    let tracker = Rc::new(ModeTracker::new(&terminal).unwrap());
+    let events = Rc::new(RefCell::new(Vec::new()));
+
+    // The first listener adds a second one the first time bracketed paste
+    // turns on; it should only see changes from later writes.
+    let (log, weak) = (events.clone(), Rc::downgrade(&tracker));
+    tracker.on_mode_change(move |change| {
+        log.borrow_mut().push((1, *change));
+        if *change == ModeChanged::BracketedPaste(true) {
+            let log = log.clone();
+            weak.upgrade().unwrap().on_mode_change(move |change| {
+                log.borrow_mut().push((2, *change));
+            });
+        }
+    });
+
+    parse(
+        &terminal,
+        &[
+            ("bracketedPasteMode", true.into()),
+            ("insertMode", true.into()),
+        ],
+    );
+    assert_eq!(
+        events.take(),
+        [
+            (1, ModeChanged::BracketedPaste(true)),
+            (1, ModeChanged::Insert(true)),
+        ]
+    );
+    assert_eq!(tracker.modes().bracketed_paste_mode, Some(true));
+
+    // Nothing we track changed.
+    parse(&terminal, &[("wraparoundMode", false.into())]);
+    assert_eq!(events.take(), []);
+
+    parse(&terminal, &[("mouseTrackingMode", "any".into())]);
+    assert_eq!(
+        events.take(),
+        [
+            (1, ModeChanged::MouseTracking(MouseTrackingMode::Any)),
+            (2, ModeChanged::MouseTracking(MouseTrackingMode::Any)),
+        ]
+    );
+    let modes = tracker.modes();
+    assert_eq!(modes.mouse_tracking_mode, Some(MouseTrackingMode::Any));
+    assert_eq!(modes.wraparound_mode, Some(false));
+    assert_eq!(modes.origin_mode, None);
+
+    // Dropping the tracker disposes of its listener, and the (leaked)
+    // callback no longer does anything.
+    assert!(!write_parsed_disposed(&terminal));
+    drop(tracker);
+    assert!(write_parsed_disposed(&terminal));
+
+    parse(&terminal, &[("bracketedPasteMode", false.into())]);
+    assert_eq!(events.take(), []);
+}
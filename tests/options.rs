@@ -0,0 +1,369 @@
+#![cfg(feature = "ext")]
+
+mod common;
+
+use common::{get, object, resized, FakeTerminal};
+use js_sys::{Array, Object};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::options::diff::{OptionChange, OptionEffect};
+use xterm_js_sys::ext::options::validate::{Allowed, MAX_SIZE};
+use xterm_js_sys::xterm::{
+    CursorStyle, FontWeight, FunctionIdentifier, Terminal, TerminalOptions,
+    Theme, WindowOptions,
+};
+
+#[wasm_bindgen_test]
+fn builders() {
+    let theme = Theme::default()
+        .with_foreground("#ffffff")
+        .with_bright_black(String::from("#808080"))
+        .with_selection_background(Some("#ffffff4d".into()))
+        .with_extended_ansi(vec!["#000000".into()]);
+    assert_eq!(theme.foreground().as_deref(), Some("#ffffff"));
+    assert_eq!(theme.bright_black().as_deref(), Some("#808080"));
+    assert_eq!(theme.selection_background().as_deref(), Some("#ffffff4d"));
+    assert_eq!(theme.extended_ansi().map(|e| e.len()), Some(1));
+
+    let options = TerminalOptions::default()
+        .with_cols(80)
+        .with_rows(None)
+        .with_font_size(12.5)
+        .with_cursor_style(CursorStyle::Bar)
+        .with_font_family("monospace")
+        .with_theme(theme.clone())
+        .with_window_options(WindowOptions::all_reports());
+    assert_eq!(options.cols, Some(80));
+    assert_eq!(options.rows, None);
+    assert_eq!(options.font_size, Some(12.5));
+    assert_eq!(options.cursor_style, Some(CursorStyle::Bar));
+    assert_eq!(options.font_family().as_deref(), Some("monospace"));
+    assert_eq!(options.theme(), Some(theme));
+
+    let rows = options.with_rows(24).with_cols(None);
+    assert_eq!((rows.cols, rows.rows), (None, Some(24)));
+
+    let id = FunctionIdentifier::new(None, None, "t".into())
+        .with_prefix("?")
+        .with_final_byte("q");
+    assert_eq!(id.prefix().as_deref(), Some("?"));
+    assert_eq!(id.final_byte(), "q");
+}
+
+#[wasm_bindgen_test]
+fn window_option_presets() {
+    let none = WindowOptions::none();
+    assert_eq!(none.fullscreen_win, Some(false));
+    assert_eq!(none.get_win_title, Some(false));
+
+    let all = WindowOptions::all();
+    assert_eq!(all.set_win_size_pixels, Some(true));
+    assert_eq!(all.get_icon_title, Some(true));
+
+    let reports = WindowOptions::all_reports();
+    assert_eq!(reports.get_cell_size_pixels, Some(true));
+    assert_eq!(reports.get_win_title, Some(true));
+    assert_eq!(reports.push_title, Some(false));
+    assert_eq!(reports.raise_win, Some(false));
+    assert_eq!(
+        reports,
+        WindowOptions::none()
+            .with_get_cell_size_pixels(true)
+            .with_get_icon_title(true)
+            .with_get_screen_size_chars(true)
+            .with_get_screen_size_pixels(true)
+            .with_get_win_position(true)
+            .with_get_win_size_chars(true)
+            .with_get_win_size_pixels(true)
+            .with_get_win_state(true)
+            .with_get_win_title(true)
+    );
+}
+
+#[wasm_bindgen_test]
+fn diffs() {
+    let old = TerminalOptions::default()
+        .with_font_size(12.0)
+        .with_cursor_blink(true)
+        .with_theme(Theme::default().with_background("#000000"))
+        .with_window_options(WindowOptions::none());
+    assert!(old.diff(&old.clone()).is_empty());
+    assert_eq!(old.diff(&old.clone()).effect(), OptionEffect::None);
+
+    let new = old
+        .clone()
+        .with_cursor_blink(None)
+        .with_scrollback(100)
+        .with_window_options(WindowOptions::all_reports());
+    let diff = old.diff(&new);
+    assert_eq!(
+        diff.changes,
+        [
+            OptionChange {
+                name: "cursorBlink",
+                effect: OptionEffect::None,
+            },
+            OptionChange {
+                name: "scrollback",
+                effect: OptionEffect::None,
+            },
+            OptionChange {
+                name: "windowOptions",
+                effect: OptionEffect::None,
+            },
+        ]
+    );
+    assert!(!diff.refreshes());
+
+    let new = old
+        .clone()
+        .with_theme(Theme::default().with_background("#ffffff"))
+        .with_font_family("monospace");
+    let diff = old.diff(&new);
+    let names: Vec<_> = diff.changes.iter().map(|c| c.name).collect();
+    assert_eq!(names, ["fontFamily", "theme"]);
+    assert!(diff.refreshes());
+    assert!(diff.rebuilds_texture_atlas());
+}
+
+#[wasm_bindgen_test]
+fn diffs_that_resize() {
+    let old = TerminalOptions::default()
+        .with_cols(80)
+        .with_rows(24)
+        .with_font_size(12.0);
+
+    // The size is applied with `resize`, never as an option.
+    let diff = old.diff(&old.clone().with_cols(100).with_font_size(13.0));
+    assert!(diff.needs_resize());
+    assert!(diff.refreshes());
+    let keys: Vec<_> = Object::keys(&diff.to_object())
+        .iter()
+        .map(|key| key.as_string().unwrap())
+        .collect();
+    assert_eq!(keys, ["fontSize"]);
+
+    let terminal = FakeTerminal::new().with_size(80, 24).build();
+    diff.apply(&terminal).unwrap();
+    let options = get(&terminal, "options");
+    assert_eq!(get(&options, "fontSize"), 13.0);
+    assert!(get(&options, "cols").is_undefined());
+    assert_eq!(resized(&terminal).unwrap(), [100, 24]);
+
+    // Unset dimensions keep their current value; nothing else is set.
+    let terminal = FakeTerminal::new().with_size(120, 40).build();
+    old.diff(&old.clone().with_rows(None))
+        .apply(&terminal)
+        .unwrap();
+    assert!(get(&terminal, "options").is_undefined());
+    assert_eq!(resized(&terminal).unwrap(), [80, 40]);
+
+    // No resize unless the size changed.
+    let diff = old.diff(&old.clone().with_scrollback(10));
+    assert!(!diff.needs_resize());
+    let terminal = FakeTerminal::new().with_size(80, 24).build();
+    diff.apply(&terminal).unwrap();
+    assert_eq!(resized(&terminal), None);
+}
+
+#[wasm_bindgen_test]
+fn validation() {
+    let options = TerminalOptions::default()
+        .with_cols(80)
+        .with_rows(24)
+        .with_font_size(13.0)
+        .with_minimum_contrast_ratio(4.5);
+    assert_eq!(options.validate(), Ok(()));
+
+    let options = options
+        .with_cols(1)
+        .with_rows(0)
+        .with_tab_stop_width(0)
+        .with_font_size(f32::NAN)
+        .with_font_weight(FontWeight::Numeric(1200))
+        .with_font_weight_bold(FontWeight::Bold)
+        .with_minimum_contrast_ratio(0.5);
+    let err = options.clone().validated().unwrap_err();
+    let fields: Vec<_> = err.0.iter().map(|e| e.field).collect();
+    assert_eq!(
+        fields,
+        [
+            "cols",
+            "fontSize",
+            "fontWeight",
+            "minimumContrastRatio",
+            "rows",
+            "tabStopWidth",
+        ]
+    );
+    assert_eq!(err.0[0].value, 1.0);
+    assert_eq!(err.0[0].allowed, Allowed::Between(2.0, 4096.0));
+    assert_eq!(err.0[3].allowed, Allowed::Between(1.0, 21.0));
+    assert!(err
+        .to_string()
+        .contains("`fontWeight` is 1200 but must be between 1 and 1000"));
+}
+
+#[wasm_bindgen_test]
+fn validation_limits_size() {
+    let options = TerminalOptions::default().with_cols(4096).with_rows(4096);
+    assert_eq!(options.validate(), Ok(()));
+
+    // i.e. a size in pixels.
+    let err = options
+        .with_cols(4097)
+        .with_rows(1080)
+        .validate()
+        .unwrap_err();
+    let fields: Vec<_> = err.0.iter().map(|e| e.field).collect();
+    assert_eq!(fields, ["cols"]);
+    assert_eq!(err.0[0].allowed, Allowed::Between(2.0, MAX_SIZE));
+}
+
+#[wasm_bindgen_test]
+fn validated_setters() {
+    let terminal = FakeTerminal::new().with_size(80, 24).build();
+    let options = TerminalOptions::default()
+        .with_rows(40)
+        .with_font_size(13.0);
+    terminal.try_set_options(&options).unwrap();
+    let set = get(&terminal, "options");
+    assert_eq!(Object::keys(&set.clone().into()).length(), 1);
+    assert_eq!(get(&set, "fontSize"), 13.0);
+    assert_eq!(resized(&terminal).unwrap(), [80, 40]);
+
+    // Nothing is set if anything is out of range.
+    let terminal = FakeTerminal::new().with_size(80, 24).build();
+    let err = terminal
+        .try_set_options(&options.with_cols(10_000))
+        .unwrap_err();
+    assert_eq!(err.0[0].field, "cols");
+    assert!(get(&terminal, "options").is_undefined());
+    assert_eq!(resized(&terminal), None);
+}
+
+#[wasm_bindgen_test]
+fn font_weights() {
+    assert_eq!(FontWeight::from_str("bold"), Some(FontWeight::Bold));
+    assert_eq!(FontWeight::from_str("450"), Some(FontWeight::Numeric(450)));
+    assert_eq!(FontWeight::from_str("heavy"), None);
+    assert_eq!(FontWeight::_700, FontWeight::Numeric(700));
+
+    assert_eq!(FontWeight::Bold.to_number(), 700);
+    assert_eq!(FontWeight::Numeric(450).to_string(), "450");
+    assert!(FontWeight::Numeric(1000).is_valid());
+    assert!(!FontWeight::Numeric(0).is_valid());
+}
+
+#[wasm_bindgen_test]
+fn reads_plain_objects() {
+    let ansi = Array::of2(&"#000000".into(), &"#111111".into());
+
+    let options = TerminalOptions::from_object(&object(&[
+        ("fontSize", 13.into()),
+        ("fontWeight", "bold".into()),
+        ("scrollback", 10.into()),
+        ("cols", 80.5.into()),
+        ("cursorBlink", "yes".into()),
+        ("cursorStyle", "bar".into()),
+        (
+            "theme",
+            object(&[
+                ("background", "#000".into()),
+                ("extendedAnsi", ansi.into()),
+            ])
+            .into(),
+        ),
+        (
+            "windowOptions",
+            object(&[("getWinSizePixels", true.into())]).into(),
+        ),
+    ]))
+    .unwrap();
+
+    // Fields with the wrong type (or a fractional size) are left unset.
+    let expected = TerminalOptions::default()
+        .with_font_size(13.0)
+        .with_font_weight(FontWeight::Bold)
+        .with_scrollback(10)
+        .with_cursor_style(CursorStyle::Bar)
+        .with_theme(
+            Theme::default()
+                .with_background("#000")
+                .with_extended_ansi(vec!["#000000".into(), "#111111".into()]),
+        )
+        .with_window_options(
+            WindowOptions::default().with_get_win_size_pixels(true),
+        );
+    assert_eq!(options, expected);
+
+    assert_eq!(TerminalOptions::from_object(&JsValue::NULL), None);
+    assert_eq!(Theme::from_object(&"#000".into()), None);
+
+    // Required fields have to be there.
+    assert!(FunctionIdentifier::from_object(&object(&[])).is_none());
+    let id = FunctionIdentifier::from_object(&object(&[
+        ("final", "m".into()),
+        ("prefix", "?".into()),
+    ]))
+    .unwrap();
+    assert_eq!(id.final_byte(), "m");
+    assert_eq!(id.prefix().as_deref(), Some("?"));
+}
+
+#[wasm_bindgen_test]
+fn cleared_options_get_xterms_defaults() {
+    let old = TerminalOptions::default()
+        .with_scrollback(5000)
+        .with_font_family("monospace")
+        .with_custom_glyphs(false)
+        .with_theme(Theme::default().with_background("#ffffff"));
+    let diff = old.diff(&TerminalOptions::default());
+    let names: Vec<_> = diff.changes.iter().map(|c| c.name).collect();
+    assert_eq!(names, ["customGlyphs", "fontFamily", "scrollback", "theme"]);
+
+    // Not `undefined`, which xterm.js would turn into `NaN` and so on.
+    let terminal = FakeTerminal::new().build();
+    diff.apply(&terminal).unwrap();
+    let options = get(&terminal, "options");
+    assert_eq!(get(&options, "scrollback"), 1000);
+    assert_eq!(get(&options, "customGlyphs"), true);
+    assert_eq!(
+        get(&options, "fontFamily"),
+        "courier-new, courier, monospace"
+    );
+    assert_eq!(Object::keys(&get(&options, "theme").into()).length(), 0);
+
+    // Setting it again works as usual.
+    let diff = TerminalOptions::default().diff(&old);
+    diff.apply(&terminal).unwrap();
+    assert_eq!(get(&get(&terminal, "options"), "scrollback"), 5000);
+}
+
+#[wasm_bindgen_test]
+fn plain_object_round_trip() {
+    let options = TerminalOptions::default()
+        .with_cols(100)
+        .with_font_weight(FontWeight::Numeric(450))
+        .with_font_family("monospace")
+        .with_theme(Theme::nord())
+        .with_window_options(WindowOptions::all_reports());
+
+    // Only the fields that are set, under xterm.js's names.
+    let object = options.to_object();
+    let keys: Vec<_> = Object::keys(&object)
+        .iter()
+        .map(|key| key.as_string().unwrap())
+        .collect();
+    assert_eq!(
+        keys,
+        ["cols", "fontFamily", "fontWeight", "theme", "windowOptions"]
+    );
+    assert_eq!(get(&object, "fontWeight"), 450);
+    assert_eq!(
+        get(&get(&object, "theme"), "background").as_string(),
+        Theme::nord().background()
+    );
+
+    assert_eq!(TerminalOptions::from_object(&object), Some(options));
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "ext")]
+
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::color::Color;
+use xterm_js_sys::ext::theme::palette::ExtendedPalette;
+use xterm_js_sys::xterm::Theme;
+
+#[wasm_bindgen_test]
+fn xterm_palette() {
+    let palette = ExtendedPalette::Xterm.generate(&Theme::default()).unwrap();
+    assert_eq!(palette.len(), 240);
+    assert_eq!(palette[0], Color::rgb(0, 0, 0));
+    assert_eq!(palette[196 - 16], Color::rgb(0xFF, 0, 0));
+    assert_eq!(palette[208 - 16], Color::rgb(0xFF, 0x87, 0));
+    assert_eq!(palette[232 - 16], Color::rgb(8, 8, 8));
+    assert_eq!(palette[255 - 16], Color::rgb(0xEE, 0xEE, 0xEE));
+}
+
+#[wasm_bindgen_test]
+fn palette_from_theme() {
+    let mut theme = Theme::default();
+    theme.set_background_color(Some(Color::rgb(0x10, 0x10, 0x10)));
+    theme.set_foreground_color(Some(Color::rgb(0xF0, 0xF0, 0xF0)));
+    theme.set_blue_color(Some(Color::rgb(0, 0, 0xC0)));
+
+    theme
+        .fill_extended_ansi(ExtendedPalette::FromTheme)
+        .unwrap();
+    let palette = theme.extended_ansi_colors().unwrap().unwrap();
+    assert_eq!(palette.len(), 240);
+
+    // Two fifths of the way from the background to blue.
+    assert_eq!(palette[0], Color::rgb(0x10, 0x10, 0x10));
+    assert_eq!(palette[21 - 16], Color::rgb(0, 0, 0xC0));
+    assert_eq!(palette[18 - 16], Color::rgb(0x0A, 0x0A, 0x56));
+
+    // The ramp runs from the background to the foreground, exclusive.
+    assert_eq!(palette[232 - 16], Color::rgb(0x19, 0x19, 0x19));
+    assert_eq!(palette[255 - 16], Color::rgb(0xE7, 0xE7, 0xE7));
+
+    theme.set_red(Some("nope".into()));
+    assert!(ExtendedPalette::FromTheme.generate(&theme).is_err());
+    assert!(ExtendedPalette::Xterm.generate(&theme).is_ok());
+}
+
+#[wasm_bindgen_test]
+fn light_and_dark_variants() {
+    let default = Theme::default().to_light().unwrap();
+    assert_eq!(
+        default.background_color(),
+        Ok(Some(Color::rgb(0xFF, 0xFF, 0xFF)))
+    );
+    assert_eq!(default.foreground_color(), Ok(Some(Color::rgb(0, 0, 0))));
+    assert!(default.cursor().is_some());
+
+    let dark = Theme::nord();
+    assert!(dark.is_dark().unwrap());
+    assert_eq!(dark.to_dark().unwrap(), dark);
+
+    let light = dark.to_light().unwrap();
+    assert!(!light.is_dark().unwrap());
+
+    // Contrast with the background is kept (or improved).
+    let ratio = |t: &Theme, index: u8| {
+        let color: Color = t.ansi_color(index).unwrap().parse().unwrap();
+        color.contrast_ratio(t.background_color().unwrap().unwrap())
+    };
+    for index in 0..16 {
+        assert!(ratio(&light, index) >= ratio(&dark, index) - 0.01);
+    }
+
+    let back = light.to_dark().unwrap();
+    assert!(back.is_dark().unwrap());
+    assert_eq!(back.background_color(), dark.background_color());
+}
+
+#[wasm_bindgen_test]
+fn flipping_malformed_themes_fails() {
+    // Flipping can't drop extended colors that don't parse (which would shift
+    // the rest down).
+    let mut theme = Theme::nord();
+    theme.set_extended_ansi(Some(vec!["0x000000".into(), "#000000".into()]));
+    let err = theme.to_light().unwrap_err();
+    assert_eq!(err.0[0].field, "extendedAnsi[0]");
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "ext")]
+
+mod common;
+
+use common::{object, FakeTerminal};
+use core::panic::Location;
+use js_sys::{Function, Object, Reflect};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::panic::PanicHook;
+use xterm_js_sys::xterm::Terminal;
+
+#[wasm_bindgen_test]
+fn render_report() {
+    let location = Location::caller();
+    let report = PanicHook::new().render("oh no\nbad", Some(location));
+
+    assert!(report.starts_with("\x1b[?1l"));
+    assert!(report.contains("\x1b[?1000l"));
+    assert!(report.contains("\x1b[?25h"));
+    assert!(report.contains("\x1b[1;31moh no\r\nbad\x1b[0m\r\n"));
+    assert!(report.ends_with(&format!(
+        "at {}:{}:{}\x1b[0m\r\n",
+        location.file(),
+        location.line(),
+        location.column(),
+    )));
+}
+
+#[wasm_bindgen_test]
+fn render_bare_report() {
+    let report = PanicHook::new()
+        .with_reset_modes(false)
+        .with_show_cursor(false)
+        .render("oops", None);
+
+    assert_eq!(
+        report,
+        "\r\n\x1b[1;97;41m panicked \x1b[0m \x1b[1;31moops\x1b[0m\r\n"
+    );
+}
+
+/// A fake terminal whose `write` saves what's written to the global `name`
+/// (panic hooks can't hold on to JS values) and that throws when its buffer
+/// is read, like a disposed terminal.
+fn terminal(name: &str) -> Terminal {
+    let write = Function::new_with_args(
+        "data",
+        &format!("globalThis[{name:?}] = data;"),
+    );
+    let terminal = FakeTerminal::new().with(&[("write", write.into())]).build();
+
+    let buffer = Function::new_no_args("throw new Error('disposed');");
+    let getter = object(&[("get", buffer.into())]);
+    Object::define_property(
+        terminal.unchecked_ref(),
+        &"buffer".into(),
+        &getter,
+    );
+    terminal
+}
+
+/// What was written to the terminal saved as `name`, if anything was.
+fn written(name: &str) -> Option<String> {
+    Reflect::get(&js_sys::global(), &name.into())
+        .ok()?
+        .as_string()
+}
+
+// The test harness's panic hook records the message that `expected` is
+// checked against. The hook this puts in front of it only passes the panic on
+// if it was reported as expected, so the test fails if it wasn't, or if our
+// hook doesn't chain to the one installed before it.
+#[wasm_bindgen_test]
+#[should_panic(expected = "reported once")]
+fn hook_reports_then_chains() {
+    let harness = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // Reading the buffer threw, so there's no leaving the alternate
+        // buffer, but the rest of the report is there.
+        let reported = written("panicReported").map_or(false, |report| {
+            report.starts_with("\x1b[?1l") && report.contains("reported once")
+        });
+        if reported && written("panicUninstalled").is_none() {
+            harness(info);
+        }
+    }));
+
+    PanicHook::new().install(&terminal("panicUninstalled"));
+    assert!(PanicHook::uninstall().is_some());
+    assert!(PanicHook::uninstall().is_none());
+
+    // Installing again uses the new terminal (and doesn't chain twice).
+    PanicHook::new().install(&terminal("panicReported"));
+    panic!("reported once");
+}
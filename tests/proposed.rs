@@ -0,0 +1,90 @@
+//! Not `#![cfg(feature = "proposed-api")]`: without it, this checks that the
+//! proposed APIs aren't on `Terminal`.
+
+mod common;
+
+use common::FakeTerminal;
+use wasm_bindgen_test::*;
+use xterm_js_sys::xterm::Terminal;
+
+/// What [`NotProposed`]'s methods return.
+#[derive(Debug)]
+struct Missing;
+
+/// Stand-ins for the proposed APIs: method calls only resolve to these when
+/// the type doesn't have a (public) method of the same name, so the calls in
+/// `terminal_has_no_proposed_apis` only type check if `Terminal` doesn't.
+trait NotProposed {
+    fn markers(&self) -> Missing {
+        Missing
+    }
+
+    fn unicode(&self) -> Missing {
+        Missing
+    }
+
+    fn register_character_joiner<H>(&self, _handler: H) -> Missing {
+        Missing
+    }
+}
+
+impl NotProposed for Terminal {}
+
+#[wasm_bindgen_test]
+fn terminal_has_no_proposed_apis() {
+    let terminal = FakeTerminal::new().build();
+    let Missing = terminal.markers();
+    let Missing = terminal.unicode();
+    let Missing = terminal.register_character_joiner(());
+}
+
+#[cfg(feature = "proposed-api")]
+mod proposed {
+    use super::common::{object, FakeTerminal};
+    use js_sys::{Array, Function};
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen_test::*;
+    use xterm_js_sys::xterm::{ProposedTerminal, Str, Terminal};
+
+    /// A fake terminal whose options have `allowProposedApi` set to
+    /// `allowed`, with (fake) proposed APIs.
+    fn terminal(allowed: Option<bool>) -> Terminal {
+        let options: &[_] = match allowed {
+            Some(allowed) => &[("allowProposedApi", allowed.into())],
+            None => &[],
+        };
+        let register = Function::new_with_args("handler", "return 7;");
+        FakeTerminal::new()
+            .with_options(options)
+            .with(&[
+                ("markers", Array::new().into()),
+                ("unicode", object(&[("activeVersion", "11".into())]).into()),
+                ("registerCharacterJoiner", register.into()),
+            ])
+            .build()
+    }
+
+    #[wasm_bindgen_test]
+    fn needs_allow_proposed_api() {
+        // Unset and off are both refused, handing the terminal back.
+        let refused = terminal(None).into_proposed().unwrap_err();
+        assert!(refused.into_proposed().is_err());
+        assert!(terminal(Some(false)).into_proposed().is_err());
+
+        let proposed: ProposedTerminal =
+            terminal(Some(true)).into_proposed().unwrap();
+        let _: &Terminal = &proposed;
+        let _: Terminal = proposed.into_inner();
+    }
+
+    #[wasm_bindgen_test]
+    fn proposed_apis() {
+        let proposed = terminal(Some(true)).into_proposed().unwrap();
+        assert_eq!(proposed.markers().length(), 0);
+        assert_eq!(proposed.unicode().active_version(), "11");
+
+        let joiner: Closure<dyn FnMut(Str) -> Array> =
+            Closure::new(|_: Str| Array::new());
+        assert_eq!(proposed.register_character_joiner(&joiner), 7);
+    }
+}
@@ -0,0 +1,542 @@
+#![cfg(feature = "ext")]
+
+use js_sys::{Array, Promise, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::pty::{
+    ClientMessage, ConnectionState, PtyConfig, PtyConnection, PtyProtocol,
+    PtyProtocolError, ReconnectPolicy, ServerMessage,
+};
+use xterm_js_sys::xterm::Terminal;
+
+use std::{cell::RefCell, rc::Rc};
+
+/// Plays the server side of a PTY session: echoes input back as output and
+/// records what the client has told it.
+struct StandInServer {
+    protocol: PtyProtocol,
+    handshake: Option<String>,
+    size: Option<(u16, u16)>,
+    paused: bool,
+}
+
+impl StandInServer {
+    fn new(protocol: PtyProtocol) -> Self {
+        Self {
+            protocol,
+            handshake: None,
+            size: None,
+            paused: false,
+        }
+    }
+
+    /// Handles one frame from the client, returning the frames to send back.
+    fn receive(&mut self, frame: &[u8]) -> Vec<Vec<u8>> {
+        if self.handshake.is_none() {
+            self.handshake = Some(String::from_utf8(frame.to_vec()).unwrap());
+            return vec![self
+                .protocol
+                .encode_server(&ServerMessage::SetTitle("bash".into()))
+                .unwrap()];
+        }
+
+        let reply = match self.protocol.decode_client(frame).unwrap() {
+            ClientMessage::Input(data) if !self.paused => {
+                ServerMessage::Output(data.into_bytes())
+            }
+            ClientMessage::Input(_) => return vec![],
+            ClientMessage::Resize { cols, rows } => {
+                self.size = Some((cols, rows));
+                return vec![];
+            }
+            ClientMessage::Pause => {
+                self.paused = true;
+                return vec![];
+            }
+            ClientMessage::Resume => {
+                self.paused = false;
+                return vec![];
+            }
+            ClientMessage::Ping => ServerMessage::Pong,
+        };
+
+        vec![self.protocol.encode_server(&reply).unwrap()]
+    }
+}
+
+/// Runs a client through a session against the stand-in server.
+fn session(protocol: PtyProtocol) {
+    let mut server = StandInServer::new(protocol);
+    let client = |msg| protocol.encode_client(&msg).unwrap();
+
+    let replies =
+        server.receive(&protocol.handshake(Some("s3cr\"t"), None, 80, 24));
+    assert_eq!(
+        protocol.decode_server(&replies[0]),
+        Ok(ServerMessage::SetTitle("bash".into()))
+    );
+    assert!(server.handshake.as_ref().unwrap().contains(r#""s3cr\"t""#));
+
+    let replies = server.receive(&client(ClientMessage::Resize {
+        cols: 132,
+        rows: 43,
+    }));
+    assert!(replies.is_empty());
+    assert_eq!(server.size, Some((132, 43)));
+
+    let replies =
+        server.receive(&client(ClientMessage::Input("ls -l ✓\r".into())));
+    assert_eq!(
+        protocol.decode_server(&replies[0]),
+        Ok(ServerMessage::Output("ls -l ✓\r".as_bytes().to_vec()))
+    );
+}
+
+#[wasm_bindgen_test]
+fn ttyd_session() {
+    session(PtyProtocol::Ttyd);
+
+    let mut server = StandInServer::new(PtyProtocol::Ttyd);
+    let _ = server.receive(b"{}");
+    let _ = server.receive(
+        &PtyProtocol::Ttyd
+            .encode_client(&ClientMessage::Pause)
+            .unwrap(),
+    );
+    assert!(server.paused);
+    assert_eq!(PtyProtocol::Ttyd.encode_client(&ClientMessage::Ping), None);
+}
+
+#[wasm_bindgen_test]
+fn gotty_session() {
+    session(PtyProtocol::Gotty);
+
+    // gotty output is base64 encoded on the wire.
+    let frame = PtyProtocol::Gotty
+        .encode_server(&ServerMessage::Output(vec![0, 1, 2, 0xff, b'x']))
+        .unwrap();
+    assert_eq!(frame, b"1AAEC/3g=");
+    assert_eq!(
+        PtyProtocol::Gotty.decode_server(b"1AAEC/3g"),
+        Ok(ServerMessage::Output(vec![0, 1, 2, 0xff, b'x']))
+    );
+    assert_eq!(
+        PtyProtocol::Gotty.decode_server(b"1!!"),
+        Err(PtyProtocolError::InvalidBase64)
+    );
+    assert_eq!(
+        PtyProtocol::Gotty.decode_server(b"530"),
+        Ok(ServerMessage::SetReconnect(30))
+    );
+    assert_eq!(
+        PtyProtocol::Gotty.encode_client(&ClientMessage::Resume),
+        None
+    );
+}
+
+#[wasm_bindgen_test]
+fn malformed_frames() {
+    assert_eq!(
+        PtyProtocol::Ttyd.decode_server(b""),
+        Err(PtyProtocolError::Empty)
+    );
+    assert_eq!(
+        PtyProtocol::Ttyd.decode_server(b"9"),
+        Err(PtyProtocolError::UnknownCommand(b'9'))
+    );
+    assert_eq!(
+        PtyProtocol::Ttyd.decode_client(br#"1{"columns":80}"#),
+        Err(PtyProtocolError::MalformedPayload)
+    );
+}
+
+#[wasm_bindgen_test]
+fn resize_payloads() {
+    let decode = |json: &str| {
+        PtyProtocol::Ttyd.decode_client(format!("1{json}").as_bytes())
+    };
+
+    assert_eq!(
+        decode(r#"{ "rows" : 24, "columns" : 80 }"#),
+        Ok(ClientMessage::Resize { cols: 80, rows: 24 })
+    );
+    assert_eq!(
+        decode(r#"{"columns":65535,"rows":0}"#),
+        Ok(ClientMessage::Resize {
+            cols: u16::MAX,
+            rows: 0
+        })
+    );
+
+    // Out of range, fractional, negative, not numbers, nested or not JSON.
+    for json in [
+        r#"{"columns":65536,"rows":24}"#,
+        r#"{"columns":80.5,"rows":24}"#,
+        r#"{"columns":-1,"rows":24}"#,
+        r#"{"columns":"80","rows":24}"#,
+        r#"{"columns":null,"rows":24}"#,
+        r#"{"size":{"columns":80},"rows":24}"#,
+        r#"{"columns":80,"rows":24"#,
+        "80",
+    ] {
+        assert_eq!(
+            decode(json),
+            Err(PtyProtocolError::MalformedPayload),
+            "{json}"
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn reconnect_backoff() {
+    let policy = ReconnectPolicy {
+        initial_delay_ms: 100,
+        max_delay_ms: 1_000,
+        multiplier: 3,
+        max_attempts: Some(4),
+    };
+
+    assert_eq!(policy.delay_for(1), Some(100));
+    assert_eq!(policy.delay_for(2), Some(300));
+    assert_eq!(policy.delay_for(3), Some(900));
+    assert_eq!(policy.delay_for(4), Some(1_000));
+    assert_eq!(policy.delay_for(5), None);
+
+    assert_eq!(ReconnectPolicy::never().delay_for(1), None);
+    assert_eq!(ReconnectPolicy::default().delay_for(100), Some(30_000));
+}
+
+// A stand-in `WebSocket` (and terminal) for driving a real `PtyConnection`.
+//
+// `install` swaps the global `WebSocket` constructor for one that records the
+// sockets it makes; the helpers below play the server's side of them. Node
+// has no `window` (which the reconnect timer is scheduled on) so the global
+// object is made to pass for one there.
+#[wasm_bindgen(inline_js = r#"
+    const sockets = [];
+
+    class FakeWebSocket {
+        constructor(url, protocol) {
+            this.url = url;
+            this.protocol = protocol;
+            this.binaryType = "blob";
+            this.readyState = 0;
+            this.sent = [];
+            sockets.push(this);
+        }
+
+        send(data) {
+            this.sent.push(typeof data === "string" ? data : data.slice());
+        }
+
+        close() {
+            this.readyState = 3;
+        }
+    }
+
+    export function install() {
+        globalThis.WebSocket = FakeWebSocket;
+        sockets.length = 0;
+
+        if (typeof Window === "undefined") {
+            globalThis.Window = function Window() {};
+            globalThis.Window.prototype = Object.getPrototypeOf(globalThis);
+        }
+    }
+
+    export function socket_count() { return sockets.length; }
+    export function url(i) { return sockets[i].url; }
+    export function subprotocol(i) { return sockets[i].protocol; }
+    export function binary_type(i) { return sockets[i].binaryType; }
+    export function sent(i) { return sockets[i].sent; }
+
+    export function open(i) {
+        sockets[i].readyState = 1;
+        sockets[i].onopen?.();
+    }
+
+    export function receive(i, frame) {
+        sockets[i].onmessage?.({ data: frame.slice().buffer });
+    }
+
+    export function drop_connection(i, code, reason) {
+        sockets[i].readyState = 3;
+        sockets[i].onclose?.({ code, reason });
+    }
+
+    export function terminal() {
+        const listeners = (list) => (listener) => {
+            list.push(listener);
+            return { dispose() { list.splice(list.indexOf(listener), 1); } };
+        };
+        const data = [], resize = [];
+
+        return {
+            cols: 80,
+            rows: 24,
+            written: [],
+            write(bytes) { this.written.push(...bytes); },
+            onData: listeners(data),
+            onResize: listeners(resize),
+            input(s) { data.forEach((l) => l(s)); },
+            resize(cols, rows) {
+                this.cols = cols;
+                this.rows = rows;
+                resize.forEach((l) => l({ cols, rows }));
+            },
+            listenerCount() { return data.length + resize.length; },
+        };
+    }
+
+    export function sleep(ms) {
+        return new Promise((resolve) => setTimeout(resolve, ms));
+    }
+"#)]
+extern "C" {
+    fn install();
+    fn socket_count() -> u32;
+    fn url(i: u32) -> String;
+    fn subprotocol(i: u32) -> String;
+    fn binary_type(i: u32) -> String;
+    fn sent(i: u32) -> Array;
+    fn open(i: u32);
+    fn receive(i: u32, frame: &[u8]);
+    fn drop_connection(i: u32, code: u16, reason: &str);
+    fn sleep(ms: u32) -> Promise;
+
+    #[wasm_bindgen(extends = Terminal)]
+    type FakeTerminal;
+    fn terminal() -> FakeTerminal;
+    #[wasm_bindgen(method)]
+    fn input(this: &FakeTerminal, data: &str);
+    #[wasm_bindgen(method)]
+    fn resize(this: &FakeTerminal, cols: u16, rows: u16);
+    #[wasm_bindgen(method, getter)]
+    fn written(this: &FakeTerminal) -> Array;
+    #[wasm_bindgen(method, js_name = listenerCount)]
+    fn listener_count(this: &FakeTerminal) -> u32;
+}
+
+/// The frames the client has sent on socket `i`.
+fn frames(i: u32) -> Vec<Vec<u8>> {
+    sent(i)
+        .iter()
+        .map(|frame| match frame.as_string() {
+            Some(s) => s.into_bytes(),
+            None => Uint8Array::new(&frame).to_vec(),
+        })
+        .collect()
+}
+
+/// Connects a fake terminal to a fake server, recording state changes.
+fn connect(
+    config: PtyConfig,
+) -> (
+    FakeTerminal,
+    PtyConnection,
+    Rc<RefCell<Vec<ConnectionState>>>,
+) {
+    install();
+    let terminal = terminal();
+    let connection = PtyConnection::connect(&terminal, config).unwrap();
+
+    let states = Rc::new(RefCell::new(Vec::new()));
+    let log = states.clone();
+    connection.on_state_change(move |s| log.borrow_mut().push(s.clone()));
+
+    (terminal, connection, states)
+}
+
+/// Waits for a reconnect timer (of at most `ms` milliseconds) to fire.
+async fn wait(ms: u32) {
+    JsFuture::from(sleep(ms + 20)).await.unwrap();
+}
+
+#[wasm_bindgen_test]
+fn connection_handshake_and_forwarding() {
+    let config = PtyConfig::new("ws://localhost:7681/ws", PtyProtocol::Ttyd)
+        .with_auth_token("s3cr3t");
+    let (terminal, connection, states) = connect(config);
+    let ttyd = PtyProtocol::Ttyd;
+
+    assert_eq!(socket_count(), 1);
+    assert_eq!(url(0), "ws://localhost:7681/ws");
+    assert_eq!(subprotocol(0), "tty");
+    assert_eq!(binary_type(0), "arraybuffer");
+    assert_eq!(
+        connection.state(),
+        ConnectionState::Connecting { attempt: 0 }
+    );
+
+    // Nothing is sent before the socket opens.
+    terminal.input("early");
+    assert!(frames(0).is_empty());
+
+    open(0);
+    assert_eq!(frames(0), [ttyd.handshake(Some("s3cr3t"), None, 80, 24)]);
+    assert_eq!(*states.borrow(), [ConnectionState::Open]);
+
+    terminal.input("ls\r");
+    terminal.resize(132, 43);
+    assert_eq!(
+        frames(0)[1..],
+        [
+            ttyd.encode_client(&ClientMessage::Input("ls\r".into()))
+                .unwrap(),
+            ttyd.encode_client(&ClientMessage::Resize {
+                cols: 132,
+                rows: 43,
+            })
+            .unwrap(),
+        ]
+    );
+
+    let titles = Rc::new(RefCell::new(Vec::new()));
+    let log = titles.clone();
+    connection.on_title_change(move |t| log.borrow_mut().push(t.to_owned()));
+
+    let output = ServerMessage::Output(b"total 0\r\n".to_vec());
+    receive(0, &ttyd.encode_server(&output).unwrap());
+    let title = ServerMessage::SetTitle("bash".into());
+    receive(0, &ttyd.encode_server(&title).unwrap());
+    assert_eq!(
+        Uint8Array::new(&terminal.written()).to_vec(),
+        b"total 0\r\n"
+    );
+    assert_eq!(*titles.borrow(), ["bash"]);
+
+    // Dropping the connection detaches from the terminal and the socket.
+    drop(connection);
+    assert_eq!(terminal.listener_count(), 0);
+    terminal.input("late");
+    assert_eq!(frames(0).len(), 3);
+}
+
+#[wasm_bindgen_test]
+fn gotty_sends_size_on_open() {
+    let config = PtyConfig::new("ws://localhost:8080/ws", PtyProtocol::Gotty)
+        .with_arguments("?arg=-l");
+    let (_terminal, _connection, _) = connect(config);
+    let gotty = PtyProtocol::Gotty;
+
+    assert_eq!(subprotocol(0), "webtty");
+    open(0);
+    assert_eq!(
+        frames(0),
+        [
+            gotty.handshake(None, Some("?arg=-l"), 80, 24),
+            gotty
+                .encode_client(&ClientMessage::Resize { cols: 80, rows: 24 })
+                .unwrap(),
+        ]
+    );
+}
+
+#[wasm_bindgen_test]
+async fn reconnects_with_backoff() {
+    let policy = ReconnectPolicy {
+        initial_delay_ms: 10,
+        max_delay_ms: 1_000,
+        multiplier: 3,
+        max_attempts: Some(2),
+    };
+    let config = PtyConfig::new("ws://localhost:7681/ws", PtyProtocol::Ttyd)
+        .with_reconnect(policy);
+    let (terminal, connection, states) = connect(config);
+
+    open(0);
+    drop_connection(0, 1006, "");
+    assert_eq!(
+        connection.state(),
+        ConnectionState::Reconnecting {
+            attempt: 1,
+            delay_ms: 10,
+        }
+    );
+
+    wait(10).await;
+    assert_eq!(socket_count(), 2);
+    assert_eq!(
+        connection.state(),
+        ConnectionState::Connecting { attempt: 1 }
+    );
+
+    // Failing again backs off further.
+    drop_connection(1, 1006, "");
+    assert_eq!(
+        connection.state(),
+        ConnectionState::Reconnecting {
+            attempt: 2,
+            delay_ms: 30,
+        }
+    );
+
+    // A successful open resets the attempt count and redoes the handshake
+    // (with the terminal's current size).
+    wait(30).await;
+    terminal.resize(100, 30);
+    open(2);
+    assert_eq!(
+        frames(2),
+        [PtyProtocol::Ttyd.handshake(None, None, 100, 30)]
+    );
+
+    drop_connection(2, 1006, "");
+    wait(10).await;
+    drop_connection(3, 1006, "");
+    wait(30).await;
+    drop_connection(4, 1011, "gone");
+
+    // Out of attempts.
+    assert_eq!(socket_count(), 5);
+    assert_eq!(
+        states.borrow().last(),
+        Some(&ConnectionState::Closed {
+            code: 1011,
+            reason: "gone".into(),
+        })
+    );
+    assert_eq!(
+        states
+            .borrow()
+            .iter()
+            .filter(|s| matches!(s, ConnectionState::Open))
+            .count(),
+        2
+    );
+}
+
+#[wasm_bindgen_test]
+async fn close_stops_reconnecting() {
+    let config = PtyConfig::new("ws://localhost:7681/ws", PtyProtocol::Ttyd)
+        .with_reconnect(ReconnectPolicy::never());
+    let (_terminal, connection, _) = connect(config);
+
+    open(0);
+    drop_connection(0, 1006, "");
+    assert_eq!(
+        connection.state(),
+        ConnectionState::Closed {
+            code: 1006,
+            reason: "".into(),
+        }
+    );
+
+    let config = PtyConfig::new("ws://localhost:7681/ws", PtyProtocol::Ttyd);
+    let (_terminal, connection, _) = connect(config);
+
+    open(0);
+    drop_connection(0, 1006, "");
+    connection.close();
+    assert_eq!(
+        connection.state(),
+        ConnectionState::Closed {
+            code: 1000,
+            reason: "".into(),
+        }
+    );
+
+    wait(ReconnectPolicy::default().initial_delay_ms).await;
+    assert_eq!(socket_count(), 1);
+}
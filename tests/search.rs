@@ -0,0 +1,105 @@
+#![cfg(all(feature = "ext", feature = "addon-search"))]
+
+mod common;
+
+use common::{get, object};
+use js_sys::{Function, Object};
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_test::*;
+use xterm_js_sys::addons::search::{
+    SearchAddon, SearchDecorationOptions, SearchOptions,
+};
+
+#[wasm_bindgen_test]
+fn builders() {
+    let decorations = SearchDecorationOptions::default()
+        .with_match_background("#444444")
+        .with_active_match_border(String::from("#ffffff"))
+        .with_match_overview_ruler("#888888");
+    assert_eq!(decorations.match_background().as_deref(), Some("#444444"));
+    assert_eq!(
+        decorations.active_match_border().as_deref(),
+        Some("#ffffff")
+    );
+    assert_eq!(
+        decorations.match_overview_ruler().as_deref(),
+        Some("#888888")
+    );
+    assert_eq!(decorations.active_match_color_overview_ruler(), None);
+
+    let options = SearchOptions::default()
+        .with_regex(true)
+        .with_case_sensitive(false)
+        .with_whole_word(None)
+        .with_decorations(decorations.clone());
+    assert_eq!(options.regex, Some(true));
+    assert_eq!(options.case_sensitive, Some(false));
+    assert_eq!(options.whole_word, None);
+    assert_eq!(options.incremental, None);
+    assert_eq!(options.decorations(), Some(decorations));
+}
+
+#[wasm_bindgen_test]
+fn unset_colors_are_left_out() {
+    // Only the colors that are set are passed on, overview ruler ones
+    // included.
+    let options = SearchOptions::default()
+        .with_incremental(true)
+        .with_decorations(
+            SearchDecorationOptions::default().with_match_border("#ff0000"),
+        );
+    let object = options.to_object();
+    let keys: Vec<_> = Object::keys(&object)
+        .iter()
+        .map(|key| key.as_string().unwrap())
+        .collect();
+    assert_eq!(keys, ["incremental", "decorations"]);
+
+    let decorations = get(&object, "decorations");
+    assert_eq!(Object::keys(decorations.unchecked_ref()).length(), 1);
+    assert_eq!(get(&decorations, "matchBorder"), "#ff0000");
+
+    assert_eq!(SearchOptions::from_object(&object), Some(options));
+}
+
+#[wasm_bindgen_test]
+fn results_change_listener() {
+    // A fake addon that holds on to its listener.
+    let on_did_change_results = Function::new_with_args(
+        "listener",
+        "this.listener = listener;
+         return { dispose: () => { this.disposed = true; } };",
+    );
+    let addon: SearchAddon =
+        object(&[("onDidChangeResults", on_did_change_results.into())])
+            .unchecked_into();
+
+    let results = Rc::new(RefCell::new(Vec::new()));
+    let listener = addon.attach_results_change_event_listener({
+        let results = results.clone();
+        move |r| {
+            results
+                .borrow_mut()
+                .push((r.get_result_index(), r.result_count()));
+        }
+    });
+
+    let fire = |index: i32, count: u32| {
+        let event = object(&[
+            ("resultIndex", index.into()),
+            ("resultCount", count.into()),
+        ]);
+        let listener: Function = get(&addon, "listener").unchecked_into();
+        listener.call1(&JsValue::UNDEFINED, &event).unwrap();
+    };
+    fire(2, 10);
+    // No active match (or too many matches to highlight).
+    fire(-1, 1500);
+    assert_eq!(*results.borrow(), [(Some(2), 10), (None, 1500)]);
+
+    assert!(get(&addon, "disposed").is_undefined());
+    drop(listener);
+    assert_eq!(get(&addon, "disposed"), true);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "serde")]
+
+use wasm_bindgen_test::*;
+use xterm_js_sys::xterm::{
+    CursorStyle, FontWeight, FunctionIdentifier, LogLevel, Modes,
+    MouseTrackingMode, TerminalOptions, Theme, WindowOptions,
+};
+
+#[wasm_bindgen_test]
+fn camel_case_round_trip() {
+    let mut theme = Theme::default();
+    theme.set_foreground(Some("#ffffff".into()));
+    theme.set_bright_black(Some("#808080".into()));
+
+    let mut window_options = WindowOptions::default();
+    window_options.get_win_size_pixels = Some(true);
+
+    let mut options = TerminalOptions::default();
+    options.cursor_blink = Some(true);
+    options.cursor_style = Some(CursorStyle::Bar);
+    options.font_weight_bold = Some(FontWeight::_700);
+    options.set_theme(Some(theme));
+    options.set_window_options(Some(window_options));
+
+    let json = serde_json::to_value(&options).unwrap();
+    assert_eq!(json["cursorBlink"], true);
+    assert_eq!(json["cursorStyle"], "bar");
+    assert_eq!(json["fontWeightBold"], 700);
+    assert_eq!(json["theme"]["brightBlack"], "#808080");
+    assert_eq!(json["windowOptions"]["getWinSizePixels"], true);
+    assert!(json.get("logger").is_none());
+
+    // Unset fields are left out rather than written as `null`.
+    assert!(json.get("scrollback").is_none());
+    assert!(json["theme"].get("background").is_none());
+    assert_eq!(
+        serde_json::to_string(&WindowOptions::default()).unwrap(),
+        "{}"
+    );
+    let id = FunctionIdentifier::new(None, None, "m".into());
+    assert_eq!(serde_json::to_string(&id).unwrap(), r#"{"final":"m"}"#);
+
+    #[cfg(feature = "xterm-5")]
+    {
+        let level = serde_json::json!("trace");
+        let level: LogLevel = serde_json::from_value(level).unwrap();
+        assert_eq!(level, LogLevel::Trace);
+        assert_eq!(serde_json::to_value(level).unwrap(), "trace");
+    }
+
+    let back: TerminalOptions = serde_json::from_value(json).unwrap();
+    assert_eq!(back, options);
+
+    let modes: Modes = serde_json::from_str(
+        r#"{ "bracketedPasteMode": true, "mouseTrackingMode": "drag" }"#,
+    )
+    .unwrap();
+    assert_eq!(modes.bracketed_paste_mode, Some(true));
+    assert_eq!(modes.mouse_tracking_mode, Some(MouseTrackingMode::Drag));
+
+    let options: TerminalOptions = serde_json::from_str(
+        r#"{ "fontWeight": "normal", "fontWeightBold": 650 }"#,
+    )
+    .unwrap();
+    assert_eq!(options.font_weight, Some(FontWeight::Normal));
+    assert_eq!(options.font_weight_bold, Some(FontWeight::Numeric(650)));
+
+    let id: FunctionIdentifier =
+        serde_json::from_str(r#"{ "prefix": "?", "final": "m" }"#).unwrap();
+    assert_eq!(id.final_byte(), "m");
+}
+
+#[wasm_bindgen_test]
+fn rejects_unknown_fields_and_values() {
+    let err =
+        serde_json::from_str::<TerminalOptions>(r#"{ "cursorBlnk": true }"#)
+            .unwrap_err()
+            .to_string();
+    assert!(err.starts_with("unknown field `cursorBlnk`"), "{err}");
+
+    let err =
+        serde_json::from_str::<TerminalOptions>(r#"{ "cursorStyle": "beam" }"#)
+            .unwrap_err()
+            .to_string();
+    assert!(
+        err.starts_with(
+            "invalid value: string \"beam\", expected one of `block`, \
+             `underline`, `bar`"
+        ),
+        "{err}"
+    );
+
+    let err =
+        serde_json::from_str::<TerminalOptions>(r#"{ "fontWeight": "heavy" }"#)
+            .unwrap_err()
+            .to_string();
+    assert!(
+        err.starts_with(
+            "invalid value: string \"heavy\", expected `normal`, `bold` or \
+             a number from 1 to 1000"
+        ),
+        "{err}"
+    );
+
+    let weight = |json: &str| {
+        serde_json::from_str::<TerminalOptions>(&format!(
+            r#"{{ "fontWeight": {json} }}"#
+        ))
+        .map(|options| options.font_weight.unwrap())
+        .map_err(|err| err.to_string())
+    };
+    assert_eq!(weight("450.0"), Ok(FontWeight::Numeric(450)));
+    assert_eq!(weight("\"1000\""), Ok(FontWeight::Numeric(1000)));
+    for json in ["0", "1200", "-100", "450.5", "1e9", "\"0\"", "\"70000\""] {
+        let err = weight(json).unwrap_err();
+        assert!(err.starts_with("invalid value: "), "{json}: {err}");
+    }
+
+    let err = serde_json::from_str::<Theme>(
+        r##"{ "foreground": "#fff", "fg": "" }"##,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.starts_with("unknown field `fg`"), "{err}");
+}
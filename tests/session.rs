@@ -0,0 +1,95 @@
+#![cfg(all(feature = "ext", feature = "addon-serialize"))]
+
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::session::SessionSnapshot;
+use xterm_js_sys::xterm::{Modes, MouseTrackingMode, TerminalOptions};
+
+/// Applies the SM/RM and DECSET/DECRST sequences in `data` to `modes`, the way
+/// a terminal restoring a snapshot would.
+fn apply(modes: &mut Modes, data: &str) {
+    for seq in data.split("\x1b[").skip(1) {
+        let (private, seq) = match seq.strip_prefix('?') {
+            Some(rest) => (true, rest),
+            None => (false, seq),
+        };
+        let end = seq.find(['h', 'l']).unwrap();
+        let set = &seq[end..=end] == "h";
+        let mode: u16 = seq[..end].parse().unwrap();
+
+        match (private, mode) {
+            (true, 1) => modes.application_cursor_keys_mode = Some(set),
+            (true, 66) => modes.application_keypad_mode = Some(set),
+            (true, 2004) => modes.bracketed_paste_mode = Some(set),
+            (false, 4) => modes.insert_mode = Some(set),
+            (true, 6) => modes.origin_mode = Some(set),
+            (true, 45) => modes.reverse_wraparound_mode = Some(set),
+            (true, 1004) => modes.send_focus_mode = Some(set),
+            (true, 7) => modes.wraparound_mode = Some(set),
+            (true, 9 | 1000 | 1002 | 1003) => {
+                let tracking = match mode {
+                    9 => MouseTrackingMode::X10,
+                    1000 => MouseTrackingMode::Vt200,
+                    1002 => MouseTrackingMode::Drag,
+                    _ => MouseTrackingMode::Any,
+                };
+                if set {
+                    modes.mouse_tracking_mode = Some(tracking);
+                } else if modes.mouse_tracking_mode == Some(tracking)
+                    || modes.mouse_tracking_mode.is_none()
+                {
+                    modes.mouse_tracking_mode = Some(MouseTrackingMode::None);
+                }
+            }
+            other => panic!("unexpected mode: {other:?}"),
+        }
+    }
+}
+
+fn snapshot(modes: Modes) -> SessionSnapshot {
+    SessionSnapshot {
+        options: TerminalOptions::default(),
+        cols: 120,
+        rows: 40,
+        modes,
+        data: "\x1b[1mbold\x1b[0m\r\nplain".into(),
+    }
+}
+
+#[wasm_bindgen_test]
+fn modes_round_trip() {
+    let modes = Modes {
+        application_cursor_keys_mode: Some(true),
+        application_keypad_mode: Some(false),
+        bracketed_paste_mode: Some(true),
+        insert_mode: Some(true),
+        mouse_tracking_mode: Some(MouseTrackingMode::Drag),
+        origin_mode: Some(false),
+        reverse_wraparound_mode: Some(true),
+        send_focus_mode: Some(true),
+        wraparound_mode: Some(false),
+    };
+
+    let restored = snapshot(modes.clone()).restore_data();
+    assert!(restored.starts_with("\x1b[1mbold\x1b[0m\r\nplain"));
+
+    let mut after = Modes::default();
+    apply(&mut after, &modes.to_escape_sequences());
+    assert_eq!(after, modes);
+
+    let none = Modes {
+        mouse_tracking_mode: Some(MouseTrackingMode::None),
+        ..Modes::default()
+    };
+    let mut after = modes;
+    apply(&mut after, &none.to_escape_sequences());
+    assert_eq!(after.mouse_tracking_mode, Some(MouseTrackingMode::None));
+}
+
+#[wasm_bindgen_test]
+fn unset_modes_are_left_alone() {
+    assert_eq!(Modes::default().to_escape_sequences(), "");
+    assert_eq!(
+        snapshot(Modes::default()).restore_data(),
+        snapshot(Modes::default()).data
+    );
+}
@@ -0,0 +1,148 @@
+#![cfg(all(feature = "ext", feature = "addon-serialize"))]
+
+// Round trips through real terminals. These need `@xterm/xterm` and
+// `@xterm/addon-serialize` installed, and node needs `tests/common/globals.js`
+// preloaded (see the "xterm.js" steps in `.github/workflows/full.yml`):
+//
+// NODE_OPTIONS="--require $PWD/tests/common/globals.js" wasm-pack test --node \
+//     -- --features addon-serialize --test session_xterm
+
+use js_sys::{Promise, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::JsFuture;
+use wasm_bindgen_test::*;
+use xterm_js_sys::addons::serialize::{SerializeAddon, SerializeOptions};
+use xterm_js_sys::ext::session::SessionSnapshot;
+use xterm_js_sys::xterm::{
+    Modes, MouseTrackingMode, Terminal, TerminalOptions,
+};
+
+/// Writes `data` to `terminal` and waits for it to be parsed.
+async fn write(terminal: &Terminal, data: &str) {
+    let mut callback = None;
+    let parsed = Promise::new(&mut |resolve, _| {
+        let done = Closure::once(move || {
+            let _ = resolve.call0(&JsValue::UNDEFINED);
+        });
+        terminal.write_with_callback(data.into(), &done);
+        callback = Some(done);
+    });
+
+    JsFuture::from(parsed).await.unwrap();
+}
+
+/// A terminal of the given size with a serialize addon loaded.
+fn terminal(cols: u16, rows: u16) -> (Terminal, SerializeAddon) {
+    let options = TerminalOptions::default()
+        .with_cols(cols)
+        .with_rows(rows)
+        .with_font_size(13.0);
+
+    // xterm.js only sees the options if they're a plain object.
+    let terminal = Terminal::new_with_options_object(&options.to_object());
+    let addon = SerializeAddon::new();
+    terminal.load_addon(addon.clone().into());
+
+    (terminal, addon)
+}
+
+/// The text of every line in the terminal's active buffer.
+fn lines(terminal: &Terminal) -> Vec<String> {
+    let buffer = terminal.buffer().active();
+
+    (0..u16::try_from(buffer.length()).unwrap())
+        .map(|y| {
+            buffer.get_line(y).unwrap().translate_to_string(
+                Some(true),
+                None,
+                None,
+            )
+        })
+        .collect()
+}
+
+/// The terminal's modes (which xterm.js hands back as a plain object).
+fn modes(terminal: &Terminal) -> Modes {
+    Modes::from_object(&Reflect::get(terminal, &"modes".into()).unwrap())
+        .unwrap()
+}
+
+/// The terminal's font size.
+fn font_size(terminal: &Terminal) -> JsValue {
+    let options = Reflect::get(terminal, &"options".into()).unwrap();
+    Reflect::get(&options, &"fontSize".into()).unwrap()
+}
+
+/// What the addon serializes, without the modes.
+fn contents(addon: &SerializeAddon) -> String {
+    addon.serialize(Some(SerializeOptions {
+        exclude_modes: Some(true),
+        ..SerializeOptions::default()
+    }))
+}
+
+/// A terminal with some styled text and a few modes set, and a snapshot of it.
+async fn captured() -> (Terminal, SessionSnapshot) {
+    let (terminal, addon) = terminal(60, 12);
+    write(
+        &terminal,
+        "\x1b[1;31mbold red\x1b[0m plain\r\n$ ls\r\nsrc\r\n$ ",
+    )
+    .await;
+    write(
+        &terminal,
+        "\x1b[?2004h\x1b[?1h\x1b[4h\x1b[?1002h\x1b[?1004h",
+    )
+    .await;
+
+    let snapshot = SessionSnapshot::capture(&terminal, &addon, None).unwrap();
+    (terminal, snapshot)
+}
+
+#[wasm_bindgen_test]
+async fn capture_reads_the_terminal() {
+    let (terminal, snapshot) = captured().await;
+
+    assert_eq!((snapshot.cols, snapshot.rows), (60, 12));
+    assert_eq!(snapshot.options.font_size, Some(13.0));
+    assert_eq!(snapshot.modes, modes(&terminal));
+    assert_eq!(snapshot.modes.bracketed_paste_mode, Some(true));
+    assert_eq!(snapshot.modes.application_cursor_keys_mode, Some(true));
+    assert_eq!(snapshot.modes.insert_mode, Some(true));
+    assert_eq!(snapshot.modes.send_focus_mode, Some(true));
+    assert_eq!(
+        snapshot.modes.mouse_tracking_mode,
+        Some(MouseTrackingMode::Drag)
+    );
+
+    // The modes are kept out of the serialized contents.
+    assert!(snapshot.data.contains("bold red"));
+    assert!(!snapshot.data.contains("\x1b[?2004h"));
+}
+
+#[wasm_bindgen_test]
+async fn restore_into_matches_the_original() {
+    let (original, snapshot) = captured().await;
+    let (restored, addon) = terminal(20, 5);
+
+    snapshot.restore_into(&restored);
+    write(&restored, "").await;
+
+    assert_eq!((restored.cols(), restored.rows()), (60, 12));
+    assert_eq!(lines(&restored), lines(&original));
+    assert_eq!(contents(&addon), snapshot.data);
+    assert_eq!(modes(&restored), modes(&original));
+}
+
+#[wasm_bindgen_test]
+async fn restore_makes_a_matching_terminal() {
+    let (original, snapshot) = captured().await;
+
+    let restored = snapshot.restore();
+    write(&restored, "").await;
+
+    assert_eq!((restored.cols(), restored.rows()), (60, 12));
+    assert_eq!(font_size(&restored), 13);
+    assert_eq!(lines(&restored), lines(&original));
+    assert_eq!(modes(&restored), modes(&original));
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "serde")]
+
+use std::convert::TryFrom;
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::theme::{
+    alacritty, base16, iterm, windows_terminal, ThemeFieldError,
+};
+use xterm_js_sys::xterm::Theme;
+
+#[wasm_bindgen_test]
+fn ansi_colors() {
+    let mut theme = Theme::default();
+    theme.set_ansi_color(5, Some("#123456".into()));
+    assert_eq!(theme.magenta().as_deref(), Some("#123456"));
+
+    // Setting an extended color fills in the gap with xterm's colors.
+    theme.set_ansi_color(18, Some("#abcdef".into()));
+    assert_eq!(
+        theme.extended_ansi(),
+        Some(vec!["#000000".into(), "#00005f".into(), "#abcdef".into()])
+    );
+
+    theme.set_ansi_color(18, None);
+    assert_eq!(theme.ansi_color(18).as_deref(), Some("#000087"));
+    assert_eq!(theme.ansi_color(19), None);
+}
+
+#[wasm_bindgen_test]
+fn alacritty_errors_name_fields() {
+    let colors: alacritty::Colors = serde_json::from_value(serde_json::json!({
+        "primary": { "background": "blak" },
+        "bright": { "red": "#ff00f" },
+        "indexed_colors": [{ "index": 20, "color": "0xfffff" }],
+    }))
+    .unwrap();
+
+    let err = Theme::try_from(&colors).unwrap_err();
+    let fields: Vec<_> = err.0.iter().map(|e| &*e.field).collect();
+    assert_eq!(
+        fields,
+        [
+            "colors.primary.background",
+            "colors.bright.red",
+            "colors.indexed_colors[0].color",
+        ]
+    );
+    assert_eq!(
+        err.0[0],
+        ThemeFieldError {
+            field: "colors.primary.background".into(),
+            message: "`blak`: not a CSS color".into(),
+        }
+    );
+    assert_eq!(&*err.0[2].message, "`0xfffff`: invalid hex color");
+}
+
+#[wasm_bindgen_test]
+fn alacritty_round_trip() {
+    let mut theme = Theme::default();
+    theme.set_background(Some("#000000cc".into()));
+    theme.set_selection_foreground(Some("#fff".into()));
+    theme.set_bright_cyan(Some("#00ffff".into()));
+    theme.set_ansi_color(17, Some("#010203".into()));
+
+    let colors = alacritty::Colors::try_from(&theme).unwrap();
+    assert_eq!(colors.primary.background.as_deref(), Some("#000000"));
+    assert_eq!(colors.selection.text.as_deref(), Some("#ffffff"));
+    assert_eq!(colors.bright.cyan.as_deref(), Some("#00ffff"));
+    assert_eq!(colors.indexed_colors.len(), 2);
+
+    let imported = Theme::try_from(&colors).unwrap();
+    assert_eq!(imported.ansi_color(17).as_deref(), Some("#010203"));
+    assert_eq!(imported.selection_foreground().as_deref(), Some("#ffffff"));
+    assert_eq!(imported.validate(), Ok(()));
+
+    // Alacritty's `0x` colors are imported as `#` colors.
+    let colors: alacritty::Colors = serde_json::from_value(serde_json::json!({
+        "primary": { "background": "0x1d1f21" },
+        "cursor": { "cursor": "0xC5C8C6" },
+    }))
+    .unwrap();
+    let imported = Theme::try_from(&colors).unwrap();
+    assert_eq!(imported.background().as_deref(), Some("#1d1f21"));
+    assert_eq!(imported.cursor().as_deref(), Some("#c5c8c6"));
+    assert_eq!(imported.validate(), Ok(()));
+}
+
+#[wasm_bindgen_test]
+fn exporting_malformed_colors_fails() {
+    let mut theme = Theme::default();
+    theme.set_background(Some("rebeccapurple".into()));
+    theme.set_cursor(Some("#ggg".into()));
+    theme.set_bright_blue(Some("rgb(0, 0)".into()));
+
+    let err = windows_terminal::Scheme::from_theme("x", &theme).unwrap_err();
+    let fields: Vec<_> = err.0.iter().map(|e| &*e.field).collect();
+    assert_eq!(fields, ["cursor", "brightBlue"]);
+    assert_eq!(
+        err.to_string(),
+        "invalid color scheme: `cursor`: `#ggg`: invalid hex color; \
+         `brightBlue`: `rgb(0, 0)`: invalid `rgb()`/`rgba()` color"
+    );
+}
+
+#[wasm_bindgen_test]
+fn iterm_components() {
+    let scheme: iterm::ColorScheme =
+        serde_json::from_value(serde_json::json!({
+            "Cursor Text Color": {
+                "Red Component": 0.5,
+                "Green Component": 1.5,
+                "Blue Component": -1,
+                "Color Space": "sRGB",
+            },
+            "Bold Color": {
+                "Red Component": 1,
+                "Green Component": 1,
+                "Blue Component": 1,
+            },
+        }))
+        .unwrap();
+
+    let err = Theme::try_from(&scheme).unwrap_err();
+    assert_eq!(err.0.len(), 2);
+    assert_eq!(err.0[0].field, "Cursor Text Color");
+    assert_eq!(
+        err.0[1].message,
+        "blue component `-1` is not between 0 and 1"
+    );
+
+    let mut theme = Theme::default();
+    theme.set_cursor_accent(Some("#33669980".into()));
+    let exported = iterm::ColorScheme::try_from(&theme).unwrap();
+    let color = &exported.0["Cursor Text Color"];
+    assert_eq!(
+        (color.red, color.green, color.blue),
+        (51.0 / 255.0, 102.0 / 255.0, 153.0 / 255.0)
+    );
+    assert_eq!(color.alpha, Some(128.0 / 255.0));
+    assert_eq!(exported.0.len(), 1);
+}
+
+#[wasm_bindgen_test]
+fn base16_missing_colors() {
+    let mut theme = Theme::nord();
+    theme.set_extended_ansi(None);
+    theme.set_foreground(None);
+
+    let err = base16::Scheme::from_theme("Nord", "", &theme).unwrap_err();
+    let fields: Vec<_> = err.0.iter().map(|e| &*e.field).collect();
+    assert_eq!(
+        fields,
+        ["base01", "base02", "base04", "base06", "base09", "base0F"]
+    );
+    assert_eq!(err.0[0].message, "missing (ANSI color 18)");
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "ext")]
+
+use wasm_bindgen_test::*;
+use xterm_js_sys::ext::unicode::{
+    is_ambiguous, AmbiguousWidth, CharProperties, GraphemeBreak, UnicodeV15,
+    XtermUnicodeVersionProvider,
+};
+use xterm_js_sys::xterm::WideCharacterWidth::{_0, _1, _2};
+
+#[wasm_bindgen_test]
+fn widths() {
+    let width = |c: char| UnicodeV15.wcwidth(c as u32);
+
+    // Controls, ASCII and Latin-1.
+    assert_eq!(width('\0'), _0);
+    assert_eq!(width('\x1b'), _0);
+    assert_eq!(width('a'), _1);
+    assert_eq!(width('\u{85}'), _0);
+    assert_eq!(width('é'), _1);
+    assert_eq!(width('\u{AD}'), _1); // soft hyphen
+
+    // Zero width: combining marks, format characters, Hangul Jamo.
+    assert_eq!(width('\u{301}'), _0);
+    assert_eq!(width('\u{200B}'), _0);
+    assert_eq!(width('\u{200D}'), _0);
+    assert_eq!(width('\u{FE0F}'), _0);
+    assert_eq!(width('\u{1160}'), _0);
+    assert_eq!(width('\u{11FF}'), _0);
+    assert_eq!(width('\u{D7B0}'), _0);
+    assert_eq!(width('\u{D7FB}'), _0);
+
+    // Wide: CJK, fullwidth forms, emoji presentation.
+    assert_eq!(width('中'), _2);
+    assert_eq!(width('の'), _2);
+    assert_eq!(width('한'), _2);
+    assert_eq!(width('Ａ'), _2);
+    assert_eq!(width('😀'), _2);
+    assert_eq!(width('🫨'), _2); // Unicode 15.0
+    assert_eq!(width('\u{31350}'), _2); // CJK Extension H (Unicode 15.0)
+    assert_eq!(width('\u{2EBF0}'), _2); // CJK Extension I (Unicode 15.1)
+
+    // Narrow: ambiguous width and text presentation emoji.
+    assert_eq!(width('α'), _1);
+    assert_eq!(width('☺'), _1);
+    assert_eq!(width('ｱ'), _1); // halfwidth katakana
+}
+
+#[wasm_bindgen_test]
+fn emoji() {
+    assert!(UnicodeV15::is_emoji('😀' as u32));
+    assert!(UnicodeV15::is_emoji('☺' as u32));
+    assert!(UnicodeV15::is_emoji(0x1FFFD)); // reserved
+    assert!(!UnicodeV15::is_emoji('a' as u32));
+    assert!(!UnicodeV15::is_emoji('中' as u32));
+}
+
+#[wasm_bindgen_test]
+fn version() {
+    assert_eq!(UnicodeV15.version(), UnicodeV15::VERSION);
+    assert_eq!(UnicodeV15::UNICODE_VERSION, (15, 1, 0));
+}
+
+/// Splits `s` into clusters the way xterm.js would, returning each cluster
+/// along with its width.
+fn clusters(s: &str) -> Vec<(String, u8)> {
+    clusters_with(&UnicodeV15, s)
+}
+
+fn clusters_with(
+    provider: &impl XtermUnicodeVersionProvider,
+    s: &str,
+) -> Vec<(String, u8)> {
+    let mut out: Vec<(String, u8)> = Vec::new();
+    let mut preceding = 0;
+
+    for c in s.chars() {
+        let props =
+            CharProperties(provider.char_properties(c as u32, preceding));
+        match out.last_mut() {
+            Some((cluster, width)) if props.should_join() => {
+                cluster.push(c);
+                *width = props.width();
+            }
+            _ => out.push((c.to_string(), props.width())),
+        }
+        preceding = props.0;
+    }
+
+    out
+}
+
+#[wasm_bindgen_test]
+fn grapheme_clusters() {
+    let cluster = |s: &str, width| (s.to_string(), width);
+
+    assert_eq!(clusters("ab"), vec![cluster("a", 1), cluster("b", 1)]);
+
+    // Combining marks.
+    assert_eq!(
+        clusters("e\u{301}\u{323}x"),
+        vec![cluster("e\u{301}\u{323}", 1), cluster("x", 1)]
+    );
+
+    // ZWJ sequences and emoji modifiers.
+    let family = "👨\u{200D}👩\u{200D}👧";
+    assert_eq!(clusters(family), vec![cluster(family, 2)]);
+    assert_eq!(clusters("👋🏽!"), vec![cluster("👋🏽", 2), cluster("!", 1)]);
+
+    // A ZWJ that doesn't follow an emoji doesn't join the next emoji.
+    assert_eq!(
+        clusters("a\u{200D}😀"),
+        vec![cluster("a\u{200D}", 1), cluster("😀", 2)]
+    );
+
+    // Emoji presentation widens text presentation emoji.
+    assert_eq!(clusters("☺\u{FE0F}"), vec![cluster("☺\u{FE0F}", 2)]);
+    assert_eq!(clusters("☺"), vec![cluster("☺", 1)]);
+
+    // Flags pair up regional indicators.
+    assert_eq!(
+        clusters("🇯🇵🇫🇷🇩"),
+        vec![cluster("🇯🇵", 2), cluster("🇫🇷", 2), cluster("🇩", 1)]
+    );
+
+    // Hangul syllables made of conjoining Jamo.
+    assert_eq!(
+        clusters("\u{1100}\u{1161}\u{11A8}"),
+        vec![cluster("\u{1100}\u{1161}\u{11A8}", 2)]
+    );
+
+    // Unicode 15.0's rules (there's no GB9c): the virama joins the consonant
+    // before it but the conjunct is split after it.
+    assert_eq!(
+        clusters("\u{915}\u{94D}\u{937}"),
+        vec![cluster("\u{915}\u{94D}", 1), cluster("\u{937}", 1)]
+    );
+
+    // CR LF stays together, but controls don't join anything else.
+    assert_eq!(clusters("\r\n").len(), 1);
+    assert_eq!(clusters("a\r").len(), 2);
+}
+
+#[wasm_bindgen_test]
+fn packed_properties() {
+    let props = CharProperties::new(0x1234, 2, true);
+    assert!(props.should_join());
+    assert_eq!(props.width(), 2);
+    assert_eq!(props.state(), 0x1234);
+    assert_eq!(props.0, (0x1234 << 3) | (2 << 1) | 1);
+
+    assert_eq!(GraphemeBreak::of('\u{200D}' as u32), GraphemeBreak::Zwj);
+    assert_eq!(
+        GraphemeBreak::of('😀' as u32),
+        GraphemeBreak::ExtendedPictographic
+    );
+    assert_eq!(GraphemeBreak::of('a' as u32), GraphemeBreak::Other);
+    assert_eq!(GraphemeBreak::of(0xAC01), GraphemeBreak::Lvt);
+}
+
+#[wasm_bindgen_test]
+fn ambiguous_width() {
+    let cjk = AmbiguousWidth::new(UnicodeV15, _2).with_overrides([
+        (0x2026, _1), // … is ambiguous
+        ('a' as u32, _2),
+    ]);
+    let narrow = AmbiguousWidth::new(UnicodeV15, _1).with_version_name("mine");
+
+    assert_eq!(cjk.version(), "15.1-wide-ambiguous");
+    assert_eq!(narrow.version(), "mine");
+
+    for c in ['─', 'α', 'Ж', '①'] {
+        assert!(is_ambiguous(c as u32));
+        assert_eq!(UnicodeV15.wcwidth(c as u32), _1);
+        assert_eq!(cjk.wcwidth(c as u32), _2);
+        assert_eq!(narrow.wcwidth(c as u32), _1);
+    }
+
+    // Unambiguous widths come from the base provider.
+    assert!(!is_ambiguous('中' as u32));
+    assert_eq!(cjk.wcwidth('中' as u32), _2);
+    assert_eq!(cjk.wcwidth('\u{301}' as u32), _0);
+    assert_eq!(narrow.wcwidth('b' as u32), _1);
+
+    // Overrides win.
+    assert_eq!(cjk.wcwidth(0x2026), _1);
+    assert_eq!(cjk.wcwidth('a' as u32), _2);
+
+    // ... including in clusters.
+    assert_eq!(
+        clusters_with(&cjk, "α\u{301}b…"),
+        vec![
+            ("α\u{301}".to_string(), 2),
+            ("b".to_string(), 1),
+            ("…".to_string(), 1)
+        ]
+    );
+}